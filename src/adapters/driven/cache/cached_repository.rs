@@ -0,0 +1,260 @@
+use async_trait::async_trait;
+use std::rc::Rc;
+
+use crate::adapters::driven::cache::{ContentCache, Freshness};
+use crate::application::domain::article::{Article, Articles, Category};
+use crate::application::domain::core::Result;
+use crate::application::domain::layout::{MenuTree, SiteBranding};
+use crate::application::domain::page::Page;
+use crate::application::domain::portfolio::Portfolio;
+use crate::application::domain::project::Project;
+use crate::application::ports::driven::*;
+
+/// Decorator caching the output ports implemented by the wrapped repository.
+///
+/// Every value goes through the shared `ContentCache`, so pages keep being served from the
+/// last good data while the CMS is down. Stale values are reported to the `Freshness`
+/// of the current request.
+pub struct CachedRepository<R> {
+    inner: Rc<R>,
+    cache: ContentCache,
+    freshness: Freshness,
+}
+
+impl<R> CachedRepository<R> {
+    pub fn new(inner: R, cache: ContentCache, freshness: Freshness) -> Self {
+        Self {
+            inner: Rc::new(inner),
+            cache,
+            freshness,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingArticleData + 'static> ForFetchingArticleData for CachedRepository<R> {
+    async fn find_by_slug(&self, slug: &str) -> Result<Article> {
+        let (inner, slug) = (self.inner.clone(), slug.to_string());
+        let key = format!("article:{slug}");
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                ForFetchingArticleData::find_by_slug(inner.as_ref(), &slug).await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingArticlesFeatured + 'static> ForFetchingArticlesFeatured for CachedRepository<R> {
+    async fn get_featured(&self) -> Result<Articles> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("articles:featured", &self.freshness, move || async move {
+                inner.get_featured().await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingArticlesList + 'static> ForFetchingArticlesList for CachedRepository<R> {
+    async fn get_list(&self, category_id: Option<String>) -> Result<Vec<Article>> {
+        let inner = self.inner.clone();
+        let key = format!("articles:{}", category_id.as_deref().unwrap_or_default());
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                ForFetchingArticlesList::get_list(inner.as_ref(), category_id).await
+            })
+            .await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Article>> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("articles", &self.freshness, move || async move {
+                inner.get_all().await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingCategoriesList + 'static> ForFetchingCategoriesList for CachedRepository<R> {
+    async fn find_all_categories(&self) -> Result<Vec<Category>> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("categories", &self.freshness, move || async move {
+                inner.find_all_categories().await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingMenuData + 'static> ForFetchingMenuData for CachedRepository<R> {
+    async fn find_by_id(&self, id: &str) -> Result<MenuTree> {
+        let (inner, id) = (self.inner.clone(), id.to_string());
+        let key = format!("menu:{id}");
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                inner.find_by_id(&id).await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingSiteBranding + 'static> ForFetchingSiteBranding for CachedRepository<R> {
+    async fn get_branding(&self) -> Result<SiteBranding> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("branding", &self.freshness, move || async move {
+                inner.get_branding().await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingPageData + 'static> ForFetchingPageData for CachedRepository<R> {
+    async fn find_by_slug(&self, slug: &str) -> Result<Page> {
+        let (inner, slug) = (self.inner.clone(), slug.to_string());
+        let key = format!("page:{slug}");
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                ForFetchingPageData::find_by_slug(inner.as_ref(), &slug).await
+            })
+            .await
+    }
+
+    async fn get_list(&self) -> Result<Vec<Page>> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("pages", &self.freshness, move || async move {
+                ForFetchingPageData::get_list(inner.as_ref()).await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingPortfolioData + 'static> ForFetchingPortfolioData for CachedRepository<R> {
+    async fn find_by_slug(&self, slug: &str) -> Result<Portfolio> {
+        let (inner, slug) = (self.inner.clone(), slug.to_string());
+        let key = format!("portfolio:{slug}");
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                ForFetchingPortfolioData::find_by_slug(inner.as_ref(), &slug).await
+            })
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ForFetchingProjects + 'static> ForFetchingProjects for CachedRepository<R> {
+    async fn get_list(&self) -> Result<Vec<Project>> {
+        let inner = self.inner.clone();
+
+        self.cache
+            .get_or_fetch("projects", &self.freshness, move || async move {
+                ForFetchingProjects::get_list(inner.as_ref()).await
+            })
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+        let (inner, slug) = (self.inner.clone(), slug.to_string());
+        let key = format!("project:{slug}");
+
+        self.cache
+            .get_or_fetch(&key, &self.freshness, move || async move {
+                ForFetchingProjects::find_by_slug(inner.as_ref(), &slug).await
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::application::domain::core::AppError;
+    use crate::application::domain::project::tests::project_fixture;
+
+    struct ProjectRepositoryMock {
+        calls: Rc<Cell<usize>>,
+        available: bool,
+    }
+
+    #[async_trait(?Send)]
+    impl ForFetchingProjects for ProjectRepositoryMock {
+        async fn get_list(&self) -> Result<Vec<Project>> {
+            self.calls.set(self.calls.get() + 1);
+
+            match self.available {
+                true => Ok(vec![project_fixture()]),
+                false => Err(AppError::External("ProjectRepositoryMock", "down".to_string())),
+            }
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+            Err(AppError::ResourceNotFound(slug.to_string()))
+        }
+    }
+
+    fn repository(cache: &ContentCache, freshness: &Freshness, available: bool) -> CachedRepository<ProjectRepositoryMock> {
+        let mock = ProjectRepositoryMock {
+            calls: Rc::new(Cell::new(0)),
+            available,
+        };
+
+        CachedRepository::new(mock, cache.clone(), freshness.clone())
+    }
+
+    #[actix_rt::test]
+    async fn get_list_succeeds_when_value_is_cached() {
+        let cache = ContentCache::default();
+        let freshness = Freshness::default();
+        let repository = repository(&cache, &freshness, true);
+
+        repository.get_list().await.unwrap();
+        let projects = repository.get_list().await.unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(repository.inner.calls.get(), 1);
+        assert!(!freshness.is_stale());
+    }
+
+    #[actix_rt::test]
+    async fn get_list_serves_stale_value_when_cms_is_down() {
+        let cache = ContentCache::default().ttl(Duration::ZERO);
+        repository(&cache, &Freshness::default(), true).get_list().await.unwrap();
+
+        let freshness = Freshness::default();
+        let projects = repository(&cache, &freshness, false).get_list().await.unwrap();
+
+        assert_eq!(projects[0].id(), project_fixture().id());
+        assert!(freshness.is_stale());
+    }
+
+    #[actix_rt::test]
+    async fn find_by_slug_fails_when_nothing_is_cached() {
+        let repository = repository(&ContentCache::default(), &Freshness::default(), true);
+
+        let result = ForFetchingProjects::find_by_slug(&repository, "missing").await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(_))));
+    }
+}
@@ -0,0 +1,361 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{error, warn};
+
+use crate::application::domain::core::{AppError, Result};
+use crate::utilities::Metrics;
+
+type Entries = HashMap<String, Entry>;
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    fetched_at: Instant,
+    refreshing: bool,
+}
+
+enum Lookup {
+    Fresh(Value),
+    Stale { value: Value, refresh: bool },
+    Missing,
+}
+
+/// In-memory cache of the content fetched from the CMS, shared by every worker.
+///
+/// Entries younger than `ttl` are served as they are. Older entries are still served right
+/// away while a background task fetches them again (stale-while-revalidate), and keep being
+/// served if that refresh fails while the CMS is unreachable or failing (stale-if-error), until
+/// they are older than `max_age`. Content the CMS no longer serves, e.g. unpublished, deleted or
+/// moved, is evicted instead so the next request gets the error.
+#[derive(Clone, Debug)]
+pub struct ContentCache {
+    ttl: Duration,
+    max_age: Duration,
+    entries: Arc<Mutex<Entries>>,
+    metrics: Metrics,
+}
+
+/// Records whether any value served during a request came from a stale entry.
+#[derive(Clone, Debug, Default)]
+pub struct Freshness(Rc<Cell<bool>>);
+
+impl ContentCache {
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Returns the cached value for the key, calling `fetch` when it's missing or too old.
+    pub async fn get_or_fetch<T, F, Fut>(&self, key: &str, freshness: &Freshness, fetch: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = Result<T>> + 'static,
+    {
+        match self.lookup(key)? {
+            Lookup::Fresh(value) => {
+                self.metrics.record_cache_lookup("content", "fresh");
+                decode(key, value)
+            }
+            Lookup::Stale { value, refresh } => {
+                self.metrics.record_cache_lookup("content", "stale");
+                if refresh {
+                    self.refresh(key, fetch);
+                }
+
+                freshness.mark_stale();
+                decode(key, value)
+            }
+            Lookup::Missing => {
+                self.metrics.record_cache_lookup("content", "miss");
+                let value = fetch().await?;
+                self.store(key, &value)?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn lookup(&self, key: &str) -> Result<Lookup> {
+        let mut entries = self.lock()?;
+
+        let Some(entry) = entries.get_mut(key) else {
+            return Ok(Lookup::Missing);
+        };
+
+        let age = entry.fetched_at.elapsed();
+        if age < self.ttl {
+            return Ok(Lookup::Fresh(entry.value.clone()));
+        }
+        if age >= self.max_age {
+            entries.remove(key);
+            return Ok(Lookup::Missing);
+        }
+
+        // only the first request past the ttl starts a refresh
+        let refresh = !entry.refreshing;
+        entry.refreshing = true;
+
+        Ok(Lookup::Stale {
+            value: entry.value.clone(),
+            refresh,
+        })
+    }
+
+    fn refresh<T, F, Fut>(&self, key: &str, fetch: F)
+    where
+        T: Serialize,
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future<Output = Result<T>> + 'static,
+    {
+        let cache = self.clone();
+        let key = key.to_string();
+
+        actix_web::rt::spawn(async move {
+            let result = match fetch().await {
+                Ok(value) => cache.store(&key, &value),
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Err(e) if is_transient(&e) => {
+                    error!("Failed to refresh `{key}`, serving the stale copy: {e}");
+                    cache.release(&key);
+                }
+                Err(e) => {
+                    warn!("Evicting `{key}`, the CMS no longer serves it: {e}");
+                    cache.evict(&key);
+                }
+                Ok(()) => {}
+            }
+        });
+    }
+
+    fn store<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| AppError::Deserialization(e.to_string(), key.to_string()))?;
+
+        self.lock()?.insert(
+            key.to_string(),
+            Entry {
+                value,
+                fetched_at: Instant::now(),
+                refreshing: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn evict(&self, key: &str) {
+        if let Ok(mut entries) = self.lock() {
+            entries.remove(key);
+        }
+    }
+
+    /// Lets the next request try to refresh the entry again.
+    fn release(&self, key: &str) {
+        if let Some(entry) = self.lock().ok().as_mut().and_then(|e| e.get_mut(key)) {
+            entry.refreshing = false;
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Entries>> {
+        self.entries
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))
+    }
+}
+
+impl Default for ContentCache {
+    fn default() -> Self {
+        ContentCache {
+            ttl: Duration::from_secs(60),
+            max_age: Duration::from_secs(86400),
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Metrics::default(),
+        }
+    }
+}
+
+impl Freshness {
+    pub fn mark_stale(&self) {
+        self.0.set(true);
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.0.get()
+    }
+}
+
+/// Failures that may be gone on the next try, the unreachable CMS or one answering with a 5xx.
+fn is_transient(e: &AppError) -> bool {
+    match e {
+        AppError::External(_, _) => true,
+        AppError::ApiFailure(status, _) => *status >= 500,
+        _ => false,
+    }
+}
+
+fn decode<T: DeserializeOwned>(key: &str, value: Value) -> Result<T> {
+    serde_json::from_value(value).map_err(|e| AppError::Deserialization(e.to_string(), key.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::cell::RefCell;
+
+    fn fetch(value: Result<String>) -> impl FnOnce() -> std::future::Ready<Result<String>> {
+        move || std::future::ready(value)
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_succeeds_when_entry_is_missing() {
+        let cache = ContentCache::default();
+        let freshness = Freshness::default();
+
+        let value: String = cache
+            .get_or_fetch("key", &freshness, fetch(Ok("first".to_string())))
+            .await
+            .unwrap();
+
+        assert_eq!(value, "first");
+        assert!(!freshness.is_stale());
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_serves_cached_value_when_entry_is_fresh() {
+        let cache = ContentCache::default();
+        let freshness = Freshness::default();
+        cache.store("key", &"first").unwrap();
+
+        let value: String = cache
+            .get_or_fetch("key", &freshness, fetch(Ok("second".to_string())))
+            .await
+            .unwrap();
+
+        assert_eq!(value, "first");
+        assert!(!freshness.is_stale());
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_serves_stale_value_while_refreshing() {
+        let cache = ContentCache::default().ttl(Duration::ZERO);
+        let freshness = Freshness::default();
+        cache.store("key", &"first").unwrap();
+
+        let value: String = cache
+            .get_or_fetch("key", &freshness, fetch(Ok("second".to_string())))
+            .await
+            .unwrap();
+        actix_rt::task::yield_now().await;
+
+        assert_eq!(value, "first");
+        assert!(freshness.is_stale());
+        assert_eq!(cache.lock().unwrap().get("key").unwrap().value, "second");
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_refreshes_once_when_entry_is_stale() {
+        let cache = ContentCache::default().ttl(Duration::ZERO);
+        let calls = Rc::new(RefCell::new(0));
+        cache.store("key", &"first").unwrap();
+
+        for _ in 0..3 {
+            let calls = calls.clone();
+            let _: String = cache
+                .get_or_fetch("key", &Freshness::default(), move || async move {
+                    *calls.borrow_mut() += 1;
+                    Ok("second".to_string())
+                })
+                .await
+                .unwrap();
+        }
+        actix_rt::task::yield_now().await;
+
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_keeps_stale_value_when_refresh_fails() {
+        let cache = ContentCache::default().ttl(Duration::ZERO);
+        let failure = || fetch(Err(AppError::ApiFailure(503, "down".to_string())));
+        cache.store("key", &"first").unwrap();
+
+        let _: String = cache.get_or_fetch("key", &Freshness::default(), failure()).await.unwrap();
+        actix_rt::task::yield_now().await;
+
+        // the next request is allowed to try again
+        assert!(!cache.lock().unwrap().get("key").unwrap().refreshing);
+
+        let value: String = cache.get_or_fetch("key", &Freshness::default(), failure()).await.unwrap();
+
+        assert_eq!(value, "first");
+    }
+
+    #[rstest]
+    #[case(AppError::ResourceNotFound("/en/articles/deleted".to_string()))]
+    #[case(AppError::Unauthorized("/en/articles/unpublished".to_string()))]
+    #[case(AppError::Moved("/en/articles/new-title".to_string(), 301))]
+    #[case(AppError::ApiFailure(404, "".to_string()))]
+    #[actix_rt::test]
+    async fn get_or_fetch_evicts_stale_value_when_content_is_gone(#[case] error: AppError) {
+        let cache = ContentCache::default().ttl(Duration::ZERO);
+        let gone = || fetch(Err(error.clone()));
+        cache.store("key", &"first").unwrap();
+
+        let _: String = cache.get_or_fetch("key", &Freshness::default(), gone()).await.unwrap();
+        actix_rt::task::yield_now().await;
+
+        assert!(cache.lock().unwrap().get("key").is_none());
+
+        let result: Result<String> = cache.get_or_fetch("key", &Freshness::default(), gone()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_fails_when_stale_entry_is_too_old() {
+        let cache = ContentCache::default().ttl(Duration::ZERO).max_age(Duration::ZERO);
+        cache.store("key", &"first").unwrap();
+
+        let result: Result<String> = cache
+            .get_or_fetch("key", &Freshness::default(), fetch(Err(AppError::ApiFailure(503, "down".to_string()))))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn get_or_fetch_records_outcome_when_metrics_are_given() {
+        let metrics = Metrics::default();
+        let cache = ContentCache::default().metrics(metrics.clone());
+        let freshness = Freshness::default();
+
+        let _: String = cache.get_or_fetch("key", &freshness, fetch(Ok("first".to_string()))).await.unwrap();
+        let _: String = cache.get_or_fetch("key", &freshness, fetch(Ok("second".to_string()))).await.unwrap();
+        let rendered = metrics.render();
+
+        assert!(rendered.contains(r#"website_cache_lookups_total{cache="content",outcome="miss"} 1"#));
+        assert!(rendered.contains(r#"website_cache_lookups_total{cache="content",outcome="fresh"} 1"#));
+    }
+}
@@ -0,0 +1,5 @@
+mod cached_repository;
+mod content_cache;
+
+pub use cached_repository::*;
+pub use content_cache::*;
@@ -0,0 +1,133 @@
+use crate::adapters::driven::cache::{CachedRepository, ContentCache, Freshness};
+use crate::adapters::driven::drupal_jsonapi::repositories as drupal;
+use crate::adapters::driven::markdown_files::repositories as markdown;
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::ports::driven::*;
+use crate::utilities::HttpClient;
+
+/// Where the content of the site is read from, selected at startup.
+///
+/// - `Drupal`: the JSON:API of the CMS.
+/// - `Markdown`: the Markdown files of a content directory, see `ContentFilesService`.
+#[derive(Clone, Debug, Default)]
+pub enum ContentSource {
+    #[default]
+    Drupal,
+    Markdown(ContentFilesService),
+}
+
+impl ContentSource {
+    /// The repositories of the source, the CMS ones send their requests through the client.
+    pub fn repositories(&self, http_client: &HttpClient) -> ContentRepositories {
+        ContentRepositories {
+            source: self.clone(),
+            http_client: http_client.clone(),
+            cache: None,
+        }
+    }
+}
+
+/// Boxes the repository as the port returned by the method, behind the cache when there's one.
+macro_rules! boxed {
+    ($repositories:expr, $repository:expr) => {
+        match &$repositories.cache {
+            Some((cache, freshness)) => Box::new(CachedRepository::new($repository, cache.clone(), freshness.clone())),
+            None => Box::new($repository),
+        }
+    };
+}
+
+/// Builds the driven adapters of the selected `ContentSource`, so the use cases don't know
+/// where their content comes from.
+pub struct ContentRepositories {
+    source: ContentSource,
+    http_client: HttpClient,
+    cache: Option<(ContentCache, Freshness)>,
+}
+
+impl ContentRepositories {
+    /// Caches every repository in the shared cache, reporting stale values to the `Freshness`.
+    pub fn cached(mut self, cache: ContentCache, freshness: Freshness) -> Self {
+        self.cache = Some((cache, freshness));
+        self
+    }
+
+    pub fn article_data(&self) -> Box<dyn ForFetchingArticleData> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::ArticleRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::ArticleRepository::new(files.clone())),
+        }
+    }
+
+    pub fn articles_list(&self) -> Box<dyn ForFetchingArticlesList> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::ArticleRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::ArticleRepository::new(files.clone())),
+        }
+    }
+
+    pub fn articles_featured(&self) -> Box<dyn ForFetchingArticlesFeatured> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::ArticleRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::ArticleRepository::new(files.clone())),
+        }
+    }
+
+    pub fn categories_list(&self) -> Box<dyn ForFetchingCategoriesList> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::CategoryRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::CategoryRepository::new(files.clone())),
+        }
+    }
+
+    pub fn page_data(&self) -> Box<dyn ForFetchingPageData> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::PageRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::PageRepository::new(files.clone())),
+        }
+    }
+
+    pub fn portfolio_data(&self) -> Box<dyn ForFetchingPortfolioData> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::PortfolioRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::PortfolioRepository::new(files.clone())),
+        }
+    }
+
+    pub fn projects(&self) -> Box<dyn ForFetchingProjects> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::ProjectRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::ProjectRepository::new(files.clone())),
+        }
+    }
+
+    pub fn menu_data(&self) -> Box<dyn ForFetchingMenuData> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::LayoutRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::LayoutRepository::new(files.clone())),
+        }
+    }
+
+    pub fn site_branding(&self) -> Box<dyn ForFetchingSiteBranding> {
+        match &self.source {
+            ContentSource::Drupal => boxed!(self, drupal::LayoutRepository::new(self.http_client.clone())),
+            ContentSource::Markdown(files) => boxed!(self, markdown::LayoutRepository::new(files.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_rt::test]
+    async fn repositories_read_files_when_source_is_markdown() {
+        let repositories = ContentSource::Markdown(ContentFilesService::new("tests/fixtures/content"))
+            .repositories(&HttpClient::default())
+            .cached(ContentCache::default(), Freshness::default());
+
+        let categories = repositories.categories_list().find_all_categories().await.unwrap();
+
+        assert_eq!(categories.len(), 2);
+    }
+}
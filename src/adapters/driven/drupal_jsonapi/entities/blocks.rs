@@ -0,0 +1,15 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::driven::drupal_jsonapi::entities::ImageField;
+
+#[derive(Debug, Clone, Deserialize, Serialize, Getters)]
+pub struct SiteBrandingBlock {
+    id: String,
+    #[serde(rename = "type")]
+    entity_type: String,
+    info: String,
+    site_name: String,
+    slogan: Option<String>,
+    logo: Option<ImageField>,
+}
@@ -1,5 +1,6 @@
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use serde_aux::field_attributes::deserialize_number_from_string;
 
 #[derive(Deserialize, Serialize, Getters, Debug)]
 pub struct ResolvedRoute {
@@ -9,6 +10,17 @@ pub struct ResolvedRoute {
     jsonapi: RouteJsonApiMetadata,
     #[serde(rename = "isHomePath")]
     is_home_path: bool,
+    /// The redirects followed to reach the resolved path, when its alias has changed.
+    #[serde(default)]
+    redirect: Vec<RouteRedirectMetadata>,
+}
+
+#[derive(Deserialize, Serialize, Getters, Debug)]
+pub struct RouteRedirectMetadata {
+    from: String,
+    to: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    status: u16,
 }
 
 #[derive(Deserialize, Serialize, Getters, Debug)]
@@ -57,6 +69,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserializes_resolved_route_with_redirects() {
+        let json_str = mock_redirected_route();
+        let resolved_route = serde_json::from_str::<ResolvedRoute>(json_str).unwrap();
+
+        assert_eq!(resolved_route.redirect().len(), 1);
+        assert_eq!(resolved_route.redirect()[0].from(), "/en/articles/old-title");
+        assert_eq!(resolved_route.redirect()[0].to(), "/en/articles/new-title");
+        assert_eq!(*resolved_route.redirect()[0].status(), 301);
+    }
+
+    #[test]
+    fn deserializes_resolved_route_without_redirects() {
+        let resolved_route = serde_json::from_str::<ResolvedRoute>(mock_resolved_route()).unwrap();
+
+        assert!(resolved_route.redirect().is_empty());
+    }
+
+    fn mock_redirected_route() -> &'static str {
+        r#"
+        {
+            "label": "test",
+            "isHomePath": false,
+            "resolved": "https://example.com/en/articles/new-title",
+            "redirect": [
+                {
+                    "from": "/en/articles/old-title",
+                    "to": "/en/articles/new-title",
+                    "status": "301"
+                }
+            ],
+            "entity": {
+                "id": "1",
+                "type": "node",
+                "bundle": "test",
+                "uuid": "550e8400-e29b-41d4-a716-446655440000",
+                "canonical": "https://example.com/en/articles/new-title"
+            },
+            "jsonapi": {
+                "individual": "https://example.com/api/node/test/550e8400-e29b-41d4-a716-446655440000",
+                "resourceName": "node--test",
+                "pathPrefix": "api",
+                "basePath": "/api",
+                "entryPoint": "https://example.com/api"
+            }
+        }
+        "#
+    }
+
     fn mock_resolved_route() -> &'static str {
         r#"
         {
@@ -3,9 +3,10 @@ use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_number_from_string;
 
 use crate::adapters::driven::drupal_jsonapi::entities::{
-    ContentHoverCardParagraph, ContentMediaParagraph, ContentSliderParagraph, ContentTextParagraph,
+    ContentMediaParagraph, ContentSliderParagraph, ContentTextParagraph,
     ContentTimelineParagraph, DocumentMedia, ImageMedia, PortfolioAboutMeParagraph,
-    PortfolioArticlesParagraph, PortfolioProjectsParagraph, PortfolioResumeParagraph,
+    PortfolioArticlesParagraph, PortfolioContactParagraph, PortfolioProjectsParagraph,
+    PortfolioResumeParagraph,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -13,8 +14,6 @@ use crate::adapters::driven::drupal_jsonapi::entities::{
 pub enum ContentField {
     #[serde(rename = "paragraph--content_timeline")]
     ContentTimelineParagraph(ContentTimelineParagraph),
-    #[serde(rename = "paragraph--content_hover_card")]
-    ContentProjectParagraph(ContentHoverCardParagraph),
     #[serde(rename = "paragraph--content_text")]
     ContentTextParagraph(ContentTextParagraph),
     #[serde(rename = "paragraph--content_media")]
@@ -29,6 +28,8 @@ pub enum ContentField {
     PortfolioProjectsParagraph(PortfolioProjectsParagraph),
     #[serde(rename = "paragraph--portfolio_blog")]
     PortfolioArticlesParagraph(PortfolioArticlesParagraph),
+    #[serde(rename = "paragraph--portfolio_contact")]
+    PortfolioContactParagraph(PortfolioContactParagraph),
     #[serde(other)]
     Unknown,
 }
@@ -55,6 +56,12 @@ pub struct LinkField {
     title: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, Getters)]
+pub struct DateRangeField {
+    value: String,
+    end_value: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
 pub struct ImageField {
     id: String,
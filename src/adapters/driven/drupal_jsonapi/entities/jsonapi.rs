@@ -2,13 +2,16 @@ use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
 use crate::adapters::driven::drupal_jsonapi::entities::{ArticleNode, PageNode};
-use crate::adapters::driven::drupal_jsonapi::entities::{PortfolioNode, TagsVocabulary};
+use crate::adapters::driven::drupal_jsonapi::entities::{PortfolioNode, ProjectNode, SiteBrandingBlock, TagsVocabulary};
 
 pub type NodePageResource = JsonApiDocument<PageNode>;
 pub type NodePortfolioResource = JsonApiDocument<PortfolioNode>;
 pub type NodeArticleResource = JsonApiDocument<ArticleNode>;
 pub type NodeArticleCollection = JsonApiDocument<Vec<ArticleNode>>;
+pub type NodeProjectResource = JsonApiDocument<ProjectNode>;
+pub type NodeProjectCollection = JsonApiDocument<Vec<ProjectNode>>;
 pub type VocabularyTagCollection = JsonApiDocument<Vec<TagsVocabulary>>;
+pub type BlockSiteBrandingCollection = JsonApiDocument<Vec<SiteBrandingBlock>>;
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
 pub struct JsonApiDocument<T> {
@@ -17,6 +20,17 @@ pub struct JsonApiDocument<T> {
     links: JsonApiLinks,
 }
 
+impl<T> JsonApiDocument<T> {
+    /// The url of the next page of a paginated collection, `None` on the last one.
+    pub fn next_page(&self) -> Option<&str> {
+        self.links.next.as_ref().map(|link| link.href.as_str())
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
 struct JsonApiMetaData {
     version: String,
@@ -1,3 +1,4 @@
+mod blocks;
 mod decoupled_router;
 mod fields;
 mod jsonapi;
@@ -7,6 +8,7 @@ mod nodes;
 mod paragraphs;
 mod taxonomies;
 
+pub use blocks::*;
 pub use decoupled_router::*;
 pub use fields::*;
 pub use jsonapi::*;
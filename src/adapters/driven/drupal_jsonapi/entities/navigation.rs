@@ -17,6 +17,8 @@ pub struct NavigationItem {
     enabled: bool,
     expanded: bool,
     external: bool,
+    parent: Option<String>,
+    below: Option<Vec<NavigationItem>>,
     field_image: Option<NavigationImageField>,
 }
 
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_bool_from_anything;
 
 use crate::adapters::driven::drupal_jsonapi::entities::{
-    ContentField, ImageField, MetatagsField, PathField, TagsVocabulary
+    ContentField, DateRangeField, ImageField, LinkField, MetatagsField, PathField, TagsVocabulary
 };
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
@@ -39,6 +39,28 @@ pub struct PortfolioNode {
     created_at: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, Getters)]
+pub struct ProjectNode {
+    id: String,
+    #[serde(rename = "type")]
+    entity_type: String,
+    title: String,
+    summary: String,
+    body: String,
+    path: PathField,
+    role: Option<String>,
+    technologies: Vec<String>,
+    date_range: DateRangeField,
+    link: Option<LinkField>,
+    thumbnail: ImageField,
+    gallery: Vec<ImageField>,
+    metatags: Vec<MetatagsField>,
+    #[serde(deserialize_with = "deserialize_bool_from_anything")]
+    status: bool,
+    #[serde(rename = "created")]
+    created_at: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
 pub struct PageNode {
     id: String,
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use serde_aux::field_attributes::deserialize_number_from_string;
 
 use crate::adapters::driven::drupal_jsonapi::entities::{
-    ContentField, DocumentField, ImageField, LinkField,
+    ContentField, DateRangeField, DocumentField, ImageField,
 };
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
@@ -34,7 +34,6 @@ pub struct PortfolioProjectsParagraph {
     title: String,
     subtitle: String,
     text: String,
-    items: Vec<ContentField>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
@@ -46,28 +45,26 @@ pub struct PortfolioArticlesParagraph {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
-pub struct ContentTimelineParagraph {
+pub struct PortfolioContactParagraph {
     id: String,
-    items: Vec<ContentTimelineItemParagraph>,
+    title: String,
+    subtitle: String,
+    text: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
-pub struct ContentTimelineItemParagraph {
+pub struct ContentTimelineParagraph {
     id: String,
-    title: String,
-    subtitle: String,
-    text: Option<String>,
-    #[serde(rename = "date_human_readable")]
-    date4human: String,
+    items: Vec<ContentTimelineItemParagraph>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
-pub struct ContentHoverCardParagraph {
+pub struct ContentTimelineItemParagraph {
     id: String,
     title: String,
+    subtitle: String,
     text: Option<String>,
-    link: LinkField,
-    media: ImageField,
+    date_range: DateRangeField,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Getters)]
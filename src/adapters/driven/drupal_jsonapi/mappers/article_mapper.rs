@@ -1,9 +1,11 @@
 use lazy_static::lazy_static;
 use regex::Regex;
+use voca_rs::strip::strip_tags;
 
 use crate::adapters::driven::drupal_jsonapi::entities::{ArticleNode, ContentField};
 use crate::adapters::driven::drupal_jsonapi::entities::{ImageField, TagsVocabulary};
 use crate::adapters::driven::drupal_jsonapi::mappers::metatags_field_mapper;
+use crate::adapters::driven::metatags::MetatagsFallback;
 use crate::application::domain::article::{Article, ArticleBuilder, Articles};
 use crate::application::domain::article::{ArticleContent, Category, CategoryBuilder};
 use crate::application::domain::common::{Image, ImageBuilder};
@@ -52,6 +54,12 @@ impl ExternalArticleMapper for ArticleNodeMapper {
 }
 
 fn article_node_mapper(node: ArticleNode) -> Result<Article> {
+    let metatags_fallback = MetatagsFallback::default()
+        .title(node.title())
+        .description(&strip_tags(node.body()))
+        .image(node.thumbnail().media_image().image_style_uri().max_900x550())
+        .path(node.path().alias());
+
     ArticleBuilder::default()
         .id(node.id().to_string().try_into()?)
         .slug(node.path().alias().to_string().try_into()?)
@@ -61,7 +69,7 @@ fn article_node_mapper(node: ArticleNode) -> Result<Article> {
         .created_at(node.created_at().to_string().try_into()?)
         .category(tag_vocabulary_mapper(node.tags().clone()))
         .thumbnail(thumbnail_field_mapper(node.thumbnail()))
-        .metatags(metatags_field_mapper(node.metatags()))
+        .metatags(metatags_field_mapper(node.metatags(), &metatags_fallback)?)
         .content(content_field_mapper(node))
         .build()
         .map_err(|e| AppError::Unexpected(e.to_string()))
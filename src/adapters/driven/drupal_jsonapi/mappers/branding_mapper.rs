@@ -0,0 +1,71 @@
+use voca_rs::escape::unescape_html;
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driven::drupal_jsonapi::entities::{ImageField, SiteBrandingBlock};
+use crate::application::domain::common::{Image, ImageBuilder};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::layout::{SiteBranding, SiteBrandingBuilder};
+
+/// Trait for converting external data into a `SiteBranding` domain entity.
+/// Ensures separation between external data sources and core domain logic.
+///
+/// # Associated Types
+/// - `Input`: The external data type to be transformed into a `SiteBranding`.
+pub trait ExternalSiteBrandingMapper {
+    type Input;
+
+    /// Converts external data into a `SiteBranding`.
+    ///
+    /// # Arguments
+    /// * `input` - The external data to be transformed into a `SiteBranding`.
+    ///
+    /// # Returns
+    /// * `Result<SiteBranding>` - The result of the transformation.
+    fn adapt(&self, input: Self::Input) -> Result<SiteBranding>;
+}
+
+#[derive(Default)]
+pub struct SiteBrandingBlockMapper;
+
+impl ExternalSiteBrandingMapper for SiteBrandingBlockMapper {
+    type Input = SiteBrandingBlock;
+
+    fn adapt(&self, input: Self::Input) -> Result<SiteBranding> {
+        site_branding_block_mapper(input)
+    }
+}
+
+fn site_branding_block_mapper(block: SiteBrandingBlock) -> Result<SiteBranding> {
+    SiteBrandingBuilder::default()
+        .name(strip_tags(block.site_name()).try_into()?)
+        .slogan(
+            block
+                .slogan()
+                .as_deref()
+                .map(|s| unescape_html(&strip_tags(s)).trim().to_string())
+                .filter(|s| !s.is_empty()),
+        )
+        .logo(block.logo().as_ref().map(logo_field_mapper))
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+fn logo_field_mapper(p: &ImageField) -> Image {
+    let url = p
+        .media_image()
+        .image_style_uri()
+        .medium()
+        .to_string()
+        .try_into()
+        .unwrap();
+
+    ImageBuilder::default()
+        .id(p.id().to_string().try_into().unwrap())
+        .title(p.media_image().meta().alt().to_string().try_into().unwrap())
+        .alt(p.media_image().meta().alt().to_string().try_into().unwrap())
+        .height(*p.media_image().meta().height())
+        .width(*p.media_image().meta().width())
+        .url(url)
+        .build()
+        .unwrap()
+}
@@ -1,36 +1,78 @@
-use crate::adapters::driven::drupal_jsonapi::entities::{ MetatagAttributesField, MetatagsField};
-use crate::application::domain::common::{MetaTags, MetaTagsBuilder};
-
-pub fn metatags_field_mapper(metatags: &Vec<MetatagsField>) -> MetaTags {
-    MetaTagsBuilder::default()
-        .title(extract_metatag_content(metatags, "title").unwrap().try_into().unwrap())
-        .description(extract_metatag_content(metatags, "description").unwrap().try_into().unwrap())
-        .keywords(extract_metatag_content(metatags, "keywords").unwrap().try_into().unwrap())
-        .canonical_url(extract_metatag_content(metatags, "canonical").unwrap().try_into().unwrap())
-        .robots(extract_metatag_content(metatags, "robots").unwrap().try_into().unwrap())
-        .og_type(extract_metatag_content(metatags, "og:type").unwrap().try_into().unwrap())
-        .og_site_name(extract_metatag_content(metatags, "og:site_name").unwrap().try_into().unwrap())
-        .og_title(extract_metatag_content(metatags, "og:title").unwrap().try_into().unwrap())
-        .og_description(extract_metatag_content(metatags, "og:description").unwrap().try_into().unwrap())
-        .og_url(extract_metatag_content(metatags, "shortlink").unwrap().try_into().unwrap())
-        .og_image(extract_metatag_content(metatags, "og:image:secure_url").unwrap().try_into().unwrap())
-        .twitter_card(extract_metatag_content(metatags, "twitter:card").unwrap().try_into().unwrap())
-        .twitter_title(extract_metatag_content(metatags, "twitter:title").unwrap().try_into().unwrap())
-        .twitter_creator(extract_metatag_content(metatags, "twitter:site").unwrap().try_into().unwrap())
-        .twitter_description(extract_metatag_content(metatags, "twitter:description").unwrap().try_into().unwrap())
-        .twitter_image(extract_metatag_content(metatags, "og:image:secure_url").unwrap().try_into().unwrap())
-        .twitter_site(extract_metatag_content(metatags, "twitter:site").unwrap().try_into().unwrap())
-        .build()
-        .unwrap()
+use tracing::warn;
+
+use crate::adapters::driven::drupal_jsonapi::entities::{MetatagAttributesField, MetatagsField};
+use crate::adapters::driven::metatags::{metatags_mapper, MetatagsFallback};
+use crate::application::domain::common::MetaTags;
+use crate::application::domain::core::Result;
+
+/// Maps the Drupal metatags into `MetaTags`, the missing or invalid ones fall back to the
+/// values derived from the node and then to the site-wide defaults, a warning lists them.
+pub fn metatags_field_mapper(metatags: &[MetatagsField], fallback: &MetatagsFallback) -> Result<MetaTags> {
+    let (metatags, defaulted) = metatags_mapper(&metatag_contents(metatags), fallback)?;
+
+    if !defaulted.is_empty() {
+        warn!("Metatags defaulted for {}: {}", metatags.canonical_url().as_str(), defaulted.join(", "));
+    }
+
+    Ok(metatags)
 }
 
-fn extract_metatag_content<'a>(metatags: &'a Vec<MetatagsField>, key: &str) -> Option<&'a str> {
-    metatags.iter().find_map(|metatag| {
-        match &metatag.attributes() {
-            MetatagAttributesField::Named { name, content } if name == key => Some(content.as_str()),
-            MetatagAttributesField::Property { property, content } if property == key => Some(content.as_str()),
-            MetatagAttributesField::Link { rel, href } if rel == key => Some(href.as_str()),
-            _ => None
-        }
-    })
-}
\ No newline at end of file
+/// The name and content of every metatag, whatever its kind, e.g. `("canonical", "https://…")`
+/// for a `<link rel="canonical">`.
+fn metatag_contents(metatags: &[MetatagsField]) -> Vec<(&str, &str)> {
+    metatags
+        .iter()
+        .map(|metatag| match metatag.attributes() {
+            MetatagAttributesField::Named { name, content } => (name.as_str(), content.as_str()),
+            MetatagAttributesField::Property { property, content } => (property.as_str(), content.as_str()),
+            MetatagAttributesField::Link { rel, href } => (rel.as_str(), href.as_str()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn fallback_fixture() -> MetatagsFallback {
+        MetatagsFallback::default()
+            .title("Understanding Linked Lists")
+            .description("A linked list is a data structure")
+            .image("https://example.com/thumbnail.png")
+            .path("/en/articles/linked-lists")
+    }
+
+    fn metatags_fixture(file: &str) -> Vec<MetatagsField> {
+        let json = fs::read_to_string(format!("tests/fixtures/{file}")).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn mapper_keeps_every_tag_when_metatags_are_complete() {
+        let metatags = metatags_fixture("metatags_complete.json");
+
+        let m = metatags_field_mapper(&metatags, &fallback_fixture()).unwrap();
+
+        assert_eq!(m.og_type().as_str(), "article");
+        assert_eq!(m.og_url().as_str(), "https://tiagocode.com/node/21");
+        assert_eq!(m.og_image().as_str(), "https://tiagocode.com/files/linked-lists.png");
+        assert_eq!(m.twitter_image().as_str(), "https://tiagocode.com/files/linked-lists-twitter.png");
+        assert_eq!(m.twitter_creator().as_str(), "@smarulanda97");
+        assert_eq!(m.twitter_site().as_str(), "@tiagocode");
+    }
+
+    #[test]
+    fn metatag_contents_are_keyed_by_name_when_metatags_are_of_every_kind() {
+        let metatags = metatags_fixture("metatags_partial.json");
+
+        let contents = metatag_contents(&metatags);
+
+        assert!(contents.contains(&("robots", "noindex, nofollow")));
+        assert!(contents.contains(&("og:image", "https://tiagocode.com/media/21/edit")));
+        assert!(contents.contains(&(
+            "canonical",
+            "https://tiagocode.com/en/articles/programming/understanding-linked-lists"
+        )));
+    }
+}
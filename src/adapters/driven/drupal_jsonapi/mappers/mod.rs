@@ -1,13 +1,17 @@
 mod article_mapper;
+mod branding_mapper;
 mod category_mapper;
 mod navigation_mapper;
 mod portfolio_mapper;
 mod common_mapper;
 mod page_mapper;
+mod project_mapper;
 
 pub use article_mapper::*;
+pub use branding_mapper::*;
 pub use category_mapper::*;
 pub use navigation_mapper::*;
 pub use portfolio_mapper::*;
 pub use common_mapper::*;
-pub use page_mapper::*;
\ No newline at end of file
+pub use page_mapper::*;
+pub use project_mapper::*;
\ No newline at end of file
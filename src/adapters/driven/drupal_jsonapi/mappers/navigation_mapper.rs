@@ -43,29 +43,85 @@ fn external_menu_tree_mapper(menu_items: Navigation) -> Result<MenuTree> {
         .map_err(|e| AppError::Unexpected(e.to_string()))
 }
 
+/// Builds the menu tree, children come either nested in `below` or as flat items
+/// referencing the `key` of their parent. Items whose parent isn't part of the menu
+/// are kept at the first level.
 fn external_menu_items_mapper(menu_items: Navigation) -> Vec<MenuItem> {
+    let items = flatten_menu_items(&menu_items, None);
+    let keys = items.iter().filter_map(|(_, i)| i.key().as_deref()).collect::<Vec<_>>();
+
+    let roots = items
+        .iter()
+        .filter(|(parent, _)| parent.is_none_or(|p| !keys.contains(&p)))
+        .map(|(_, item)| *item)
+        .collect::<Vec<_>>();
+
+    external_menu_level_mapper(roots, &items)
+}
+
+fn flatten_menu_items<'a>(
+    menu_items: &'a [NavigationItem],
+    parent: Option<&'a str>,
+) -> Vec<(Option<&'a str>, &'a NavigationItem)> {
     menu_items
         .iter()
-        .filter(|item| item.enabled().clone())
-        .sorted_by(|a, b| a.weight().cmp(b.weight()))
-        .map(external_menu_item_mapper)
-        .collect::<Vec<_>>()
+        .flat_map(|item| {
+            let item_parent = parent.or(item.parent().as_deref().filter(|p| !p.is_empty()));
+            let below = item.below().as_deref().unwrap_or_default();
+
+            std::iter::once((item_parent, item)).chain(flatten_menu_items(below, item.key().as_deref()))
+        })
+        .collect()
+}
+
+fn external_menu_level_mapper(
+    level: Vec<&NavigationItem>,
+    items: &[(Option<&str>, &NavigationItem)],
+) -> Vec<MenuItem> {
+    level
         .into_iter()
+        .filter(|item| *item.enabled())
+        .sorted_by(|a, b| a.weight().cmp(b.weight()))
+        .map(|item| {
+            let children = items
+                .iter()
+                .filter(|(parent, _)| parent.is_some() && *parent == item.key().as_deref())
+                .map(|(_, child)| *child)
+                .collect::<Vec<_>>();
+
+            external_menu_item_mapper(item, external_menu_level_mapper(children, items))
+        })
         .collect()
 }
 
-fn external_menu_item_mapper(item: &NavigationItem) -> MenuItem {
+fn external_menu_item_mapper(item: &NavigationItem, children: Vec<MenuItem>) -> MenuItem {
     MenuItemBuilder::default()
-        .id(Uuid::new_v4().to_string().try_into().unwrap())
+        .id(external_id_mapper(item).try_into().unwrap())
         .title(item.title().to_string())
         .url(external_url_mapper(item).try_into().unwrap())
-        .hidden(!item.enabled().clone())
-        .weight(item.weight().clone())
+        .hidden(!*item.enabled())
+        .weight(*item.weight())
         .icon(external_icon_mapper(item))
+        .external(*item.external())
+        .expanded(*item.expanded())
+        .children(children)
         .build()
         .unwrap()
 }
 
+/// Keeps the id stable between the server render and the hydration, Drupal keys look like
+/// `menu_link_content:<uuid>` or `standard.front_page`, the latter get a name based uuid.
+fn external_id_mapper(item: &NavigationItem) -> String {
+    let key = item.key().clone().unwrap_or(format!("{}:{}", item.title(), item.uri()));
+
+    item.uuid()
+        .as_deref()
+        .or(key.rsplit_once(':').map(|(_, uuid)| uuid))
+        .and_then(|uuid| Uuid::parse_str(uuid).ok())
+        .unwrap_or(Uuid::new_v5(&Uuid::NAMESPACE_OID, key.as_bytes()))
+        .to_string()
+}
+
 fn external_icon_mapper(item: &NavigationItem) -> Option<Image> {
     item.field_image()
         .as_ref()
@@ -74,7 +130,7 @@ fn external_icon_mapper(item: &NavigationItem) -> Option<Image> {
 }
 
 fn external_url_mapper(item: &NavigationItem) -> String {
-    if item.external().clone() {
+    if *item.external() {
         item.absolute().to_string()
     } else {
         item.relative().to_string()
@@ -83,7 +139,7 @@ fn external_url_mapper(item: &NavigationItem) -> String {
 
 fn external_image_mapper(image: &NavigationImageMetadata) -> Image {
     ImageBuilder::default()
-        .id(Uuid::new_v4().to_string().try_into().unwrap())
+        .id(image.target_uuid().to_string().try_into().unwrap())
         .url(image.url().to_string().try_into().unwrap())
         .alt(image.alt().to_string().try_into().unwrap())
         .title(image.alt().to_string().try_into().unwrap())
@@ -92,3 +148,73 @@ fn external_image_mapper(image: &NavigationImageMetadata) -> Image {
         .build()
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn navigation_item(key: &str, title: &str, weight: &str, parent: Option<&str>) -> serde_json::Value {
+        json!({
+            "key": key,
+            "title": title,
+            "uri": format!("internal:/{}", title.to_lowercase()),
+            "absolute": format!("https://example.com/{}", title.to_lowercase()),
+            "relative": format!("/{}", title.to_lowercase()),
+            "weight": weight,
+            "uuid": null,
+            "enabled": true,
+            "expanded": false,
+            "external": false,
+            "parent": parent,
+        })
+    }
+
+    #[test]
+    fn adapt_succeeds_when_children_reference_their_parent() {
+        let navigation: Navigation = serde_json::from_value(json!([
+            navigation_item("menu_link_content:0b9bb3f4-5d4c-4b8a-9f3e-1a2b3c4d5e01", "Blog", "1", None),
+            navigation_item("standard.front_page", "Home", "0", None),
+            navigation_item(
+                "menu_link_content:0b9bb3f4-5d4c-4b8a-9f3e-1a2b3c4d5e02",
+                "Rust",
+                "0",
+                Some("menu_link_content:0b9bb3f4-5d4c-4b8a-9f3e-1a2b3c4d5e01"),
+            ),
+        ]))
+        .unwrap();
+
+        let menu = NavigationAdapter.adapt(navigation).unwrap();
+
+        assert_eq!(menu.items().len(), 2);
+        assert_eq!(menu.items()[0].title(), "Home");
+        assert_eq!(menu.items()[1].title(), "Blog");
+        assert_eq!(menu.items()[1].id().to_string(), "0b9bb3f4-5d4c-4b8a-9f3e-1a2b3c4d5e01");
+        assert_eq!(menu.items()[1].children()[0].title(), "Rust");
+        assert!(menu.items()[1].has_children());
+    }
+
+    #[test]
+    fn adapt_succeeds_when_children_are_nested_below() {
+        let mut blog = navigation_item("menu_link_content:0b9bb3f4-5d4c-4b8a-9f3e-1a2b3c4d5e01", "Blog", "0", None);
+        blog["below"] = json!([navigation_item("standard.rust", "Rust", "0", None)]);
+        let navigation: Navigation = serde_json::from_value(json!([blog])).unwrap();
+
+        let menu = NavigationAdapter.adapt(navigation).unwrap();
+
+        assert_eq!(menu.items().len(), 1);
+        assert_eq!(menu.items()[0].children().len(), 1);
+    }
+
+    #[test]
+    fn adapt_keeps_ids_stable_when_keys_are_not_uuids() {
+        let navigation = || -> Navigation {
+            serde_json::from_value(json!([navigation_item("standard.front_page", "Home", "0", None)])).unwrap()
+        };
+
+        let first = NavigationAdapter.adapt(navigation()).unwrap();
+        let second = NavigationAdapter.adapt(navigation()).unwrap();
+
+        assert_eq!(first.items()[0].id(), second.items()[0].id());
+    }
+}
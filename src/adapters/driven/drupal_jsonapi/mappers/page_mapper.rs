@@ -1,5 +1,8 @@
+use voca_rs::strip::strip_tags;
+
 use crate::adapters::driven::drupal_jsonapi::entities::PageNode;
 use crate::adapters::driven::drupal_jsonapi::mappers::metatags_field_mapper;
+use crate::adapters::driven::metatags::MetatagsFallback;
 use crate::application::domain::core::{AppError, Result};
 use crate::application::domain::page::{Page, PageBuilder};
 
@@ -33,12 +36,18 @@ impl ExternalPageAdapter for PageNodeMapper {
 }
 
 fn page_node_mapper(node: PageNode) -> Result<Page> {
+    let metatags_fallback = MetatagsFallback::default()
+        .title(node.title())
+        .description(&strip_tags(node.body()))
+        .path(node.path().alias());
+
     PageBuilder::default()
         .id(node.id().to_string().try_into()?)
+        .slug(node.path().alias().to_string().try_into()?)
         .status(node.status().clone().into())
         .title(node.title().to_string().try_into()?)
         .created_at(node.created_at().to_string().try_into()?)
-        .metatags(metatags_field_mapper(node.metatags()))
+        .metatags(metatags_field_mapper(node.metatags(), &metatags_fallback)?)
         .build()
         .map_err(|e| AppError::Unexpected(e.to_string()))
 }
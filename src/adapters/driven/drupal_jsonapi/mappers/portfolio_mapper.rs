@@ -4,13 +4,13 @@ use crate::adapters::driven::drupal_jsonapi::entities::{ContentTimelineItemParag
 use crate::adapters::driven::drupal_jsonapi::entities::{ContentField, DocumentField};
 use crate::adapters::driven::drupal_jsonapi::entities::{ImageField, PortfolioNode};
 use crate::adapters::driven::drupal_jsonapi::mappers::metatags_field_mapper;
+use crate::adapters::driven::metatags::MetatagsFallback;
 use crate::application::domain::common::{Document, DocumentBuilder, Image, ImageBuilder};
-use crate::application::domain::common::{LinkBuilder, Project, ProjectBuilder};
 use crate::application::domain::common::{Timeline, TimelineBuilder};
 use crate::application::domain::common::{TimelineItem, TimelineItemBuilder};
 use crate::application::domain::core::{AppError, Result};
 use crate::application::domain::portfolio::{Portfolio, PortfolioBuilder, PortfolioSection};
-use crate::application::domain::portfolio::{AboutMeBuilder, BlogsBuilder};
+use crate::application::domain::portfolio::{AboutMeBuilder, BlogsBuilder, ContactBuilder};
 use crate::application::domain::portfolio::{ProjectsBuilder, ResumeBuilder};
 
 /// Trait for converting external data into a `Portfolio` domain entity.
@@ -43,13 +43,17 @@ impl ExternalPortfolioAdapter for PortfolioNodeMapper {
 }
 
 fn portfolio_node_mapper(node: PortfolioNode) -> Result<Portfolio> {
+    let metatags_fallback = MetatagsFallback::default()
+        .title(node.title())
+        .path(node.path().alias());
+
     PortfolioBuilder::default()
         .id(node.id().to_string().try_into()?)
         .status(node.status().clone().into())
         .title(node.title().to_string().try_into()?)
         .created_at(node.created_at().to_string().try_into()?)
         .sections(node.content().iter().map(content_elements_mapper).collect())
-        .metatags(metatags_field_mapper(node.metatags()))
+        .metatags(metatags_field_mapper(node.metatags(), &metatags_fallback)?)
         .build()
         .map_err(|e| AppError::Unexpected(e.to_string()))
 }
@@ -60,6 +64,7 @@ fn content_elements_mapper(content: &ContentField) -> PortfolioSection {
         ContentField::PortfolioResumeParagraph(_) => resume_paragraph_mapper(&content),
         ContentField::PortfolioProjectsParagraph(_) => projects_paragraph_mapper(&content),
         ContentField::PortfolioArticlesParagraph(_) => blog_paragraph_mapper(&content),
+        ContentField::PortfolioContactParagraph(_) => contact_paragraph_mapper(content),
         _ => PortfolioSection::Unknown,
     }
 }
@@ -80,6 +85,21 @@ fn blog_paragraph_mapper(p: &ContentField) -> PortfolioSection {
     PortfolioSection::Unknown
 }
 
+fn contact_paragraph_mapper(p: &ContentField) -> PortfolioSection {
+    if let ContentField::PortfolioContactParagraph(p) = p {
+        return PortfolioSection::Contact(
+            ContactBuilder::default()
+                .id(p.id().to_string().try_into().unwrap())
+                .title(strip_tags(p.title()).try_into().unwrap())
+                .subtitle(strip_tags(p.subtitle()).try_into().unwrap())
+                .text(strip_tags(p.text().clone().unwrap_or_default().as_str()))
+                .build()
+                .unwrap(),
+        );
+    }
+    PortfolioSection::Unknown
+}
+
 fn about_me_paragraph_mapper(p: &ContentField) -> PortfolioSection {
     if let ContentField::PortfolioAboutMeParagraph(p) = p {
         return PortfolioSection::AboutMe(
@@ -132,19 +152,13 @@ fn resume_paragraph_mapper(p: &ContentField) -> PortfolioSection {
 
 fn projects_paragraph_mapper(p: &ContentField) -> PortfolioSection {
     if let ContentField::PortfolioProjectsParagraph(p) = p {
-        let projects = p
-            .items()
-            .iter()
-            .map(project_item_paragraph_mapper)
-            .collect();
-
         return PortfolioSection::Projects(
             ProjectsBuilder::default()
                 .id(p.id().to_string().try_into().unwrap())
                 .title(strip_tags(p.title()).try_into().unwrap())
                 .subtitle(strip_tags(p.subtitle()).try_into().unwrap())
                 .text(strip_tags(p.text()).try_into().unwrap())
-                .projects(projects)
+                .projects(vec![]) // empty at the beginning, will be filled later
                 .build()
                 .unwrap(),
         );
@@ -217,31 +231,16 @@ fn timeline_paragraph_mapper(s: &ContentField) -> Timeline {
 fn timeline_item_paragraph_mapper(s: &ContentTimelineItemParagraph) -> TimelineItem {
     TimelineItemBuilder::default()
         .id(s.id().to_string().try_into().unwrap())
-        .date(s.date4human().to_string().try_into().unwrap())
+        .start_date(s.date_range().value().to_string().try_into().unwrap())
+        .end_date(
+            s.date_range()
+                .end_value()
+                .as_ref()
+                .map(|end| end.to_string().try_into().unwrap()),
+        )
         .title(s.title().to_string().try_into().unwrap())
         .subtitle(s.subtitle().to_string().try_into().unwrap())
         .text(s.text().clone())
         .build()
         .unwrap()
 }
-
-fn project_item_paragraph_mapper(s: &ContentField) -> Project {
-    if let ContentField::ContentProjectParagraph(p) = s {
-        let link = LinkBuilder::default()
-            .url(p.link().uri().to_string().try_into().unwrap())
-            .title(p.link().title().to_string().try_into().unwrap())
-            .options(vec![])
-            .build()
-            .unwrap();
-
-        return ProjectBuilder::default()
-            .id(p.id().to_string().try_into().unwrap())
-            .title(strip_tags(p.title()).try_into().unwrap())
-            .text(strip_tags(p.text().clone().unwrap_or_default().as_str()))
-            .link(link)
-            .image(image_field_mapper(p.media()))
-            .build()
-            .unwrap();
-    }
-    ProjectBuilder::default().build().unwrap()
-}
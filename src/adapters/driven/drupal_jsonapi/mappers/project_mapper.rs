@@ -0,0 +1,119 @@
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driven::drupal_jsonapi::entities::{ImageField, LinkField, ProjectNode};
+use crate::adapters::driven::drupal_jsonapi::mappers::metatags_field_mapper;
+use crate::adapters::driven::metatags::MetatagsFallback;
+use crate::application::domain::common::{Image, ImageBuilder, Link, LinkBuilder};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::project::{Gallery, GalleryBuilder, Project, ProjectBuilder};
+
+/// Trait for converting external data into a `Project` domain entity.
+/// Ensures separation between external data sources and core domain logic.
+///
+/// # Associated Types
+/// - `Input`: The external data type to be transformed into a `Project`.
+pub trait ExternalProjectMapper {
+    type Input;
+
+    /// Converts external data into a `Project`.
+    ///
+    /// # Arguments
+    /// * `input` - The external data to be transformed into a `Project`.
+    ///
+    /// # Returns
+    /// * `Result<Project>` - The result of the transformation.
+    fn adapt(&self, input: Self::Input) -> Result<Project>;
+
+    fn adapt_multiple(&self, input: Vec<Self::Input>) -> Result<Vec<Project>> {
+        input
+            .into_iter()
+            .map(|project| self.adapt(project))
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectNodeMapper;
+
+impl ExternalProjectMapper for ProjectNodeMapper {
+    type Input = ProjectNode;
+
+    fn adapt(&self, input: Self::Input) -> Result<Project> {
+        project_node_mapper(input)
+    }
+}
+
+fn project_node_mapper(node: ProjectNode) -> Result<Project> {
+    let end_date = match node.date_range().end_value() {
+        Some(end) => Some(end.to_string().try_into()?),
+        None => None,
+    };
+
+    let metatags_fallback = MetatagsFallback::default()
+        .title(node.title())
+        .description(&strip_tags(node.summary()))
+        .image(node.thumbnail().media_image().image_style_uri().max_900x550())
+        .path(node.path().alias());
+
+    ProjectBuilder::default()
+        .id(node.id().to_string().try_into()?)
+        .slug(node.path().alias().to_string().try_into()?)
+        .status((*node.status()).into())
+        .title(node.title().to_string().try_into()?)
+        .text(strip_tags(node.summary()))
+        .body(node.body().to_string())
+        .role(node.role().clone().filter(|r| !r.trim().is_empty()))
+        .technologies(node.technologies().iter().map(|t| t.trim().to_string()).collect())
+        .start_date(node.date_range().value().to_string().try_into()?)
+        .end_date(end_date)
+        .link(node.link().as_ref().map(link_field_mapper))
+        .image(image_field_mapper(node.thumbnail()))
+        .gallery(gallery_field_mapper(node.gallery()))
+        .metatags(metatags_field_mapper(node.metatags(), &metatags_fallback)?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+fn link_field_mapper(link: &LinkField) -> Link {
+    LinkBuilder::default()
+        .url(link.uri().to_string().try_into().unwrap())
+        .title(link.title().to_string())
+        .options(vec![])
+        .build()
+        .unwrap()
+}
+
+fn gallery_field_mapper(gallery: &[ImageField]) -> Gallery {
+    GalleryBuilder::default()
+        .thumbnails(gallery.iter().map(thumbnail_field_mapper).collect())
+        .images(gallery.iter().map(gallery_image_field_mapper).collect())
+        .build()
+        .unwrap()
+}
+
+fn image_field_mapper(p: &ImageField) -> Image {
+    let url = p.media_image().image_style_uri().thumbnail_664x410().to_string();
+    image_style_mapper(p, url)
+}
+
+fn gallery_image_field_mapper(p: &ImageField) -> Image {
+    let url = p.media_image().image_style_uri().max_900x550().to_string();
+    image_style_mapper(p, url)
+}
+
+fn thumbnail_field_mapper(p: &ImageField) -> Image {
+    let url = p.media_image().image_style_uri().thumbnail_260x210().to_string();
+    image_style_mapper(p, url)
+}
+
+fn image_style_mapper(p: &ImageField, url: String) -> Image {
+    ImageBuilder::default()
+        .id(p.id().to_string().try_into().unwrap())
+        .title(p.media_image().meta().alt().to_string().try_into().unwrap())
+        .alt(p.media_image().meta().alt().to_string().try_into().unwrap())
+        .height(*p.media_image().meta().height())
+        .width(*p.media_image().meta().width())
+        .url(url.try_into().unwrap())
+        .build()
+        .unwrap()
+}
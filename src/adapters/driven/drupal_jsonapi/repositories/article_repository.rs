@@ -5,23 +5,22 @@ use crate::adapters::driven::drupal_jsonapi::entities::NodeArticleResource;
 use crate::adapters::driven::drupal_jsonapi::entities::{ArticleNode, NodeArticleCollection};
 use crate::adapters::driven::drupal_jsonapi::mappers::ArticleNodeMapper;
 use crate::adapters::driven::drupal_jsonapi::mappers::ExternalArticleMapper;
-use crate::adapters::driven::drupal_jsonapi::services::JsonApiClientService;
+use crate::adapters::driven::drupal_jsonapi::services::{Condition, Direction, JsonApiClientService, JsonApiQuery};
 use crate::application::domain::article::{Article, Articles};
 use crate::application::domain::core::{AppError, Result};
 use crate::application::ports::driven::ForFetchingArticlesFeatured;
 use crate::application::ports::driven::{ForFetchingArticleData, ForFetchingArticlesList};
 use crate::utilities::HttpClient;
 
-const COLLECTION_QUERY: &str = "\
-    include=tags,content.media.media_image,thumbnail.media_image,content.media_list.media_image\
-    &filter[status]=1&sort=created,title\
-    &page[limit]=2\
-    &sort[sort-created][path]=created&sort[sort-created][direction]=desc\
-    &jsonapi_include=1";
+/// The published articles, the newest first.
+fn collection_query() -> JsonApiQuery {
+    resource_query().filter("status", 1).sort("created", Direction::Desc)
+}
 
-const RESOURCE_QUERY: &str = "\
-    include=tags,content.media.media_image,thumbnail.media_image,content.media_list.media_image\
-    &jsonapi_include=1";
+fn resource_query() -> JsonApiQuery {
+    JsonApiQuery::default()
+        .include(&["tags", "content.media.media_image", "thumbnail.media_image", "content.media_list.media_image"])
+}
 
 /// Repository for fetching and transforming article data from an external CMS API.
 ///
@@ -48,8 +47,10 @@ impl ArticleRepository {
 #[async_trait(?Send)]
 impl ForFetchingArticlesFeatured for ArticleRepository {
     async fn get_featured(&self) -> Result<Articles> {
-        let endpoint =
-            &format!("/jsonapi/node/article?{COLLECTION_QUERY}&filter[promoted]=1&page[limit]=2");
+        let endpoint = &collection_query()
+            .filter("promoted", 1)
+            .page_limit(2)
+            .endpoint("/jsonapi/node/article");
 
         let external_articles = self
             .api_client
@@ -58,8 +59,8 @@ impl ForFetchingArticlesFeatured for ArticleRepository {
             .map_err(|e| AppError::External(type_name::<Self>(), e.to_string()))?;
 
         let articles = self
-            .api_adapter
-            .adapt_multiple(external_articles.data().clone())?;
+            .api_client
+            .track_mapping(endpoint, self.api_adapter.adapt_multiple(external_articles.data().clone()))?;
 
         Ok(articles)
     }
@@ -69,12 +70,14 @@ impl ForFetchingArticlesFeatured for ArticleRepository {
 impl ForFetchingArticlesList for ArticleRepository {
     async fn get_list(&self, category_id: Option<String>) -> Result<Vec<Article>> {
         let adapter = type_name::<Self>();
-        let mut endpoint = format!("/jsonapi/node/article?{COLLECTION_QUERY}&page[limit]=10");
+        let mut query = collection_query().page_limit(10);
 
         if let Some(category) = category_id {
-            endpoint.push_str(&format!("&filter[tags][condition][path]=tags.machine_name&filter[tags][condition][value]={category}"));
+            query = query.condition("tags", Condition::equal("tags.machine_name", category));
         }
 
+        let endpoint = query.endpoint("/jsonapi/node/article");
+
         let articles = self
             .api_client
             .get_external_data::<NodeArticleCollection>(endpoint.as_str())
@@ -82,11 +85,24 @@ impl ForFetchingArticlesList for ArticleRepository {
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
         Ok(self
-            .api_adapter
-            .adapt_multiple(articles.data().clone())?
+            .api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt_multiple(articles.data().clone()))?
             .into_iter()
             .collect())
     }
+
+    async fn get_all(&self) -> Result<Vec<Article>> {
+        let endpoint = collection_query().page_limit(50).endpoint("/jsonapi/node/article");
+
+        let articles = self
+            .api_client
+            .get_all_external_data::<ArticleNode>(&endpoint)
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e))?;
+
+        self.api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt_multiple(articles))
+    }
 }
 
 #[async_trait(?Send)]
@@ -96,9 +112,8 @@ impl ForFetchingArticleData for ArticleRepository {
         let endpoint = self
             .api_client
             .resolve_external_endpoint(slug)
-            .await
-            .map_err(|e| AppError::External(adapter, e.to_string()))?;
-        let endpoint = &format!("{endpoint}?{RESOURCE_QUERY}");
+            .await?;
+        let endpoint = &resource_query().endpoint(&endpoint);
 
         let article = self
             .api_client
@@ -106,9 +121,85 @@ impl ForFetchingArticleData for ArticleRepository {
             .await
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
-        Ok(self.api_adapter.adapt(article.data().clone())?)
+        Ok(self
+            .api_client
+            .track_mapping(endpoint, self.api_adapter.adapt(article.data().clone()))?)
     }
 }
 
 #[cfg(test)]
-pub mod tests {}
+pub mod tests {
+    use super::*;
+    use mockito::Matcher::{AllOf, UrlEncoded};
+    use mockito::Server;
+    use serde_json::{json, Value};
+
+    use crate::utilities::tests::http_client_mock;
+
+    #[actix_rt::test]
+    async fn list_sends_encoded_filters_when_category_is_given() {
+        let mut server = Server::new_async().await;
+        let collection_mock = server
+            .mock("GET", "/jsonapi/node/article")
+            .match_query(AllOf(vec![
+                UrlEncoded("filter[status]".to_string(), "1".to_string()),
+                UrlEncoded("filter[tags][condition][value]".to_string(), "c++ & rust".to_string()),
+                UrlEncoded("sort".to_string(), "-created".to_string()),
+                UrlEncoded("page[limit]".to_string(), "10".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/vnd.api+json")
+            .with_body(r#"{"jsonapi": {"version": "1.0"}, "data": [], "links": {"self": {"href": ""}}}"#)
+            .create_async()
+            .await;
+
+        let articles = ArticleRepository::new(http_client_mock(&server.url()))
+            .get_list(Some("c++ & rust".to_string()))
+            .await
+            .unwrap();
+
+        assert!(collection_mock.matched_async().await);
+        assert!(articles.is_empty());
+    }
+
+    /// A collection page holding the fixture article `count` times, linking to `next`.
+    fn collection_page(count: usize, next: Option<String>) -> String {
+        let mut collection =
+            serde_json::from_str::<Value>(&std::fs::read_to_string("tests/fixtures/http_article_collection_200.json").unwrap())
+                .unwrap();
+        collection["data"] = Value::Array(vec![collection["data"][0].clone(); count]);
+        collection["links"]["next"] = next.map(|href| json!({ "href": href })).unwrap_or(Value::Null);
+
+        collection.to_string()
+    }
+
+    #[actix_rt::test]
+    async fn get_all_succeeds_when_collection_has_more_pages() {
+        let mut server = Server::new_async().await;
+        let first_page_mock = server
+            .mock("GET", "/jsonapi/node/article")
+            .match_query(AllOf(vec![
+                UrlEncoded("sort".to_string(), "-created".to_string()),
+                UrlEncoded("page[limit]".to_string(), "50".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/vnd.api+json")
+            .with_body(collection_page(10, Some(format!("{}/jsonapi/node/article?page%5Boffset%5D=10", server.url()))))
+            .create_async()
+            .await;
+        let last_page_mock = server
+            .mock("GET", "/jsonapi/node/article")
+            .match_query(UrlEncoded("page[offset]".to_string(), "10".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/vnd.api+json")
+            .with_body(collection_page(2, None))
+            .create_async()
+            .await;
+
+        let articles = ArticleRepository::new(http_client_mock(&server.url())).get_all().await.unwrap();
+
+        assert!(first_page_mock.matched_async().await);
+        assert!(last_page_mock.matched_async().await);
+        assert_eq!(articles.len(), 12);
+    }
+}
@@ -1,20 +1,22 @@
 use async_trait::async_trait;
 use std::any::type_name;
 
-use crate::adapters::driven::drupal_jsonapi::entities::{TagsVocabulary, VocabularyTagCollection};
+use crate::adapters::driven::drupal_jsonapi::entities::TagsVocabulary;
 use crate::adapters::driven::drupal_jsonapi::mappers::ExternalCategoryMapper;
 use crate::adapters::driven::drupal_jsonapi::mappers::ExternalTagsVocabularyMapper;
-use crate::adapters::driven::drupal_jsonapi::services::JsonApiClientService;
+use crate::adapters::driven::drupal_jsonapi::services::{Direction, JsonApiClientService, JsonApiQuery};
 use crate::application::domain::article::Category;
 use crate::application::domain::core::AppError;
 use crate::application::ports::driven::ForFetchingCategoriesList;
 use crate::utilities::HttpClient;
 
-const COLLECTION_QUERY: &str = "\
-    &filter[status]=1\
-    &page[limit]=10\
-    &sort[sort-created][path]=name&sort[sort-created][direction]=asc\
-    &jsonapi_include=1";
+/// The published categories, sorted by name.
+fn collection_query() -> JsonApiQuery {
+    JsonApiQuery::default()
+        .filter("status", 1)
+        .sort("name", Direction::Asc)
+        .page_limit(10)
+}
 
 /// Repository for fetching and transforming category data from an external API.
 ///
@@ -39,17 +41,17 @@ impl CategoryRepository {
 impl ForFetchingCategoriesList for CategoryRepository {
     async fn find_all_categories(&self) -> crate::application::domain::core::Result<Vec<Category>> {
         let adapter = type_name::<Self>();
-        let endpoint = &format!("/jsonapi/taxonomy_term/tags?{COLLECTION_QUERY}");
+        let endpoint = &collection_query().endpoint("/jsonapi/taxonomy_term/tags");
 
         let categories = self
             .api_client
-            .get_external_data::<VocabularyTagCollection>(endpoint)
+            .get_all_external_data::<TagsVocabulary>(endpoint)
             .await
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
         Ok(self
-            .api_adapter
-            .adapt_multiple(categories.data().clone())?
+            .api_client
+            .track_mapping(endpoint, self.api_adapter.adapt_multiple(categories))?
             .into_iter()
             .collect())
     }
@@ -1,21 +1,31 @@
 use async_trait::async_trait;
 use std::any::type_name;
 
-use crate::adapters::driven::drupal_jsonapi::entities::Navigation;
+use crate::adapters::driven::drupal_jsonapi::entities::{BlockSiteBrandingCollection, Navigation, SiteBrandingBlock};
 use crate::adapters::driven::drupal_jsonapi::mappers::{ExternalMenuTreeMapper, NavigationAdapter};
-use crate::adapters::driven::drupal_jsonapi::services::JsonApiClientService;
+use crate::adapters::driven::drupal_jsonapi::mappers::{ExternalSiteBrandingMapper, SiteBrandingBlockMapper};
+use crate::adapters::driven::drupal_jsonapi::services::{JsonApiClientService, JsonApiQuery};
 use crate::application::domain::core::{AppError, Result};
-use crate::application::domain::layout::MenuTree;
-use crate::application::ports::driven::ForFetchingMenuData;
+use crate::application::domain::layout::{MenuTree, SiteBranding};
+use crate::application::ports::driven::{ForFetchingMenuData, ForFetchingSiteBranding};
 use crate::utilities::HttpClient;
 
+/// The site branding block, there's a single one.
+fn branding_query() -> JsonApiQuery {
+    JsonApiQuery::default()
+        .include(&["logo.media_image"])
+        .page_limit(1)
+}
+
 /// Repository for fetching and transforming menu data from an external CMS API.
 ///
-/// This struct implements the `ForFetchingMenuData` output port of the hexagonal architecture
-/// by integrating with a CMS API client to retrieve menu items and transform them into domain entities.
+/// This struct implements the `ForFetchingMenuData` and `ForFetchingSiteBranding` output ports of the
+/// hexagonal architecture by integrating with a CMS API client to retrieve menu items and the site
+/// branding block and transform them into domain entities.
 pub struct LayoutRepository {
     api_client: Box<JsonApiClientService>,
     api_mapper: Box<(dyn ExternalMenuTreeMapper<Input = Navigation>)>,
+    branding_mapper: Box<dyn ExternalSiteBrandingMapper<Input = SiteBrandingBlock>>,
 }
 
 impl LayoutRepository {
@@ -23,6 +33,7 @@ impl LayoutRepository {
         Self {
             api_client: Box::new(JsonApiClientService::new(http_client)),
             api_mapper: Box::new(NavigationAdapter::default()),
+            branding_mapper: Box::new(SiteBrandingBlockMapper),
         }
     }
 }
@@ -31,12 +42,68 @@ impl LayoutRepository {
 impl ForFetchingMenuData for LayoutRepository {
     async fn find_by_id(&self, id: &str) -> Result<MenuTree> {
         let adapter = type_name::<Self>();
+        let endpoint = format!("/api/menu_items/{id}");
         let menu_tree = self
             .api_client
-            .get_external_data::<Navigation>(&format!("/api/menu_items/{id}"))
+            .get_external_data::<Navigation>(&endpoint)
             .await
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
-        Ok(self.api_mapper.adapt(menu_tree)?)
+        Ok(self
+            .api_client
+            .track_mapping(&endpoint, self.api_mapper.adapt(menu_tree))?)
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingSiteBranding for LayoutRepository {
+    async fn get_branding(&self) -> Result<SiteBranding> {
+        let endpoint = branding_query().endpoint("/jsonapi/block_content/site_branding");
+
+        let blocks = self
+            .api_client
+            .get_external_data::<BlockSiteBrandingCollection>(&endpoint)
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e))?;
+
+        let block = blocks
+            .data()
+            .first()
+            .cloned()
+            .ok_or(AppError::ResourceNotFound("site_branding".to_string()))?;
+
+        self.api_client
+            .track_mapping(&endpoint, self.branding_mapper.adapt(block))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher::Regex;
+    use mockito::Server;
+
+    use crate::utilities::tests::http_client_mock;
+
+    #[actix_rt::test]
+    async fn branding_succeeds_when_block_is_returned() {
+        let mut server = Server::new_async().await;
+        let branding_mock = server
+            .mock("GET", Regex("/jsonapi/block_content/site_branding".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/vnd.api+json")
+            .with_body_from_file("tests/fixtures/http_site_branding_collection_200.json")
+            .create_async()
+            .await;
+
+        let branding = LayoutRepository::new(http_client_mock(&server.url()))
+            .get_branding()
+            .await
+            .unwrap();
+
+        assert!(branding_mock.matched_async().await);
+        assert_eq!(branding.name().as_str(), "Tiagocode");
+        assert_eq!(branding.slogan().as_deref(), Some("Software engineer & open source enthusiast"));
+        assert!(branding.logo().is_some());
     }
 }
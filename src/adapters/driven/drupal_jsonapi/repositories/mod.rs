@@ -3,9 +3,11 @@ pub mod category_repository;
 pub mod layout_repository;
 pub mod portfolio_repository;
 pub mod page_repository;
+pub mod project_repository;
 
 pub use article_repository::ArticleRepository;
 pub use category_repository::CategoryRepository;
 pub use layout_repository::LayoutRepository;
 pub use portfolio_repository::PortfolioRepository;
-pub use page_repository::PageRepository;
\ No newline at end of file
+pub use page_repository::PageRepository;
+pub use project_repository::ProjectRepository;
\ No newline at end of file
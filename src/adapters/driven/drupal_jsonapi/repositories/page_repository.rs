@@ -3,13 +3,16 @@ use std::any::type_name;
 
 use crate::adapters::driven::drupal_jsonapi::entities::{NodePageResource, PageNode};
 use crate::adapters::driven::drupal_jsonapi::mappers::{ExternalPageAdapter, PageNodeMapper};
-use crate::adapters::driven::drupal_jsonapi::services::JsonApiClientService;
+use crate::adapters::driven::drupal_jsonapi::services::{JsonApiClientService, JsonApiQuery};
 use crate::application::domain::core::{AppError, Result};
 use crate::application::domain::page::Page;
 use crate::application::ports::driven::ForFetchingPageData;
 use crate::utilities::HttpClient;
 
-const RESOURCE_QUERY: &str = "jsonapi_include=1";
+/// The published pages.
+fn collection_query() -> JsonApiQuery {
+    JsonApiQuery::default().filter("status", 1).page_limit(50)
+}
 
 /// Repository for fetching and transforming portfolio data from an external API.
 ///
@@ -36,10 +39,7 @@ impl ForFetchingPageData for PageRepository {
         let endpoint = self
             .api_client
             .resolve_external_endpoint(slug)
-            .await
-            .map_err(|e| AppError::External(adapter, e.to_string()))?;
-
-        let endpoint = format!("{endpoint}?{RESOURCE_QUERY}");
+            .await?;
 
         let portfolio = self
             .api_client
@@ -47,6 +47,23 @@ impl ForFetchingPageData for PageRepository {
             .await
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
-        Ok(self.api_adapter.adapt(portfolio.data().clone())?)
+        Ok(self
+            .api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt(portfolio.data().clone()))?)
+    }
+
+    async fn get_list(&self) -> Result<Vec<Page>> {
+        let endpoint = collection_query().endpoint("/jsonapi/node/page");
+
+        let pages = self
+            .api_client
+            .get_all_external_data::<PageNode>(&endpoint)
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e))?;
+
+        self.api_client.track_mapping(
+            &endpoint,
+            pages.into_iter().map(|page| self.api_adapter.adapt(page)).collect(),
+        )
     }
 }
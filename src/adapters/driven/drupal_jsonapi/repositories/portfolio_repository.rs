@@ -4,15 +4,22 @@ use std::any::type_name;
 use crate::adapters::driven::drupal_jsonapi::entities::{NodePortfolioResource, PortfolioNode};
 use crate::adapters::driven::drupal_jsonapi::mappers::ExternalPortfolioAdapter;
 use crate::adapters::driven::drupal_jsonapi::mappers::PortfolioNodeMapper;
-use crate::adapters::driven::drupal_jsonapi::services::JsonApiClientService;
+use crate::adapters::driven::drupal_jsonapi::services::{JsonApiClientService, JsonApiQuery};
 use crate::application::domain::core::{AppError, Result};
 use crate::application::domain::portfolio::Portfolio;
 use crate::application::ports::driven::ForFetchingPortfolioData;
 use crate::utilities::HttpClient;
 
-const RESOURCE_QUERY: &str = "\
-    include=content,content.document.media_document,content.image.media_image,content.items.media.media_image,content.items.items\
-    &jsonapi_include=1";
+fn resource_query() -> JsonApiQuery {
+    JsonApiQuery::default()
+        .include(&[
+            "content",
+            "content.document.media_document",
+            "content.image.media_image",
+            "content.items.media.media_image",
+            "content.items.items",
+        ])
+}
 
 /// Repository for fetching and transforming portfolio data from an external API.
 ///
@@ -39,10 +46,9 @@ impl ForFetchingPortfolioData for PortfolioRepository {
         let endpoint = self
             .api_client
             .resolve_external_endpoint(slug)
-            .await
-            .map_err(|e| AppError::External(adapter, e.to_string()))?;
+            .await?;
 
-        let endpoint = format!("{endpoint}?{RESOURCE_QUERY}");
+        let endpoint = resource_query().endpoint(&endpoint);
 
         let portfolio = self
             .api_client
@@ -50,7 +56,9 @@ impl ForFetchingPortfolioData for PortfolioRepository {
             .await
             .map_err(|e| AppError::External(adapter, e.to_string()))?;
 
-        Ok(self.api_adapter.adapt(portfolio.data().clone())?)
+        Ok(self
+            .api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt(portfolio.data().clone()))?)
     }
 }
 
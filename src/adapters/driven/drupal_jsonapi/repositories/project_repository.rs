@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use std::any::type_name;
+
+use crate::adapters::driven::drupal_jsonapi::entities::{NodeProjectResource, ProjectNode};
+use crate::adapters::driven::drupal_jsonapi::mappers::{ExternalProjectMapper, ProjectNodeMapper};
+use crate::adapters::driven::drupal_jsonapi::services::{Direction, JsonApiClientService, JsonApiQuery};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::project::Project;
+use crate::application::ports::driven::ForFetchingProjects;
+use crate::utilities::HttpClient;
+
+/// The published projects, the most recent first.
+fn collection_query() -> JsonApiQuery {
+    resource_query()
+        .filter("status", 1)
+        .sort("date_range.value", Direction::Desc)
+        .page_limit(50)
+}
+
+fn resource_query() -> JsonApiQuery {
+    JsonApiQuery::default()
+        .include(&["thumbnail.media_image", "gallery.media_image"])
+}
+
+/// Repository for fetching and transforming project data from an external CMS API.
+///
+/// This struct implements the `ForFetchingProjects` output port of the hexagonal architecture
+/// by integrating with a CMS API client to retrieve project nodes and transform them into domain entities.
+pub struct ProjectRepository {
+    api_client: Box<JsonApiClientService>,
+    api_adapter: Box<dyn ExternalProjectMapper<Input = ProjectNode>>,
+}
+
+impl ProjectRepository {
+    pub fn new(http_client: HttpClient) -> Self {
+        Self {
+            api_client: Box::new(JsonApiClientService::new(http_client)),
+            api_adapter: Box::new(ProjectNodeMapper),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingProjects for ProjectRepository {
+    async fn get_list(&self) -> Result<Vec<Project>> {
+        let endpoint = collection_query().endpoint("/jsonapi/node/project");
+
+        let projects = self
+            .api_client
+            .get_all_external_data::<ProjectNode>(&endpoint)
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e))?;
+
+        self.api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt_multiple(projects))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+        let adapter = type_name::<Self>();
+        let endpoint = self
+            .api_client
+            .resolve_external_endpoint(slug)
+            .await?;
+        let endpoint = resource_query().endpoint(&endpoint);
+
+        let project = self
+            .api_client
+            .get_external_data::<NodeProjectResource>(&endpoint)
+            .await
+            .map_err(|e| AppError::External(adapter, e))?;
+
+        self.api_client
+            .track_mapping(&endpoint, self.api_adapter.adapt(project.data().clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher::Regex;
+    use mockito::Server;
+
+    use crate::utilities::tests::http_client_mock;
+
+    #[actix_rt::test]
+    async fn list_succeeds_when_projects_are_returned() {
+        let mut server = Server::new_async().await;
+        let collection_mock = server
+            .mock("GET", Regex("/jsonapi/node/project".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/vnd.api+json")
+            .with_body_from_file("tests/fixtures/http_project_collection_200.json")
+            .create_async()
+            .await;
+
+        let projects = ProjectRepository::new(http_client_mock(&server.url()))
+            .get_list()
+            .await
+            .unwrap();
+
+        assert!(collection_mock.matched_async().await);
+        assert_eq!(projects.len(), 2);
+
+        let website = &projects[0];
+        assert_eq!(website.slug().as_str(), "/en/projects/personal-website");
+        assert_eq!(website.technologies(), &vec!["Rust", "Leptos", "Drupal"]);
+        assert_eq!(website.role().as_deref(), Some("Full-stack developer"));
+        assert!(website.end_date().is_none());
+        assert!(website.link().is_some());
+        assert_eq!(website.gallery().images().len(), 2);
+
+        let legacy = &projects[1];
+        assert!(legacy.role().is_none());
+        assert!(legacy.link().is_none());
+        assert!(legacy.end_date().is_some());
+        assert!(legacy.gallery().is_empty());
+    }
+}
@@ -1,8 +1,13 @@
+use reqwest::Url;
 use serde::de::DeserializeOwned;
-use serde_json::from_value;
+use serde_json::{from_value, Map, Value};
+use std::any::type_name;
+use std::collections::HashMap;
 
-use crate::adapters::driven::drupal_jsonapi::entities::ResolvedRoute;
-use crate::utilities::HttpClient;
+use crate::adapters::driven::drupal_jsonapi::entities::{JsonApiDocument, ResolvedRoute};
+use crate::adapters::driven::drupal_jsonapi::services::JsonApiQuery;
+use crate::application::domain::core::{AppError, Result};
+use crate::utilities::{resource_type, HttpClient};
 
 pub struct JsonApiClientService {
     http_client: HttpClient,
@@ -13,15 +18,39 @@ impl JsonApiClientService {
         Self { http_client }
     }
 
-    pub async fn resolve_external_endpoint(&self, path: &str) -> Result<String, String> {
+    /// Resolves a path alias into its JSON:API resource endpoint.
+    ///
+    /// # Errors
+    /// * `AppError::Moved` - The alias has changed and Drupal redirects it to a new path.
+    /// * `AppError::ResourceNotFound` - No entity matches the path.
+    /// * `AppError::External` - The router couldn't be reached or answered unexpected data.
+    pub async fn resolve_external_endpoint(&self, path: &str) -> Result<String> {
+        let adapter = type_name::<Self>();
         let json = self
             .http_client
-            .get_json(&format!("/router/translate-path?path={path}"))
+            .get_json(&JsonApiQuery::default().param("path", path).endpoint("/router/translate-path"))
             .await
-            .unwrap();
+            .map_err(|e| match e {
+                AppError::ApiFailure(_, body) if is_unresolved_path(&body) => AppError::ResourceNotFound(path.to_string()),
+                // unpublished content is denied to the site instead of being unresolved
+                AppError::ApiFailure(401 | 403, _) => AppError::Unauthorized(path.to_string()),
+                e => AppError::External(adapter, e.to_string()),
+            })?;
+
+        if json.get("entity").is_none() {
+            return Err(AppError::ResourceNotFound(path.to_string()));
+        }
+
+        let external_route = from_value::<ResolvedRoute>(json).map_err(|e| AppError::External(adapter, e.to_string()))?;
+
+        if let Some(redirect) = external_route.redirect().last() {
+            let destination = redirect_destination(redirect.to());
 
-        let external_route =
-            from_value::<ResolvedRoute>(json.clone()).map_err(|e| e.to_string())?;
+            if destination != path {
+                let status = *external_route.redirect().first().unwrap_or(redirect).status();
+                return Err(AppError::Moved(destination, status));
+            }
+        }
 
         Ok(format!(
             "/{}/{}/{}/{}",
@@ -32,13 +61,409 @@ impl JsonApiClientService {
         ))
     }
 
-    pub async fn get_external_data<T>(&self, endpoint: &str) -> Result<T, String>
+    /// Fetches the document of the endpoint into the entity, resolving its relationships from
+    /// the `included` resources.
+    pub async fn get_external_data<T>(&self, endpoint: &str) -> core::result::Result<T, String>
     where
         T: DeserializeOwned,
     {
-        let json = self.http_client.get_json(endpoint).await.unwrap();
+        let json = self.http_client.get_json(endpoint).await.map_err(|e| e.to_string())?;
+        let json = resolve_included(json);
 
         serde_json_path_to_error::from_value::<T>(json.clone())
+            .inspect_err(|_| self.http_client.record_mapper_failure(&resource_type(endpoint)))
             .map_err(|e| format!(r#"{}\n{}\n{}\n"#, e.to_string(), endpoint, json))
     }
+
+    /// Fetches every page of the collection of the endpoint, following the `next` links, into
+    /// its resources.
+    pub async fn get_all_external_data<T>(&self, endpoint: &str) -> core::result::Result<Vec<T>, String>
+    where
+        T: DeserializeOwned,
+    {
+        let mut resources = vec![];
+        let mut next = Some(endpoint.to_string());
+
+        while let Some(endpoint) = next {
+            let collection = self.get_external_data::<JsonApiDocument<Vec<T>>>(&endpoint).await?;
+            next = collection.next_page().map(str::to_string);
+            resources.extend(collection.into_data());
+        }
+
+        Ok(resources)
+    }
+
+    /// Counts the entities fetched from the endpoint that couldn't be mapped into the domain,
+    /// per resource type.
+    pub fn track_mapping<T>(&self, endpoint: &str, mapped: Result<T>) -> Result<T> {
+        mapped.inspect_err(|_| self.http_client.record_mapper_failure(&resource_type(endpoint)))
+    }
+}
+
+/// Inlines the relationships of a compound document into its resources, the way the contrib
+/// JSON:API Include module does, so the entities deserialize from stock Drupal core JSON:API.
+///
+/// Attributes and relationships become fields of the resource, each relationship is replaced
+/// by its `included` resource with the `meta` of the relationship, e.g. the `alt` of an image.
+/// Relationships that weren't included keep their type, id and meta. Documents whose resources
+/// are already inlined are returned as they are.
+fn resolve_included(mut document: Value) -> Value {
+    let Some(object) = document.as_object_mut() else {
+        return document;
+    };
+    let included = match object.remove("included") {
+        Some(Value::Array(resources)) => resources,
+        _ => vec![],
+    };
+    let index = included
+        .iter()
+        .filter_map(|resource| Some((resource_key(resource)?, resource)))
+        .collect::<HashMap<_, _>>();
+
+    if let Some(data) = object.get_mut("data") {
+        *data = match data.take() {
+            Value::Array(resources) => resources
+                .into_iter()
+                .map(|resource| inline_relationships(resource, &index, &mut vec![]))
+                .collect(),
+            resource => inline_relationships(resource, &index, &mut vec![]),
+        };
+    }
+
+    document
+}
+
+/// The resource with its attributes and resolved relationships as fields.
+///
+/// The keys of the resources being resolved are kept, so a relationship back to one of them is
+/// left unresolved instead of recursing forever.
+fn inline_relationships(resource: Value, index: &HashMap<(String, String), &Value>, ancestors: &mut Vec<(String, String)>) -> Value {
+    let Value::Object(mut resource) = resource else {
+        return resource;
+    };
+
+    if !resource.contains_key("attributes") && !resource.contains_key("relationships") {
+        return Value::Object(resource);
+    }
+
+    let attributes = take_object(&mut resource, "attributes");
+    let relationships = take_object(&mut resource, "relationships");
+    resource.remove("links");
+    // a resource without type or id can't be linked back to, only the identified ones are kept
+    let key = resource_key(&Value::Object(resource.clone()));
+    if let Some(key) = &key {
+        ancestors.push(key.clone());
+    }
+
+    resource.extend(attributes);
+
+    for (name, relationship) in relationships {
+        let resolved = match relationship.get("data").cloned().unwrap_or_default() {
+            Value::Array(identifiers) => identifiers
+                .into_iter()
+                .map(|identifier| resolve_identifier(identifier, index, ancestors))
+                .collect(),
+            Value::Null => Value::Null,
+            identifier => resolve_identifier(identifier, index, ancestors),
+        };
+        resource.insert(name, resolved);
+    }
+
+    if key.is_some() {
+        ancestors.pop();
+    }
+    Value::Object(resource)
+}
+
+fn resolve_identifier(identifier: Value, index: &HashMap<(String, String), &Value>, ancestors: &mut Vec<(String, String)>) -> Value {
+    let Some(key) = resource_key(&identifier).filter(|key| !ancestors.contains(key)) else {
+        return identifier;
+    };
+    let Some(included) = index.get(&key) else {
+        return identifier;
+    };
+
+    let mut resolved = inline_relationships((*included).clone(), index, ancestors);
+
+    if let (Value::Object(resolved), Some(meta)) = (&mut resolved, identifier.get("meta")) {
+        resolved.insert("meta".to_string(), meta.clone());
+    }
+
+    resolved
+}
+
+/// The type and id identifying a resource, e.g. `("node--article", "1b4e28ba-…")`.
+fn resource_key(resource: &Value) -> Option<(String, String)> {
+    Some((resource.get("type")?.as_str()?.to_string(), resource.get("id")?.as_str()?.to_string()))
+}
+
+fn take_object(resource: &mut Map<String, Value>, key: &str) -> Map<String, Value> {
+    match resource.remove(key) {
+        Some(Value::Object(object)) => object,
+        _ => Map::new(),
+    }
+}
+
+/// The router answers unknown paths with a 404 whose body explains why nothing matched.
+fn is_unresolved_path(body: &str) -> bool {
+    serde_json::from_str::<Value>(body).is_ok_and(|json| json.get("message").is_some() && json.get("details").is_some())
+}
+
+/// Drupal may answer with absolute urls of the CMS host, only the path is kept so the
+/// visitor stays on the website.
+fn redirect_destination(to: &str) -> String {
+    match Url::parse(to) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        },
+        Err(_) => to.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher::Regex;
+    use mockito::Server;
+    use rstest::rstest;
+
+    use serde_json::json;
+
+    use crate::adapters::driven::drupal_jsonapi::entities::{NodeArticleCollection, NodeProjectCollection};
+    use crate::utilities::tests::http_client_mock;
+    use crate::utilities::Metrics;
+
+    #[rstest]
+    #[case("http_decoupled_router_200.json", "/portfolio/john-doe")]
+    #[case("http_decoupled_router_redirect_200.json", "/en/articles/new-title")]
+    #[actix_rt::test]
+    async fn resolve_succeeds_when_path_is_current(#[case] fixture: &str, #[case] path: &str) {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", Regex("/router/translate-path".to_string()))
+            .with_status(200)
+            .with_body_from_file(format!("tests/fixtures/{fixture}"))
+            .create_async()
+            .await;
+
+        let endpoint = JsonApiClientService::new(http_client_mock(&server.url()))
+            .resolve_external_endpoint(path)
+            .await
+            .unwrap();
+
+        assert!(endpoint.starts_with("/jsonapi/node/"));
+    }
+
+    #[actix_rt::test]
+    async fn resolve_fails_with_moved_when_alias_has_changed() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", Regex("/router/translate-path".to_string()))
+            .with_status(200)
+            .with_body_from_file("tests/fixtures/http_decoupled_router_redirect_200.json")
+            .create_async()
+            .await;
+
+        let result = JsonApiClientService::new(http_client_mock(&server.url()))
+            .resolve_external_endpoint("/en/articles/old-title")
+            .await;
+
+        assert!(matches!(result, Err(AppError::Moved(path, 301)) if path == "/en/articles/new-title"));
+    }
+
+    #[actix_rt::test]
+    async fn resolve_fails_with_not_found_when_path_is_unknown() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", Regex("/router/translate-path".to_string()))
+            .with_status(404)
+            .with_body_from_file("tests/fixtures/http_decoupled_router_404.json")
+            .create_async()
+            .await;
+
+        let result = JsonApiClientService::new(http_client_mock(&server.url()))
+            .resolve_external_endpoint("/non-existing-portfolio")
+            .await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(_))), "{result:?}");
+    }
+
+    #[actix_rt::test]
+    async fn resolve_fails_with_unauthorized_when_content_is_unpublished() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", Regex("/router/translate-path".to_string()))
+            .with_status(403)
+            .with_body(r#"{"message": "Access denied for entity."}"#)
+            .create_async()
+            .await;
+
+        let result = JsonApiClientService::new(http_client_mock(&server.url()))
+            .resolve_external_endpoint("/en/articles/draft")
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(path)) if path == "/en/articles/draft"));
+    }
+
+    #[actix_rt::test]
+    async fn resolve_fails_with_external_when_router_is_unreachable() {
+        let result = JsonApiClientService::new(http_client_mock("http://127.0.0.1:1"))
+            .resolve_external_endpoint("/en/articles")
+            .await;
+
+        assert!(matches!(result, Err(AppError::External(_, _))));
+    }
+
+    #[actix_rt::test]
+    async fn get_external_data_records_mapper_failure_when_entity_is_invalid() {
+        let mut server = Server::new_async().await;
+        let metrics = Metrics::default();
+        server
+            .mock("GET", "/jsonapi/node/article")
+            .with_status(200)
+            .with_body(r#"{"data": [{"id": "not-an-article"}]}"#)
+            .create_async()
+            .await;
+
+        let result = JsonApiClientService::new(http_client_mock(&server.url()).metrics(metrics.clone()))
+            .get_external_data::<NodeArticleCollection>("/jsonapi/node/article")
+            .await;
+
+        assert!(result.is_err());
+        assert!(metrics
+            .render()
+            .contains(r#"website_mapper_failures_total{entity="node--article"} 1"#));
+    }
+
+    #[actix_rt::test]
+    async fn get_external_data_succeeds_when_document_is_compound() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/jsonapi/node/project")
+            .with_status(200)
+            .with_body_from_file("tests/fixtures/http_project_compound_collection_200.json")
+            .create_async()
+            .await;
+
+        let projects = JsonApiClientService::new(http_client_mock(&server.url()))
+            .get_external_data::<NodeProjectCollection>("/jsonapi/node/project")
+            .await
+            .unwrap();
+
+        let flattened = from_value::<NodeProjectCollection>(
+            serde_json::from_str(&std::fs::read_to_string("tests/fixtures/http_project_collection_200.json").unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(projects.data()).unwrap(),
+            serde_json::to_value(flattened.data()).unwrap()
+        );
+    }
+
+    #[test]
+    fn resolve_included_inlines_relationships_with_their_meta() {
+        let document = json!({
+            "data": {
+                "type": "node--article",
+                "id": "1",
+                "attributes": {"title": "Linked lists"},
+                "relationships": {
+                    "thumbnail": {"data": {"type": "media--image", "id": "2"}},
+                    "tags": {"data": [{"type": "taxonomy_term--tags", "id": "3"}]},
+                    "uid": {"data": {"type": "user--user", "id": "4"}},
+                    "image": {"data": null}
+                }
+            },
+            "included": [
+                {
+                    "type": "media--image",
+                    "id": "2",
+                    "attributes": {"name": "Thumbnail"},
+                    "relationships": {
+                        "media_image": {"data": {"type": "file--file", "id": "5", "meta": {"alt": "A list"}}}
+                    }
+                },
+                {"type": "file--file", "id": "5", "attributes": {"uri": {"url": "/list.png"}}},
+                {
+                    "type": "taxonomy_term--tags",
+                    "id": "3",
+                    "attributes": {"name": "Algorithms"},
+                    "relationships": {"parent": {"data": [{"type": "node--article", "id": "1"}]}}
+                }
+            ]
+        });
+
+        let resolved = resolve_included(document);
+
+        assert_eq!(
+            resolved,
+            json!({
+                "data": {
+                    "type": "node--article",
+                    "id": "1",
+                    "title": "Linked lists",
+                    "thumbnail": {
+                        "type": "media--image",
+                        "id": "2",
+                        "name": "Thumbnail",
+                        "media_image": {"type": "file--file", "id": "5", "uri": {"url": "/list.png"}, "meta": {"alt": "A list"}}
+                    },
+                    "tags": [{
+                        "type": "taxonomy_term--tags",
+                        "id": "3",
+                        "name": "Algorithms",
+                        "parent": [{"type": "node--article", "id": "1"}]
+                    }],
+                    "uid": {"type": "user--user", "id": "4"},
+                    "image": null
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn inline_relationships_keeps_ancestors_when_resource_has_no_key() {
+        let parent = ("node--article".to_string(), "1".to_string());
+        let mut ancestors = vec![parent.clone()];
+        let resource = json!({"attributes": {"title": "Linked lists"}, "relationships": {}});
+
+        inline_relationships(resource, &HashMap::new(), &mut ancestors);
+
+        assert_eq!(ancestors, vec![parent]);
+    }
+
+    #[test]
+    fn resolve_included_keeps_document_when_resources_are_inlined() {
+        let document =
+            serde_json::from_str::<Value>(&std::fs::read_to_string("tests/fixtures/http_project_collection_200.json").unwrap())
+                .unwrap();
+
+        assert_eq!(resolve_included(document.clone()), document);
+    }
+
+    #[test]
+    fn track_mapping_records_mapper_failure_when_mapping_fails() {
+        let metrics = Metrics::default();
+        let service = JsonApiClientService::new(http_client_mock("https://localhost.dev").metrics(metrics.clone()));
+
+        let _ = service.track_mapping("/jsonapi/node/project?include=image", Ok(()));
+        let _ = service.track_mapping::<()>(
+            "/jsonapi/node/project/8b2f5e1c-5c4d-4f0e-9a1e-1f2d3c4b5a69",
+            Err(AppError::Unexpected("missing title".to_string())),
+        );
+
+        assert!(metrics
+            .render()
+            .contains(r#"website_mapper_failures_total{entity="node--project"} 1"#));
+    }
+
+    #[rstest]
+    #[case("/en/articles/new-title", "/en/articles/new-title")]
+    #[case("https://cms.example.com/en/articles/new-title", "/en/articles/new-title")]
+    #[case("https://cms.example.com/en/articles?page=2", "/en/articles?page=2")]
+    fn redirect_destination_keeps_the_path(#[case] to: &str, #[case] expected: &str) {
+        assert_eq!(redirect_destination(to), expected);
+    }
 }
@@ -0,0 +1,386 @@
+use std::fmt::{Display, Formatter};
+
+/// Builds the query string of a JSON:API request, percent-encoding every key and value.
+///
+/// Filters are written in the long form Drupal documents, e.g. `filter[tags][condition][path]`,
+/// while `filter` is the shorthand for a field equal to a value. Setting the page again replaces
+/// it, the same goes for the other single valued parameters.
+///
+/// ```text
+/// JsonApiQuery::default()
+///     .include(&["tags"])
+///     .condition("tags", Condition::equal("tags.machine_name", "rust"))
+///     .sort("created", Direction::Desc)
+///     .page_limit(10)
+///
+/// include=tags&filter[tags][condition][path]=tags.machine_name
+///     &filter[tags][condition][value]=rust&sort=-created&page[limit]=10 (encoded)
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct JsonApiQuery {
+    includes: Vec<String>,
+    filters: Vec<(String, Filter)>,
+    sorts: Vec<(String, Direction)>,
+    fields: Vec<(String, Vec<String>)>,
+    page_offset: Option<u32>,
+    page_limit: Option<u32>,
+    params: Vec<(String, String)>,
+}
+
+/// A named member of the filter of a query, which conditions and groups can belong to.
+#[derive(Clone, Debug)]
+enum Filter {
+    Value(String),
+    Condition(Condition),
+    Group(Group),
+}
+
+/// Compares the field at the path of the resources to the values.
+#[derive(Clone, Debug)]
+pub struct Condition {
+    path: String,
+    operator: Operator,
+    values: Vec<String>,
+    member_of: Option<String>,
+}
+
+/// Combines the conditions and groups that are members of it.
+#[derive(Clone, Debug)]
+pub struct Group {
+    conjunction: Conjunction,
+    member_of: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    StartsWith,
+    Contains,
+    EndsWith,
+    In,
+    NotIn,
+    Between,
+    NotBetween,
+    IsNull,
+    IsNotNull,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Conjunction {
+    And,
+    Or,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+impl JsonApiQuery {
+    /// Includes the related resources at the paths, e.g. `thumbnail.media_image`.
+    pub fn include(mut self, paths: &[&str]) -> Self {
+        self.includes.extend(paths.iter().map(|path| path.to_string()));
+        self
+    }
+
+    /// Keeps the resources whose field equals the value, e.g. `filter[status]=1`.
+    pub fn filter(mut self, path: &str, value: impl ToString) -> Self {
+        self.filters.push((path.to_string(), Filter::Value(value.to_string())));
+        self
+    }
+
+    /// Keeps the resources matching the condition, named so that it's unique within the query.
+    pub fn condition(mut self, name: &str, condition: Condition) -> Self {
+        self.filters.push((name.to_string(), Filter::Condition(condition)));
+        self
+    }
+
+    /// Adds a group that the conditions and other groups of the query can be members of.
+    pub fn group(mut self, name: &str, group: Group) -> Self {
+        self.filters.push((name.to_string(), Filter::Group(group)));
+        self
+    }
+
+    /// Sorts the resources by the field, the earlier sorts take precedence.
+    pub fn sort(mut self, path: &str, direction: Direction) -> Self {
+        self.sorts.push((path.to_string(), direction));
+        self
+    }
+
+    /// Only returns the given fields of the resources of the type, e.g. `node--article`.
+    pub fn fields(mut self, resource_type: &str, fields: &[&str]) -> Self {
+        self.fields.retain(|(t, _)| t != resource_type);
+        self.fields
+            .push((resource_type.to_string(), fields.iter().map(|field| field.to_string()).collect()));
+        self
+    }
+
+    pub fn page_limit(mut self, limit: u32) -> Self {
+        self.page_limit = Some(limit);
+        self
+    }
+
+    pub fn page_offset(mut self, offset: u32) -> Self {
+        self.page_offset = Some(offset);
+        self
+    }
+
+    /// Adds a parameter that isn't part of the JSON:API specification, replacing its value.
+    pub fn param(mut self, key: &str, value: impl ToString) -> Self {
+        self.params.retain(|(k, _)| k != key);
+        self.params.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// The endpoint of the path with the query, e.g. `/jsonapi/node/article?include=tags`.
+    pub fn endpoint(&self, path: &str) -> String {
+        match self.to_string().as_str() {
+            "" => path.to_string(),
+            query => format!("{path}?{query}"),
+        }
+    }
+
+    fn pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = vec![];
+
+        if !self.includes.is_empty() {
+            pairs.push(("include".to_string(), self.includes.join(",")));
+        }
+
+        for (name, filter) in &self.filters {
+            match filter {
+                Filter::Value(value) => pairs.push((format!("filter[{name}]"), value.clone())),
+                Filter::Condition(condition) => pairs.extend(condition.pairs(&format!("filter[{name}][condition]"))),
+                Filter::Group(group) => pairs.extend(group.pairs(&format!("filter[{name}][group]"))),
+            }
+        }
+
+        if !self.sorts.is_empty() {
+            let sorts = self.sorts.iter().map(|(path, direction)| match direction {
+                Direction::Asc => path.clone(),
+                Direction::Desc => format!("-{path}"),
+            });
+            pairs.push(("sort".to_string(), sorts.collect::<Vec<_>>().join(",")));
+        }
+
+        for (resource_type, fields) in &self.fields {
+            pairs.push((format!("fields[{resource_type}]"), fields.join(",")));
+        }
+
+        if let Some(offset) = self.page_offset {
+            pairs.push(("page[offset]".to_string(), offset.to_string()));
+        }
+
+        if let Some(limit) = self.page_limit {
+            pairs.push(("page[limit]".to_string(), limit.to_string()));
+        }
+
+        pairs.extend(self.params.iter().cloned());
+        pairs
+    }
+}
+
+impl Display for JsonApiQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let query = form_urlencoded::Serializer::new(String::new()).extend_pairs(self.pairs()).finish();
+
+        write!(f, "{query}")
+    }
+}
+
+impl Condition {
+    /// Compares the field with the operator, the values are added with `value`.
+    pub fn new(path: &str, operator: Operator) -> Self {
+        Self {
+            path: path.to_string(),
+            operator,
+            values: vec![],
+            member_of: None,
+        }
+    }
+
+    pub fn equal(path: &str, value: impl ToString) -> Self {
+        Self::new(path, Operator::Equal).value(value)
+    }
+
+    /// Adds a value to compare with, `IN` and `BETWEEN` operators take several of them.
+    pub fn value(mut self, value: impl ToString) -> Self {
+        self.values.push(value.to_string());
+        self
+    }
+
+    pub fn member_of(mut self, group: &str) -> Self {
+        self.member_of = Some(group.to_string());
+        self
+    }
+
+    fn pairs(&self, prefix: &str) -> Vec<(String, String)> {
+        let mut pairs = vec![(format!("{prefix}[path]"), self.path.clone())];
+
+        if self.operator != Operator::Equal {
+            pairs.push((format!("{prefix}[operator]"), self.operator.to_string()));
+        }
+
+        match self.operator {
+            Operator::IsNull | Operator::IsNotNull => {}
+            Operator::In | Operator::NotIn | Operator::Between | Operator::NotBetween => {
+                pairs.extend(self.values.iter().map(|value| (format!("{prefix}[value][]"), value.clone())))
+            }
+            _ => pairs.extend(self.values.first().map(|value| (format!("{prefix}[value]"), value.clone()))),
+        }
+
+        pairs.extend(self.member_of.iter().map(|group| (format!("{prefix}[memberOf]"), group.clone())));
+        pairs
+    }
+}
+
+impl Group {
+    pub fn new(conjunction: Conjunction) -> Self {
+        Self {
+            conjunction,
+            member_of: None,
+        }
+    }
+
+    pub fn member_of(mut self, group: &str) -> Self {
+        self.member_of = Some(group.to_string());
+        self
+    }
+
+    fn pairs(&self, prefix: &str) -> Vec<(String, String)> {
+        let conjunction = match self.conjunction {
+            Conjunction::And => "AND",
+            Conjunction::Or => "OR",
+        };
+        let mut pairs = vec![(format!("{prefix}[conjunction]"), conjunction.to_string())];
+
+        pairs.extend(self.member_of.iter().map(|group| (format!("{prefix}[memberOf]"), group.clone())));
+        pairs
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            Operator::Equal => "=",
+            Operator::NotEqual => "<>",
+            Operator::GreaterThan => ">",
+            Operator::GreaterThanOrEqual => ">=",
+            Operator::LessThan => "<",
+            Operator::LessThanOrEqual => "<=",
+            Operator::StartsWith => "STARTS_WITH",
+            Operator::Contains => "CONTAINS",
+            Operator::EndsWith => "ENDS_WITH",
+            Operator::In => "IN",
+            Operator::NotIn => "NOT IN",
+            Operator::Between => "BETWEEN",
+            Operator::NotBetween => "NOT BETWEEN",
+            Operator::IsNull => "IS NULL",
+            Operator::IsNotNull => "IS NOT NULL",
+        };
+
+        write!(f, "{operator}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// The decoded pairs of the query, easier to read than the encoded ones.
+    fn decoded(query: &JsonApiQuery) -> Vec<(String, String)> {
+        form_urlencoded::parse(query.to_string().as_bytes()).into_owned().collect()
+    }
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn to_string_succeeds_when_values_have_reserved_characters() {
+        let query = JsonApiQuery::default().condition("tags", Condition::equal("tags.machine_name", "c++ & rust"));
+
+        assert_eq!(
+            query.to_string(),
+            "filter%5Btags%5D%5Bcondition%5D%5Bpath%5D=tags.machine_name\
+            &filter%5Btags%5D%5Bcondition%5D%5Bvalue%5D=c%2B%2B+%26+rust"
+        );
+    }
+
+    #[test]
+    fn to_string_succeeds_when_page_limit_is_set_twice() {
+        let query = JsonApiQuery::default().page_limit(2).page_limit(10);
+
+        assert_eq!(decoded(&query), vec![pair("page[limit]", "10")]);
+    }
+
+    #[test]
+    fn to_string_succeeds_when_conditions_belong_to_groups() {
+        let query = JsonApiQuery::default()
+            .group("featured", Group::new(Conjunction::Or))
+            .condition("promoted", Condition::equal("promote", 1).member_of("featured"))
+            .condition("sticky", Condition::new("sticky", Operator::IsNotNull).member_of("featured"));
+
+        assert_eq!(
+            decoded(&query),
+            vec![
+                pair("filter[featured][group][conjunction]", "OR"),
+                pair("filter[promoted][condition][path]", "promote"),
+                pair("filter[promoted][condition][value]", "1"),
+                pair("filter[promoted][condition][memberOf]", "featured"),
+                pair("filter[sticky][condition][path]", "sticky"),
+                pair("filter[sticky][condition][operator]", "IS NOT NULL"),
+                pair("filter[sticky][condition][memberOf]", "featured"),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(Operator::In, vec![pair("filter[c][condition][value][]", "a"), pair("filter[c][condition][value][]", "b")])]
+    #[case(Operator::Between, vec![pair("filter[c][condition][value][]", "a"), pair("filter[c][condition][value][]", "b")])]
+    #[case(Operator::Contains, vec![pair("filter[c][condition][value]", "a")])]
+    fn to_string_succeeds_when_operator_takes_values(
+        #[case] operator: Operator,
+        #[case] expected_values: Vec<(String, String)>,
+    ) {
+        let query = JsonApiQuery::default().condition("c", Condition::new("title", operator).value("a").value("b"));
+
+        let pairs = decoded(&query);
+
+        assert_eq!(pairs[1], pair("filter[c][condition][operator]", &operator.to_string()));
+        assert_eq!(pairs[2..], expected_values[..]);
+    }
+
+    #[test]
+    fn to_string_succeeds_when_every_part_is_given() {
+        let query = JsonApiQuery::default()
+            .param("resourceVersion", "rel:latest-version")
+            .page_limit(50)
+            .page_offset(100)
+            .fields("node--project", &["title", "path"])
+            .sort("date_range.value", Direction::Desc)
+            .sort("title", Direction::Asc)
+            .include(&["thumbnail.media_image", "gallery.media_image"])
+            .filter("status", 1);
+
+        assert_eq!(
+            decoded(&query),
+            vec![
+                pair("include", "thumbnail.media_image,gallery.media_image"),
+                pair("filter[status]", "1"),
+                pair("sort", "-date_range.value,title"),
+                pair("fields[node--project]", "title,path"),
+                pair("page[offset]", "100"),
+                pair("page[limit]", "50"),
+                pair("resourceVersion", "rel:latest-version"),
+            ]
+        );
+    }
+}
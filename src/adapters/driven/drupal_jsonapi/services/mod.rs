@@ -1,3 +1,5 @@
 mod jsonapi_client_service;
+mod jsonapi_query;
 
 pub use jsonapi_client_service::*;
+pub use jsonapi_query::*;
@@ -0,0 +1,78 @@
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::Message;
+use std::any::type_name;
+
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::{AppError, Result};
+
+/// Addressing information shared by all the mail transports.
+///
+/// Messages are always sent from the configured `from` mailbox to the configured
+/// `to` mailbox, the visitor address only goes into the `Reply-To` header so that
+/// the site never sends mail on behalf of third parties.
+#[derive(Debug, Clone)]
+pub struct MailEnvelope {
+    from: Mailbox,
+    to: Mailbox,
+}
+
+impl MailEnvelope {
+    pub fn new(from: &str, to: &str) -> Result<Self> {
+        let adapter = type_name::<Self>();
+
+        Ok(Self {
+            from: from.parse().map_err(|_| AppError::InvalidValue(adapter, from.to_string()))?,
+            to: to.parse().map_err(|_| AppError::InvalidValue(adapter, to.to_string()))?,
+        })
+    }
+
+    /// Converts a contact message into the email delivered to the site owner.
+    pub fn compose(&self, message: &ContactMessage) -> Result<Message> {
+        let adapter = type_name::<Self>();
+        let reply_to = Mailbox::new(
+            Some(message.name().to_string()),
+            message
+                .email()
+                .parse()
+                .map_err(|_| AppError::InvalidValue(adapter, message.email().to_string()))?,
+        );
+
+        Message::builder()
+            .from(self.from.clone())
+            .to(self.to.clone())
+            .reply_to(reply_to)
+            .subject(format!("New contact message from {}", message.name().as_str()))
+            .header(ContentType::TEXT_PLAIN)
+            .body(format!(
+                "Name: {}\nEmail: {}\n\n{}",
+                message.name().as_str(),
+                message.email().as_str(),
+                message.message().as_str()
+            ))
+            .map_err(|e| AppError::External(adapter, e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::contact::tests::contact_message_fixture;
+
+    #[test]
+    fn creation_fails_when_mailbox_is_invalid() {
+        assert!(MailEnvelope::new("not a mailbox", "owner@example.com").is_err());
+    }
+
+    #[test]
+    fn compose_succeeds_when_valid_contact_message() {
+        let envelope = MailEnvelope::new("Website <noreply@example.com>", "owner@example.com").unwrap();
+        let message = contact_message_fixture();
+        let email = String::from_utf8(envelope.compose(&message).unwrap().formatted()).unwrap();
+
+        assert!(email.contains("From: Website <noreply@example.com>"));
+        assert!(email.contains("To: owner@example.com"));
+        assert!(email.contains("Reply-To: \"John Doe\" <john@example.com>"));
+        assert!(email.contains(message.message().as_str()));
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+use std::any::type_name;
+use tracing::info;
+
+use crate::adapters::driven::mail_transport::MailEnvelope;
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::ports::driven::ForSendingMessages;
+
+/// Sender that writes contact messages as `.eml` files into a local outbox.
+///
+/// This struct implements the `ForSendingMessages` output port of the hexagonal architecture
+/// and is meant for local development, where no SMTP relay is available.
+#[derive(Clone)]
+pub struct FileMessageSender {
+    transport: AsyncFileTransport<Tokio1Executor>,
+    envelope: MailEnvelope,
+}
+
+impl FileMessageSender {
+    pub fn new(outbox_dir: &str, envelope: MailEnvelope) -> Self {
+        Self {
+            transport: AsyncFileTransport::new(outbox_dir),
+            envelope,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForSendingMessages for FileMessageSender {
+    async fn send(&self, message: &ContactMessage) -> Result<()> {
+        let email = self.envelope.compose(message)?;
+
+        let id = self
+            .transport
+            .send(email)
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e.to_string()))?;
+
+        info!("contact message from {} stored as {id}.eml", message.email().as_str());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::contact::tests::contact_message_fixture;
+
+    #[actix_rt::test]
+    async fn send_succeeds_when_outbox_is_writable() {
+        let outbox = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&outbox).unwrap();
+
+        let envelope = MailEnvelope::new("noreply@example.com", "owner@example.com").unwrap();
+        let sender = FileMessageSender::new(outbox.to_str().unwrap(), envelope);
+        sender.send(&contact_message_fixture()).await.unwrap();
+
+        let files = std::fs::read_dir(&outbox).unwrap().count();
+        std::fs::remove_dir_all(&outbox).unwrap();
+
+        assert_eq!(files, 1);
+    }
+
+    #[actix_rt::test]
+    async fn send_fails_when_outbox_does_not_exist() {
+        let outbox = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+
+        let envelope = MailEnvelope::new("noreply@example.com", "owner@example.com").unwrap();
+        let sender = FileMessageSender::new(outbox.to_str().unwrap(), envelope);
+        let result = sender.send(&contact_message_fixture()).await;
+
+        assert!(matches!(result, Err(AppError::External(_, _))));
+    }
+}
@@ -0,0 +1,9 @@
+mod envelope;
+mod file_message_sender;
+mod smtp_message_sender;
+mod transport;
+
+pub use envelope::*;
+pub use file_message_sender::*;
+pub use smtp_message_sender::*;
+pub use transport::*;
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::adapters::driven::mail_transport::{FileMessageSender, SmtpMessageSender};
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::Result;
+use crate::application::ports::driven::ForSendingMessages;
+
+/// The mail transport selected at startup.
+///
+/// It is registered once as application data and cloned into every request,
+/// delegating the delivery to the configured sender.
+#[derive(Clone)]
+pub enum MailTransport {
+    Smtp(SmtpMessageSender),
+    File(FileMessageSender),
+}
+
+#[async_trait(?Send)]
+impl ForSendingMessages for MailTransport {
+    async fn send(&self, message: &ContactMessage) -> Result<()> {
+        match self {
+            MailTransport::Smtp(sender) => sender.send(message).await,
+            MailTransport::File(sender) => sender.send(message).await,
+        }
+    }
+}
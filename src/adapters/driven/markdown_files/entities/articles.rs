@@ -0,0 +1,34 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::adapters::driven::markdown_files::entities::{published, ImageFrontMatter};
+
+/// Front matter of `articles/*.md`, the body of the file is the content of the article.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ArticleFrontMatter {
+    title: String,
+    path: String,
+    summary: String,
+    created: String,
+    /// Name of the category file, e.g. `rust` for `categories/rust.md`.
+    category: String,
+    thumbnail: ImageFrontMatter,
+    #[serde(default = "published")]
+    published: bool,
+    /// Promoted articles are the ones featured in the portfolio.
+    #[serde(default)]
+    promoted: bool,
+    #[serde(default)]
+    metatags: BTreeMap<String, String>,
+}
+
+/// Front matter of `categories/*.md`, articles are filtered by the name of the file.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct CategoryFrontMatter {
+    title: String,
+    path: String,
+    emoji: String,
+    #[serde(default = "published")]
+    published: bool,
+}
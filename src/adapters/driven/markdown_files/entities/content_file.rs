@@ -0,0 +1,29 @@
+use derive_getters::Getters;
+use std::path::Path;
+
+/// A Markdown file of the content directory, with its YAML front matter already deserialized.
+#[derive(Debug, Clone, Getters)]
+pub struct ContentFile<T> {
+    /// Path of the file relative to the content directory, e.g. `articles/linked-lists.md`.
+    path: String,
+    front_matter: T,
+    body: String,
+}
+
+impl<T> ContentFile<T> {
+    pub fn new(path: &str, front_matter: T, body: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            front_matter,
+            body: body.to_string(),
+        }
+    }
+
+    /// Name of the file without its extension, e.g. `linked-lists`.
+    pub fn stem(&self) -> &str {
+        Path::new(&self.path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,40 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ImageFrontMatter {
+    url: String,
+    alt: String,
+    title: Option<String>,
+    #[serde(default)]
+    width: u16,
+    #[serde(default)]
+    height: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct DocumentFrontMatter {
+    url: String,
+    mime: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct LinkFrontMatter {
+    url: String,
+    #[serde(default)]
+    title: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct TimelineItemFrontMatter {
+    title: String,
+    subtitle: String,
+    start_date: String,
+    /// Empty when the item is still ongoing.
+    end_date: Option<String>,
+    text: Option<String>,
+}
+
+pub(crate) fn published() -> bool {
+    true
+}
@@ -0,0 +1,34 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+
+use crate::adapters::driven::markdown_files::entities::ImageFrontMatter;
+
+/// Front matter of `site.md`.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct SiteFrontMatter {
+    name: String,
+    slogan: Option<String>,
+    logo: Option<ImageFrontMatter>,
+}
+
+/// Front matter of `menus/*.md`, the name of the file is the id of the menu, e.g. `main`.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct MenuFrontMatter {
+    #[serde(default)]
+    items: Vec<MenuItemFrontMatter>,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct MenuItemFrontMatter {
+    title: String,
+    url: String,
+    #[serde(default)]
+    weight: isize,
+    icon: Option<ImageFrontMatter>,
+    #[serde(default)]
+    hidden: bool,
+    #[serde(default)]
+    expanded: bool,
+    #[serde(default)]
+    children: Vec<MenuItemFrontMatter>,
+}
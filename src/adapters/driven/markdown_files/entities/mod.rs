@@ -0,0 +1,15 @@
+mod articles;
+mod content_file;
+mod fields;
+mod layout;
+mod pages;
+mod portfolio;
+mod projects;
+
+pub use articles::*;
+pub use content_file::*;
+pub use fields::*;
+pub use layout::*;
+pub use pages::*;
+pub use portfolio::*;
+pub use projects::*;
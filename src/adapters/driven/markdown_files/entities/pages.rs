@@ -0,0 +1,17 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::adapters::driven::markdown_files::entities::published;
+
+/// Front matter of `pages/*.md`, the body of the file describes the page.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct PageFrontMatter {
+    title: String,
+    path: String,
+    created: String,
+    #[serde(default = "published")]
+    published: bool,
+    #[serde(default)]
+    metatags: BTreeMap<String, String>,
+}
@@ -0,0 +1,65 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::adapters::driven::markdown_files::entities::{published, DocumentFrontMatter};
+use crate::adapters::driven::markdown_files::entities::{ImageFrontMatter, TimelineItemFrontMatter};
+
+/// Front matter of `portfolio/*.md`, the sections are listed in the order they are shown.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct PortfolioFrontMatter {
+    title: String,
+    path: String,
+    created: String,
+    #[serde(default = "published")]
+    published: bool,
+    #[serde(default)]
+    sections: Vec<PortfolioSectionFrontMatter>,
+    #[serde(default)]
+    metatags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PortfolioSectionFrontMatter {
+    AboutMe(AboutMeFrontMatter),
+    Resume(ResumeFrontMatter),
+    Projects(SectionFrontMatter),
+    Blogs(SectionFrontMatter),
+    Contact(SectionFrontMatter),
+    #[serde(other)]
+    Unknown,
+}
+
+/// Sections whose content, projects or articles, comes from the other files.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct SectionFrontMatter {
+    title: String,
+    subtitle: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct AboutMeFrontMatter {
+    title: String,
+    subtitle: String,
+    text: String,
+    #[serde(default)]
+    skills: Vec<String>,
+    #[serde(default)]
+    years_of_experience: u8,
+    profile_picture: ImageFrontMatter,
+    cv_document: DocumentFrontMatter,
+}
+
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ResumeFrontMatter {
+    title: String,
+    subtitle: String,
+    text: String,
+    #[serde(default)]
+    education: Vec<TimelineItemFrontMatter>,
+    #[serde(default)]
+    experience: Vec<TimelineItemFrontMatter>,
+}
@@ -0,0 +1,27 @@
+use derive_getters::Getters;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::adapters::driven::markdown_files::entities::{published, ImageFrontMatter, LinkFrontMatter};
+
+/// Front matter of `projects/*.md`, the body of the file is the description of the project.
+#[derive(Debug, Clone, Deserialize, Getters)]
+pub struct ProjectFrontMatter {
+    title: String,
+    path: String,
+    summary: String,
+    role: Option<String>,
+    #[serde(default)]
+    technologies: Vec<String>,
+    start_date: String,
+    /// Empty when the project is still ongoing.
+    end_date: Option<String>,
+    link: Option<LinkFrontMatter>,
+    image: ImageFrontMatter,
+    #[serde(default)]
+    gallery: Vec<ImageFrontMatter>,
+    #[serde(default = "published")]
+    published: bool,
+    #[serde(default)]
+    metatags: BTreeMap<String, String>,
+}
@@ -0,0 +1,36 @@
+use crate::adapters::driven::metatags::MetatagsFallback;
+use crate::adapters::driven::markdown_files::entities::{ArticleFrontMatter, ContentFile};
+use crate::adapters::driven::markdown_files::mappers::{content_id, image_front_matter_mapper, markdown_to_html};
+use crate::adapters::driven::markdown_files::mappers::metatags_front_matter_mapper;
+use crate::application::domain::article::{Article, ArticleBuilder, ArticleContent, Category};
+use crate::application::domain::core::{AppError, Result};
+
+/// Maps the article, its category is read from its own file by the repository.
+pub fn article_file_mapper(file: &ContentFile<ArticleFrontMatter>, category: Category) -> Result<Article> {
+    let article = file.front_matter();
+    let metatags_fallback = MetatagsFallback::default()
+        .title(article.title())
+        .description(article.summary())
+        .image(article.thumbnail().url())
+        .path(article.path());
+
+    // the whole body is a single text, images and code blocks are written in Markdown
+    let content = match file.body().trim().is_empty() {
+        true => vec![],
+        false => vec![ArticleContent::Text(markdown_to_html(file.body()).try_into()?)],
+    };
+
+    ArticleBuilder::default()
+        .id(content_id(file.path())?)
+        .slug(article.path().to_string().try_into()?)
+        .status((*article.published()).into())
+        .title(article.title().to_string().try_into()?)
+        .summary(article.summary().to_string().try_into()?)
+        .created_at(article.created().to_string().try_into()?)
+        .category(category)
+        .thumbnail(image_front_matter_mapper(article.thumbnail())?)
+        .metatags(metatags_front_matter_mapper(article.metatags(), &metatags_fallback)?)
+        .content(content)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
@@ -0,0 +1,17 @@
+use crate::adapters::driven::markdown_files::entities::{CategoryFrontMatter, ContentFile};
+use crate::adapters::driven::markdown_files::mappers::content_id;
+use crate::application::domain::article::{Category, CategoryBuilder};
+use crate::application::domain::core::{AppError, Result};
+
+pub fn category_file_mapper(file: &ContentFile<CategoryFrontMatter>) -> Result<Category> {
+    let category = file.front_matter();
+
+    CategoryBuilder::default()
+        .id(content_id(file.path())?)
+        .title(category.title().to_string().try_into()?)
+        .slug(category.path().to_string().try_into()?)
+        .emoji(category.emoji().to_string().try_into()?)
+        .status((*category.published()).into())
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
@@ -0,0 +1,127 @@
+use pulldown_cmark::{html, Options, Parser};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driven::markdown_files::entities::{DocumentFrontMatter, ImageFrontMatter};
+use crate::adapters::driven::markdown_files::entities::{LinkFrontMatter, TimelineItemFrontMatter};
+use crate::adapters::driven::metatags::{metatags_mapper, MetatagsFallback};
+use crate::application::domain::common::{Document, DocumentBuilder, Image, ImageBuilder, Link, LinkBuilder};
+use crate::application::domain::common::{MetaTags, Timeline, TimelineBuilder, TimelineItem, TimelineItemBuilder};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::value_objects::Identifier;
+
+/// Files have no ids, a name based uuid of their path and position keeps them stable across renders.
+pub fn content_id(key: &str) -> Result<Identifier> {
+    Identifier::new(Uuid::new_v5(&Uuid::NAMESPACE_URL, format!("content:{key}").as_bytes()))
+}
+
+/// Renders the Markdown into HTML, raw HTML is kept as the content is written by the site owner.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, Parser::new_ext(markdown, options));
+
+    rendered
+}
+
+/// The plain text of the Markdown, e.g. for the description metatag.
+pub fn markdown_to_text(markdown: &str) -> String {
+    strip_tags(&markdown_to_html(markdown)).trim().to_string()
+}
+
+/// Maps the metatags of the front matter, keyed by their name, e.g. `og:image`.
+///
+/// Unlike the ones of the CMS, files rarely define every metatag, so defaulting them isn't logged.
+pub fn metatags_front_matter_mapper(metatags: &BTreeMap<String, String>, fallback: &MetatagsFallback) -> Result<MetaTags> {
+    let contents = metatags
+        .iter()
+        .map(|(name, content)| (name.as_str(), content.as_str()))
+        .collect::<Vec<_>>();
+
+    metatags_mapper(&contents, fallback).map(|(metatags, _)| metatags)
+}
+
+pub fn image_front_matter_mapper(image: &ImageFrontMatter) -> Result<Image> {
+    ImageBuilder::default()
+        .id(content_id(image.url())?)
+        .url(image.url().to_string().try_into()?)
+        .alt(image.alt().to_string().try_into()?)
+        .title(image.title().as_deref().unwrap_or(image.alt()).to_string().try_into()?)
+        .width(*image.width())
+        .height(*image.height())
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+pub fn document_front_matter_mapper(document: &DocumentFrontMatter) -> Result<Document> {
+    DocumentBuilder::default()
+        .id(content_id(document.url())?)
+        .url(document.url().to_string().try_into()?)
+        .mime(document.mime().to_string().try_into()?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+pub fn link_front_matter_mapper(link: &LinkFrontMatter) -> Result<Link> {
+    LinkBuilder::default()
+        .url(link.url().to_string().try_into()?)
+        .title(link.title().to_string())
+        .options(vec![])
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+/// Maps the items of a timeline, `key` identifies the timeline within the content directory.
+pub fn timeline_front_matter_mapper(key: &str, items: &[TimelineItemFrontMatter]) -> Result<Timeline> {
+    let items = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| timeline_item_front_matter_mapper(&format!("{key}-{index}"), item))
+        .collect::<Result<Vec<_>>>()?;
+
+    TimelineBuilder::default()
+        .id(content_id(key)?)
+        .items(items)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+fn timeline_item_front_matter_mapper(key: &str, item: &TimelineItemFrontMatter) -> Result<TimelineItem> {
+    let end_date = match item.end_date() {
+        Some(end) => Some(end.to_string().try_into()?),
+        None => None,
+    };
+
+    TimelineItemBuilder::default()
+        .id(content_id(key)?)
+        .title(item.title().to_string().try_into()?)
+        .subtitle(item.subtitle().to_string().try_into()?)
+        .start_date(item.start_date().to_string().try_into()?)
+        .end_date(end_date)
+        .text(item.text().clone())
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_to_html_succeeds_when_code_block_has_language() {
+        let html = markdown_to_html("Some `code`:\n\n```rust\nfn main() {}\n```\n");
+
+        assert!(html.contains("<p>Some <code>code</code>:</p>"));
+        assert!(html.contains(r#"<pre><code class="language-rust">fn main() {}"#));
+    }
+
+    #[test]
+    fn content_id_is_stable_when_key_is_the_same() {
+        assert_eq!(content_id("articles/linked-lists.md").unwrap(), content_id("articles/linked-lists.md").unwrap());
+        assert_ne!(content_id("articles/linked-lists.md").unwrap(), content_id("articles/stacks.md").unwrap());
+    }
+}
@@ -0,0 +1,62 @@
+use itertools::Itertools;
+
+use crate::adapters::driven::markdown_files::entities::{ContentFile, MenuFrontMatter, MenuItemFrontMatter};
+use crate::adapters::driven::markdown_files::entities::SiteFrontMatter;
+use crate::adapters::driven::markdown_files::mappers::{content_id, image_front_matter_mapper};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::layout::{MenuItem, MenuItemBuilder, MenuTree, MenuTreeBuilder};
+use crate::application::domain::layout::{SiteBranding, SiteBrandingBuilder};
+use crate::application::value_objects::Url;
+
+pub fn menu_file_mapper(file: &ContentFile<MenuFrontMatter>) -> Result<MenuTree> {
+    MenuTreeBuilder::default()
+        .items(menu_items_mapper(file.path(), file.front_matter().items())?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+pub fn site_file_mapper(file: &ContentFile<SiteFrontMatter>) -> Result<SiteBranding> {
+    let site = file.front_matter();
+    let logo = match site.logo() {
+        Some(logo) => Some(image_front_matter_mapper(logo)?),
+        None => None,
+    };
+
+    SiteBrandingBuilder::default()
+        .name(site.name().to_string().try_into()?)
+        .slogan(site.slogan().as_deref().map(str::trim).filter(|s| !s.is_empty()).map(String::from))
+        .logo(logo)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+/// Maps a level of the menu sorted by weight, `key` identifies the level within the menu.
+fn menu_items_mapper(key: &str, items: &[MenuItemFrontMatter]) -> Result<Vec<MenuItem>> {
+    items
+        .iter()
+        .enumerate()
+        .sorted_by_key(|(_, item)| *item.weight())
+        .map(|(index, item)| menu_item_mapper(&format!("{key}-{index}"), item))
+        .collect()
+}
+
+fn menu_item_mapper(key: &str, item: &MenuItemFrontMatter) -> Result<MenuItem> {
+    let url: Url = item.url().to_string().try_into()?;
+    let icon = match item.icon() {
+        Some(icon) => Some(image_front_matter_mapper(icon)?),
+        None => None,
+    };
+
+    MenuItemBuilder::default()
+        .id(content_id(key)?)
+        .title(item.title().to_string())
+        .external(url.is_absolute())
+        .url(url)
+        .hidden(*item.hidden())
+        .weight(*item.weight())
+        .icon(icon)
+        .expanded(*item.expanded())
+        .children(menu_items_mapper(key, item.children())?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
@@ -0,0 +1,15 @@
+mod article_mapper;
+mod category_mapper;
+mod common_mapper;
+mod layout_mapper;
+mod page_mapper;
+mod portfolio_mapper;
+mod project_mapper;
+
+pub use article_mapper::*;
+pub use category_mapper::*;
+pub use common_mapper::*;
+pub use layout_mapper::*;
+pub use page_mapper::*;
+pub use portfolio_mapper::*;
+pub use project_mapper::*;
@@ -0,0 +1,23 @@
+use crate::adapters::driven::metatags::MetatagsFallback;
+use crate::adapters::driven::markdown_files::entities::{ContentFile, PageFrontMatter};
+use crate::adapters::driven::markdown_files::mappers::{content_id, markdown_to_text, metatags_front_matter_mapper};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::page::{Page, PageBuilder};
+
+pub fn page_file_mapper(file: &ContentFile<PageFrontMatter>) -> Result<Page> {
+    let page = file.front_matter();
+    let metatags_fallback = MetatagsFallback::default()
+        .title(page.title())
+        .description(&markdown_to_text(file.body()))
+        .path(page.path());
+
+    PageBuilder::default()
+        .id(content_id(file.path())?)
+        .slug(page.path().to_string().try_into()?)
+        .status((*page.published()).into())
+        .title(page.title().to_string().try_into()?)
+        .created_at(page.created().to_string().try_into()?)
+        .metatags(metatags_front_matter_mapper(page.metatags(), &metatags_fallback)?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
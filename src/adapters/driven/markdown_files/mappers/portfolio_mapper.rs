@@ -0,0 +1,100 @@
+use crate::adapters::driven::metatags::MetatagsFallback;
+use crate::adapters::driven::markdown_files::entities::{AboutMeFrontMatter, ContentFile, PortfolioFrontMatter};
+use crate::adapters::driven::markdown_files::entities::{PortfolioSectionFrontMatter, ResumeFrontMatter};
+use crate::adapters::driven::markdown_files::mappers::{content_id, document_front_matter_mapper};
+use crate::adapters::driven::markdown_files::mappers::{image_front_matter_mapper, metatags_front_matter_mapper};
+use crate::adapters::driven::markdown_files::mappers::timeline_front_matter_mapper;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::portfolio::{AboutMe, AboutMeBuilder, BlogsBuilder, ContactBuilder};
+use crate::application::domain::portfolio::{Portfolio, PortfolioBuilder, PortfolioSection};
+use crate::application::domain::portfolio::{ProjectsBuilder, Resume, ResumeBuilder};
+
+/// Maps the portfolio, the projects and articles of its sections are filled in by the use case.
+pub fn portfolio_file_mapper(file: &ContentFile<PortfolioFrontMatter>) -> Result<Portfolio> {
+    let portfolio = file.front_matter();
+    let metatags_fallback = MetatagsFallback::default()
+        .title(portfolio.title())
+        .path(portfolio.path());
+
+    let sections = portfolio
+        .sections()
+        .iter()
+        .enumerate()
+        .map(|(index, section)| section_mapper(&format!("{}#section-{index}", file.path()), section))
+        .collect::<Result<Vec<_>>>()?;
+
+    PortfolioBuilder::default()
+        .id(content_id(file.path())?)
+        .status((*portfolio.published()).into())
+        .title(portfolio.title().to_string().try_into()?)
+        .created_at(portfolio.created().to_string().try_into()?)
+        .sections(sections)
+        .metatags(metatags_front_matter_mapper(portfolio.metatags(), &metatags_fallback)?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+fn section_mapper(key: &str, section: &PortfolioSectionFrontMatter) -> Result<PortfolioSection> {
+    let section = match section {
+        PortfolioSectionFrontMatter::AboutMe(s) => PortfolioSection::AboutMe(about_me_mapper(key, s)?),
+        PortfolioSectionFrontMatter::Resume(s) => PortfolioSection::Resume(resume_mapper(key, s)?),
+        PortfolioSectionFrontMatter::Projects(s) => PortfolioSection::Projects(
+            ProjectsBuilder::default()
+                .id(content_id(key)?)
+                .title(s.title().to_string().try_into()?)
+                .subtitle(s.subtitle().to_string().try_into()?)
+                .text(s.text().to_string().try_into()?)
+                .projects(vec![])
+                .build()
+                .map_err(|e| AppError::Unexpected(e.to_string()))?,
+        ),
+        PortfolioSectionFrontMatter::Blogs(s) => PortfolioSection::Blogs(
+            BlogsBuilder::default()
+                .id(content_id(key)?)
+                .title(s.title().to_string().try_into()?)
+                .subtitle(s.subtitle().to_string().try_into()?)
+                .text(s.text().to_string())
+                .articles(vec![])
+                .build()
+                .map_err(|e| AppError::Unexpected(e.to_string()))?,
+        ),
+        PortfolioSectionFrontMatter::Contact(s) => PortfolioSection::Contact(
+            ContactBuilder::default()
+                .id(content_id(key)?)
+                .title(s.title().to_string().try_into()?)
+                .subtitle(s.subtitle().to_string().try_into()?)
+                .text(s.text().to_string())
+                .build()
+                .map_err(|e| AppError::Unexpected(e.to_string()))?,
+        ),
+        PortfolioSectionFrontMatter::Unknown => PortfolioSection::Unknown,
+    };
+
+    Ok(section)
+}
+
+fn about_me_mapper(key: &str, s: &AboutMeFrontMatter) -> Result<AboutMe> {
+    AboutMeBuilder::default()
+        .id(content_id(key)?)
+        .title(s.title().to_string().try_into()?)
+        .subtitle(s.subtitle().to_string().try_into()?)
+        .text(s.text().to_string().try_into()?)
+        .skills(s.skills().clone())
+        .years_of_experience(*s.years_of_experience())
+        .profile_picture(image_front_matter_mapper(s.profile_picture())?)
+        .cv_document(document_front_matter_mapper(s.cv_document())?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+fn resume_mapper(key: &str, s: &ResumeFrontMatter) -> Result<Resume> {
+    ResumeBuilder::default()
+        .id(content_id(key)?)
+        .title(s.title().to_string().try_into()?)
+        .subtitle(s.subtitle().to_string().try_into()?)
+        .text(s.text().to_string().try_into()?)
+        .education(timeline_front_matter_mapper(&format!("{key}-education"), s.education())?)
+        .experience(timeline_front_matter_mapper(&format!("{key}-experience"), s.experience())?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
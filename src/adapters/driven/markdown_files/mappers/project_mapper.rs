@@ -0,0 +1,53 @@
+use crate::adapters::driven::metatags::MetatagsFallback;
+use crate::adapters::driven::markdown_files::entities::{ContentFile, ProjectFrontMatter};
+use crate::adapters::driven::markdown_files::mappers::{content_id, image_front_matter_mapper, link_front_matter_mapper};
+use crate::adapters::driven::markdown_files::mappers::{markdown_to_html, metatags_front_matter_mapper};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::project::{GalleryBuilder, Project, ProjectBuilder};
+
+pub fn project_file_mapper(file: &ContentFile<ProjectFrontMatter>) -> Result<Project> {
+    let project = file.front_matter();
+    let end_date = match project.end_date() {
+        Some(end) => Some(end.to_string().try_into()?),
+        None => None,
+    };
+    let link = match project.link() {
+        Some(link) => Some(link_front_matter_mapper(link)?),
+        None => None,
+    };
+    // files have no image styles, the thumbnails are the images themselves
+    let images = project
+        .gallery()
+        .iter()
+        .map(image_front_matter_mapper)
+        .collect::<Result<Vec<_>>>()?;
+    let gallery = GalleryBuilder::default()
+        .thumbnails(images.clone())
+        .images(images)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))?;
+
+    let metatags_fallback = MetatagsFallback::default()
+        .title(project.title())
+        .description(project.summary())
+        .image(project.image().url())
+        .path(project.path());
+
+    ProjectBuilder::default()
+        .id(content_id(file.path())?)
+        .slug(project.path().to_string().try_into()?)
+        .status((*project.published()).into())
+        .title(project.title().to_string().try_into()?)
+        .text(project.summary().to_string())
+        .body(markdown_to_html(file.body()))
+        .role(project.role().clone().filter(|r| !r.trim().is_empty()))
+        .technologies(project.technologies().iter().map(|t| t.trim().to_string()).collect())
+        .start_date(project.start_date().to_string().try_into()?)
+        .end_date(end_date)
+        .link(link)
+        .image(image_front_matter_mapper(project.image())?)
+        .gallery(gallery)
+        .metatags(metatags_front_matter_mapper(project.metatags(), &metatags_fallback)?)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))
+}
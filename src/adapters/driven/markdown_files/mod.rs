@@ -0,0 +1,4 @@
+pub mod entities;
+pub mod mappers;
+pub mod repositories;
+pub mod services;
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::adapters::driven::markdown_files::entities::{ArticleFrontMatter, CategoryFrontMatter, ContentFile};
+use crate::adapters::driven::markdown_files::mappers::{article_file_mapper, category_file_mapper};
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::article::{Article, Articles, Category};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::ports::driven::ForFetchingArticlesFeatured;
+use crate::application::ports::driven::{ForFetchingArticleData, ForFetchingArticlesList};
+
+/// Amount of promoted articles featured in the portfolio, as many as the CMS returns.
+const FEATURED_LIMIT: usize = 2;
+
+/// Repository reading the articles from `articles/*.md`, and their categories from `categories/*.md`.
+///
+/// Articles are matched by the `path` of their front matter and listed from the newest.
+pub struct ArticleRepository {
+    content_files: ContentFilesService,
+}
+
+impl ArticleRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+
+    async fn articles<F>(&self, filter: F) -> Result<Articles>
+    where
+        F: Fn(&ArticleFrontMatter) -> bool,
+    {
+        let categories = self
+            .content_files
+            .read_all::<CategoryFrontMatter>("categories")
+            .await?
+            .iter()
+            .map(|file| Ok((file.stem().to_string(), category_file_mapper(file)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        let mut articles = self
+            .content_files
+            .read_all::<ArticleFrontMatter>("articles")
+            .await?
+            .iter()
+            .filter(|file| filter(file.front_matter()))
+            .map(|file| article_mapper(file, &categories))
+            .collect::<Result<Articles>>()?;
+        articles.sort_by_key(|article| Reverse(**article.created_at()));
+
+        Ok(articles)
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingArticlesFeatured for ArticleRepository {
+    async fn get_featured(&self) -> Result<Articles> {
+        let mut articles = self.articles(|article| *article.published() && *article.promoted()).await?;
+        articles.truncate(FEATURED_LIMIT);
+
+        Ok(articles)
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingArticlesList for ArticleRepository {
+    async fn get_list(&self, category_id: Option<String>) -> Result<Vec<Article>> {
+        self.articles(|article| {
+            *article.published() && category_id.as_ref().is_none_or(|category| article.category() == category)
+        })
+        .await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Article>> {
+        self.articles(|article| *article.published()).await
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingArticleData for ArticleRepository {
+    async fn find_by_slug(&self, slug: &str) -> Result<Article> {
+        self.articles(|article| article.path() == slug)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(AppError::ResourceNotFound(slug.to_string()))
+    }
+}
+
+fn article_mapper(file: &ContentFile<ArticleFrontMatter>, categories: &HashMap<String, Category>) -> Result<Article> {
+    let category = file.front_matter().category();
+    let category = categories
+        .get(category)
+        .cloned()
+        .ok_or(AppError::ResourceNotFound(format!("categories/{category}.md")))?;
+
+    article_file_mapper(file, category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repository() -> ArticleRepository {
+        ArticleRepository::new(ContentFilesService::new("tests/fixtures/content"))
+    }
+
+    #[actix_rt::test]
+    async fn find_succeeds_when_path_matches_article() {
+        let article = repository().find_by_slug("/en/articles/algorithms/linked-lists").await.unwrap();
+
+        assert_eq!(article.title().as_str(), "Understanding linked lists");
+        assert_eq!(article.category().title().as_str(), "Algorithms");
+        assert_eq!(article.content().len(), 1);
+    }
+
+    #[actix_rt::test]
+    async fn find_fails_with_not_found_when_path_is_unknown() {
+        let result = repository().find_by_slug("/en/articles/algorithms/missing").await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(_))));
+    }
+
+    #[actix_rt::test]
+    async fn list_succeeds_when_category_is_filtered() {
+        let all = repository().get_list(None).await.unwrap();
+        let algorithms = repository().get_list(Some("algorithms".to_string())).await.unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].title().as_str(), "Error handling in Rust");
+        assert_eq!(algorithms.len(), 1);
+        assert_eq!(algorithms[0].title().as_str(), "Understanding linked lists");
+    }
+
+    #[actix_rt::test]
+    async fn featured_succeeds_when_articles_are_promoted() {
+        let featured = repository().get_featured().await.unwrap();
+
+        assert_eq!(featured.len(), 1);
+        assert_eq!(featured[0].title().as_str(), "Understanding linked lists");
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::adapters::driven::markdown_files::entities::CategoryFrontMatter;
+use crate::adapters::driven::markdown_files::mappers::category_file_mapper;
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::article::Category;
+use crate::application::domain::core::Result;
+use crate::application::ports::driven::ForFetchingCategoriesList;
+
+/// Repository reading the published categories from `categories/*.md`, sorted by title.
+pub struct CategoryRepository {
+    content_files: ContentFilesService,
+}
+
+impl CategoryRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingCategoriesList for CategoryRepository {
+    async fn find_all_categories(&self) -> Result<Vec<Category>> {
+        let mut categories = self
+            .content_files
+            .read_all::<CategoryFrontMatter>("categories")
+            .await?
+            .iter()
+            .filter(|file| *file.front_matter().published())
+            .map(|file| category_file_mapper(file))
+            .collect::<Result<Vec<_>>>()?;
+        categories.sort_by(|a, b| a.title().cmp(b.title()));
+
+        Ok(categories)
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+
+use crate::adapters::driven::markdown_files::entities::{MenuFrontMatter, SiteFrontMatter};
+use crate::adapters::driven::markdown_files::mappers::{menu_file_mapper, site_file_mapper};
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::core::Result;
+use crate::application::domain::layout::{MenuTree, SiteBranding};
+use crate::application::ports::driven::{ForFetchingMenuData, ForFetchingSiteBranding};
+
+/// Repository reading the menus from `menus/<id>.md` and the site branding from `site.md`.
+pub struct LayoutRepository {
+    content_files: ContentFilesService,
+}
+
+impl LayoutRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingMenuData for LayoutRepository {
+    async fn find_by_id(&self, id: &str) -> Result<MenuTree> {
+        let file = self.content_files.read::<MenuFrontMatter>(&format!("menus/{id}.md")).await?;
+
+        menu_file_mapper(&file)
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingSiteBranding for LayoutRepository {
+    async fn get_branding(&self) -> Result<SiteBranding> {
+        let file = self.content_files.read::<SiteFrontMatter>("site.md").await?;
+
+        site_file_mapper(&file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::core::AppError;
+
+    fn repository() -> LayoutRepository {
+        LayoutRepository::new(ContentFilesService::new("tests/fixtures/content"))
+    }
+
+    #[actix_rt::test]
+    async fn menu_succeeds_when_items_are_nested() {
+        let menu = repository().find_by_id("main").await.unwrap();
+        let titles = menu.items().iter().map(|i| i.title().as_str()).collect::<Vec<_>>();
+
+        assert_eq!(titles, vec!["Home", "Articles", "GitHub"]);
+        assert_eq!(menu.items()[1].children().len(), 1);
+        assert!(menu.items()[2].external());
+    }
+
+    #[actix_rt::test]
+    async fn menu_fails_with_not_found_when_file_is_missing() {
+        let result = repository().find_by_id("sidebar").await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(_))));
+    }
+
+    #[actix_rt::test]
+    async fn branding_succeeds_when_site_file_exists() {
+        let branding = repository().get_branding().await.unwrap();
+
+        assert_eq!(branding.name().as_str(), "Tiagocode");
+        assert!(branding.logo().is_some());
+    }
+}
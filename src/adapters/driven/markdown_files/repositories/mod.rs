@@ -0,0 +1,13 @@
+pub mod article_repository;
+pub mod category_repository;
+pub mod layout_repository;
+pub mod page_repository;
+pub mod portfolio_repository;
+pub mod project_repository;
+
+pub use article_repository::ArticleRepository;
+pub use category_repository::CategoryRepository;
+pub use layout_repository::LayoutRepository;
+pub use page_repository::PageRepository;
+pub use portfolio_repository::PortfolioRepository;
+pub use project_repository::ProjectRepository;
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+
+use crate::adapters::driven::markdown_files::entities::PageFrontMatter;
+use crate::adapters::driven::markdown_files::mappers::page_file_mapper;
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::page::Page;
+use crate::application::ports::driven::ForFetchingPageData;
+
+/// Repository reading the pages from `pages/*.md`, matched by the `path` of their front matter.
+pub struct PageRepository {
+    content_files: ContentFilesService,
+}
+
+impl PageRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingPageData for PageRepository {
+    async fn find_by_slug(&self, slug: &str) -> Result<Page> {
+        let file = self
+            .content_files
+            .read_all::<PageFrontMatter>("pages")
+            .await?
+            .into_iter()
+            .find(|file| file.front_matter().path() == slug)
+            .ok_or(AppError::ResourceNotFound(slug.to_string()))?;
+
+        page_file_mapper(&file)
+    }
+
+    async fn get_list(&self) -> Result<Vec<Page>> {
+        self.content_files
+            .read_all::<PageFrontMatter>("pages")
+            .await?
+            .iter()
+            .filter(|file| *file.front_matter().published())
+            .map(|file| page_file_mapper(file))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_rt::test]
+    async fn find_succeeds_when_path_matches_page() {
+        let page = PageRepository::new(ContentFilesService::new("tests/fixtures/content"))
+            .find_by_slug("/en/articles")
+            .await
+            .unwrap();
+
+        assert_eq!(page.title().as_str(), "Articles");
+        assert!(page.metatags().description().starts_with("Notes about software engineering"));
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+
+use crate::adapters::driven::markdown_files::entities::PortfolioFrontMatter;
+use crate::adapters::driven::markdown_files::mappers::portfolio_file_mapper;
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::portfolio::Portfolio;
+use crate::application::ports::driven::ForFetchingPortfolioData;
+
+/// Repository reading the portfolio from `portfolio/*.md`, matched by the `path` of its front matter.
+pub struct PortfolioRepository {
+    content_files: ContentFilesService,
+}
+
+impl PortfolioRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingPortfolioData for PortfolioRepository {
+    async fn find_by_slug(&self, slug: &str) -> Result<Portfolio> {
+        let file = self
+            .content_files
+            .read_all::<PortfolioFrontMatter>("portfolio")
+            .await?
+            .into_iter()
+            .find(|file| file.front_matter().path() == slug)
+            .ok_or(AppError::ResourceNotFound(slug.to_string()))?;
+
+        portfolio_file_mapper(&file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::portfolio::PortfolioSection;
+
+    #[actix_rt::test]
+    async fn find_succeeds_when_path_matches_portfolio() {
+        let portfolio = PortfolioRepository::new(ContentFilesService::new("tests/fixtures/content"))
+            .find_by_slug("/en/portfolio/santiago-marulanda")
+            .await
+            .unwrap();
+
+        assert_eq!(portfolio.sections().len(), 5);
+        assert!(matches!(&portfolio.sections()[0], PortfolioSection::AboutMe(a) if a.skills().len() == 3));
+        assert!(matches!(&portfolio.sections()[1], PortfolioSection::Resume(r) if r.experience().items().len() == 2));
+    }
+}
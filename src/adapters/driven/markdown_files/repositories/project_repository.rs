@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use std::cmp::Reverse;
+
+use crate::adapters::driven::markdown_files::entities::ProjectFrontMatter;
+use crate::adapters::driven::markdown_files::mappers::project_file_mapper;
+use crate::adapters::driven::markdown_files::services::ContentFilesService;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::project::Project;
+use crate::application::ports::driven::ForFetchingProjects;
+
+/// Repository reading the projects from `projects/*.md`, listed from the most recently started.
+pub struct ProjectRepository {
+    content_files: ContentFilesService,
+}
+
+impl ProjectRepository {
+    pub fn new(content_files: ContentFilesService) -> Self {
+        Self { content_files }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForFetchingProjects for ProjectRepository {
+    async fn get_list(&self) -> Result<Vec<Project>> {
+        let mut projects = self
+            .content_files
+            .read_all::<ProjectFrontMatter>("projects")
+            .await?
+            .iter()
+            .filter(|file| *file.front_matter().published())
+            .map(|file| project_file_mapper(file))
+            .collect::<Result<Vec<_>>>()?;
+        projects.sort_by_key(|project| Reverse(**project.start_date()));
+
+        Ok(projects)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+        let file = self
+            .content_files
+            .read_all::<ProjectFrontMatter>("projects")
+            .await?
+            .into_iter()
+            .find(|file| file.front_matter().path() == slug)
+            .ok_or(AppError::ResourceNotFound(slug.to_string()))?;
+
+        project_file_mapper(&file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_rt::test]
+    async fn find_succeeds_when_path_matches_project() {
+        let project = ProjectRepository::new(ContentFilesService::new("tests/fixtures/content"))
+            .find_by_slug("/en/projects/website")
+            .await
+            .unwrap();
+
+        assert_eq!(project.technologies(), &vec!["Rust".to_string(), "Leptos".to_string()]);
+        assert!(project.end_date().is_none());
+        assert!(project.body().contains("<h2>Architecture</h2>"));
+    }
+}
@@ -0,0 +1,218 @@
+use futures::future::join_all;
+use serde::de::DeserializeOwned;
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tokio::fs;
+use tracing::warn;
+
+use crate::adapters::driven::markdown_files::entities::ContentFile;
+use crate::application::domain::core::{AppError, Result};
+
+/// A parsed file along with the modification time of the file it was parsed from.
+type CachedFile = (SystemTime, Arc<dyn Any + Send + Sync>);
+
+/// Reads the Markdown files of the content directory, e.g.
+///
+/// ```text
+/// content/
+/// ├── site.md
+/// ├── articles/linked-lists.md
+/// ├── categories/rust.md
+/// ├── menus/main.md
+/// ├── pages/articles.md
+/// ├── portfolio/santiago-marulanda.md
+/// └── projects/website.md
+/// ```
+///
+/// Every file starts with a YAML front matter between `---` lines, followed by its Markdown body.
+///
+/// Parsed files are kept until the file is modified, so listing a directory only reads the
+/// files that changed since the last time. Clones share the same parsed files.
+#[derive(Debug, Clone)]
+pub struct ContentFilesService {
+    directory: PathBuf,
+    files: Arc<Mutex<HashMap<(TypeId, String), CachedFile>>>,
+}
+
+impl ContentFilesService {
+    pub fn new(directory: &str) -> Self {
+        Self {
+            directory: PathBuf::from(directory),
+            files: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Reads a single file, e.g. `menus/main.md`.
+    ///
+    /// # Errors
+    /// * `AppError::ResourceNotFound` - The file doesn't exist.
+    /// * `AppError::Deserialization` - The front matter is missing or doesn't match `T`.
+    pub async fn read<T>(&self, path: &str) -> Result<Arc<ContentFile<T>>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let io_error = |e: std::io::Error| match e.kind() {
+            ErrorKind::NotFound => AppError::ResourceNotFound(path.to_string()),
+            _ => AppError::External(type_name::<Self>(), format!("{path}: {e}")),
+        };
+        let file_path = self.directory.join(path);
+        let modified = fs::metadata(&file_path).await.and_then(|m| m.modified()).map_err(io_error)?;
+        let key = (TypeId::of::<T>(), path.to_string());
+
+        if let Some(file) = self.cached(&key, modified) {
+            return Ok(file);
+        }
+
+        let content = fs::read_to_string(&file_path).await.map_err(io_error)?;
+        let (front_matter, body) = split_front_matter(&content)
+            .ok_or(AppError::Deserialization("Missing front matter".to_string(), path.to_string()))?;
+        let front_matter = serde_yaml::from_str::<T>(front_matter)
+            .map_err(|e| AppError::Deserialization(e.to_string(), path.to_string()))?;
+        let file = Arc::new(ContentFile::new(path, front_matter, body));
+
+        self.files
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))?
+            .insert(key, (modified, file.clone()));
+
+        Ok(file)
+    }
+
+    /// Reads every Markdown file of a directory, e.g. `articles`, sorted by name.
+    ///
+    /// A missing directory holds no files, and the files that can't be read are left out
+    /// with a warning so a single malformed file doesn't take the whole list down.
+    pub async fn read_all<T>(&self, directory: &str) -> Result<Vec<Arc<ContentFile<T>>>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let mut entries = match fs::read_dir(self.directory.join(directory)).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(AppError::External(type_name::<Self>(), format!("{directory}: {e}"))),
+        };
+
+        let mut paths = vec![];
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            if let Some(name) = entry.file_name().to_str().filter(|name| name.ends_with(".md")) {
+                paths.push(format!("{directory}/{name}"));
+            }
+        }
+        paths.sort();
+
+        let files = join_all(paths.iter().map(|path| self.read::<T>(path))).await;
+
+        Ok(files
+            .into_iter()
+            .filter_map(|file| file.inspect_err(|e| warn!("Skipping a content file: {e}")).ok())
+            .collect())
+    }
+
+    /// Returns the parsed file when it hasn't been modified since it was parsed.
+    fn cached<T>(&self, key: &(TypeId, String), modified: SystemTime) -> Option<Arc<ContentFile<T>>>
+    where
+        T: Send + Sync + 'static,
+    {
+        let files = self.files.lock().ok()?;
+        let (parsed_at, file) = files.get(key)?;
+
+        (*parsed_at == modified).then(|| file.clone().downcast().ok()).flatten()
+    }
+}
+
+/// Splits the file into its front matter and its body, `None` when it doesn't start with one.
+fn split_front_matter(content: &str) -> Option<(&str, &str)> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut lines = content.split_inclusive('\n');
+    let opening = lines.next()?;
+
+    if opening.trim_end() != "---" {
+        return None;
+    }
+
+    let mut end = opening.len();
+    for line in lines {
+        if line.trim_end() == "---" {
+            return Some((&content[opening.len()..end], &content[end + line.len()..]));
+        }
+        end += line.len();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::driven::markdown_files::entities::{ArticleFrontMatter, MenuFrontMatter};
+
+    #[test]
+    fn split_succeeds_when_front_matter_is_closed() {
+        let (front_matter, body) = split_front_matter("---\ntitle: Hello\n---\n# Hello\n").unwrap();
+
+        assert_eq!(front_matter, "title: Hello\n");
+        assert_eq!(body, "# Hello\n");
+    }
+
+    #[test]
+    fn split_fails_when_front_matter_is_missing() {
+        assert!(split_front_matter("# Hello\n").is_none());
+        assert!(split_front_matter("---\ntitle: Hello\n").is_none());
+    }
+
+    #[actix_rt::test]
+    async fn read_succeeds_when_file_has_front_matter() {
+        let file = ContentFilesService::new("tests/fixtures/content")
+            .read::<ArticleFrontMatter>("articles/linked-lists.md")
+            .await
+            .unwrap();
+
+        assert_eq!(file.stem(), "linked-lists");
+        assert_eq!(file.front_matter().category(), "algorithms");
+        assert!(file.body().starts_with("A linked list"));
+    }
+
+    #[actix_rt::test]
+    async fn read_fails_with_not_found_when_file_is_missing() {
+        let result = ContentFilesService::new("tests/fixtures/content")
+            .read::<MenuFrontMatter>("menus/missing.md")
+            .await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(path)) if path == "menus/missing.md"));
+    }
+
+    #[actix_rt::test]
+    async fn read_all_succeeds_when_directory_is_missing() {
+        let files = ContentFilesService::new("tests/fixtures/content")
+            .read_all::<ArticleFrontMatter>("drafts")
+            .await
+            .unwrap();
+
+        assert!(files.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn read_all_succeeds_when_a_file_is_malformed() {
+        let files = ContentFilesService::new("tests/fixtures/content")
+            .read_all::<ArticleFrontMatter>("articles")
+            .await
+            .unwrap();
+        let stems = files.iter().map(|file| file.stem()).collect::<Vec<_>>();
+
+        assert_eq!(stems, vec!["draft", "error-handling", "linked-lists"]);
+    }
+
+    #[actix_rt::test]
+    async fn read_all_succeeds_when_files_are_already_parsed() {
+        let service = ContentFilesService::new("tests/fixtures/content");
+        let first = service.read_all::<ArticleFrontMatter>("articles").await.unwrap();
+
+        let second = service.clone().read_all::<ArticleFrontMatter>("articles").await.unwrap();
+
+        assert!(first.iter().zip(&second).all(|(a, b)| Arc::ptr_eq(a, b)));
+    }
+}
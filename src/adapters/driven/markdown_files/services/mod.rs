@@ -0,0 +1,3 @@
+mod content_files_service;
+
+pub use content_files_service::*;
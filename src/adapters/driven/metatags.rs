@@ -0,0 +1,302 @@
+use lazy_static::lazy_static;
+use std::env;
+use voca_rs::chop::prune;
+
+use crate::application::domain::common::{MetaTags, MetaTagsBuilder};
+use crate::application::domain::core::{AppError, Result};
+use crate::application::value_objects::{RequiredText, Url};
+
+const MAX_DESCRIPTION_LENGTH: usize = 160;
+
+lazy_static! {
+    static ref METATAGS_DEFAULTS: MetatagsDefaults = MetatagsDefaults::from_env();
+}
+
+/// Site-wide values used when neither the content nor the node provide a metatag.
+#[derive(Debug, Clone)]
+struct MetatagsDefaults {
+    site_name: String,
+    site_url: String,
+    description: String,
+    keywords: String,
+    image: String,
+    robots: String,
+    twitter_handle: String,
+}
+
+impl MetatagsDefaults {
+    /// Reads the defaults from the `SITE_*` environment variables, falling back to the
+    /// values of tiagocode.com for the ones undefined.
+    fn from_env() -> Self {
+        let var = |key: &str, default: &str| {
+            env::var(key)
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or(default.to_string())
+        };
+        let site_url = var("SITE_URL", "https://tiagocode.com");
+
+        Self {
+            site_name: var("SITE_NAME", "Tiagocode"),
+            description: var("SITE_DESCRIPTION", "Software engineering articles, projects and résumé"),
+            keywords: var("SITE_KEYWORDS", "Software Developer, Rust, Leptos, Drupal"),
+            image: var("SITE_IMAGE", &format!("{site_url}/assets/images/logo_teal.svg")),
+            robots: var("SITE_ROBOTS", "index, follow"),
+            twitter_handle: var("SITE_TWITTER_HANDLE", "@smarulanda97"),
+            site_url,
+        }
+    }
+}
+
+/// Values derived from the node, used before the site-wide defaults.
+#[derive(Debug, Clone, Default)]
+pub struct MetatagsFallback {
+    title: Option<String>,
+    description: Option<String>,
+    image: Option<String>,
+    path: Option<String>,
+}
+
+impl MetatagsFallback {
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// The plain text description, pruned at a word boundary to fit search results.
+    pub fn description(mut self, description: &str) -> Self {
+        let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+        self.description = Some(prune(&description, MAX_DESCRIPTION_LENGTH, "…"));
+        self
+    }
+
+    pub fn image(mut self, image: &str) -> Self {
+        self.image = Some(image.to_string());
+        self
+    }
+
+    /// The path alias of the node, resolved against the site url for the canonical url.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+}
+
+/// Maps the metatags, given as their name and content, e.g. `("og:image", "https://…")`, into
+/// `MetaTags`. The missing or invalid ones fall back to the values derived from the node and
+/// then to the site-wide defaults, the names of the defaulted metatags are returned as well.
+pub fn metatags_mapper(contents: &[(&str, &str)], fallback: &MetatagsFallback) -> Result<(MetaTags, Vec<&'static str>)> {
+    metatags_with_defaults_mapper(contents, fallback, &METATAGS_DEFAULTS)
+}
+
+fn metatags_with_defaults_mapper(
+    contents: &[(&str, &str)],
+    fallback: &MetatagsFallback,
+    defaults: &MetatagsDefaults,
+) -> Result<(MetaTags, Vec<&'static str>)> {
+    let mut resolver = MetatagsResolver::new(contents);
+    let site_name = Some(defaults.site_name.clone());
+    let twitter_handle = Some(defaults.twitter_handle.clone());
+
+    let title: RequiredText = resolver.resolve("title", &["title"], [fallback.title.clone(), site_name.clone()])?;
+    let description: RequiredText = resolver.resolve(
+        "description",
+        &["description"],
+        [fallback.description.clone(), Some(defaults.description.clone())],
+    )?;
+    let canonical_url: Url = resolver.resolve(
+        "canonical",
+        &["canonical"],
+        [
+            fallback.path.as_ref().map(|p| format!("{}{p}", defaults.site_url.trim_end_matches('/'))),
+            Some(defaults.site_url.clone()),
+        ],
+    )?;
+    let image: Url = resolver.resolve(
+        "og:image",
+        &["og:image:secure_url", "og:image"],
+        [fallback.image.clone(), Some(defaults.image.clone())],
+    )?;
+
+    let metatags = MetaTagsBuilder::default()
+        .keywords(resolver.resolve("keywords", &["keywords"], [Some(defaults.keywords.clone())])?)
+        .robots(resolver.resolve("robots", &["robots"], [Some(defaults.robots.clone())])?)
+        .og_type(resolver.resolve("og:type", &["og:type"], [Some("website".to_string())])?)
+        .og_site_name(resolver.resolve("og:site_name", &["og:site_name"], [site_name])?)
+        .og_title(resolver.resolve("og:title", &["og:title"], [Some(title.to_string())])?)
+        .og_description(resolver.resolve("og:description", &["og:description"], [Some(description.to_string())])?)
+        .og_url(resolver.resolve("og:url", &["shortlink", "og:url"], [Some(canonical_url.to_string())])?)
+        .twitter_card(resolver.resolve("twitter:card", &["twitter:card"], [Some("summary_large_image".to_string())])?)
+        .twitter_title(resolver.resolve("twitter:title", &["twitter:title"], [Some(title.to_string())])?)
+        .twitter_creator(resolver.resolve("twitter:creator", &["twitter:creator", "twitter:site"], [twitter_handle.clone()])?)
+        .twitter_description(
+            resolver.resolve("twitter:description", &["twitter:description"], [Some(description.to_string())])?,
+        )
+        .twitter_image(resolver.resolve("twitter:image", &["twitter:image"], [Some(image.to_string())])?)
+        .twitter_site(resolver.resolve("twitter:site", &["twitter:site"], [twitter_handle])?)
+        .title(title)
+        .description(description)
+        .canonical_url(canonical_url)
+        .og_image(image)
+        .build()
+        .map_err(|e| AppError::Unexpected(e.to_string()))?;
+
+    Ok((metatags, resolver.defaulted))
+}
+
+struct MetatagsResolver<'a> {
+    contents: &'a [(&'a str, &'a str)],
+    defaulted: Vec<&'static str>,
+}
+
+impl<'a> MetatagsResolver<'a> {
+    fn new(contents: &'a [(&'a str, &'a str)]) -> Self {
+        Self {
+            contents,
+            defaulted: vec![],
+        }
+    }
+
+    /// Returns the first metatag among `keys` holding a valid value, otherwise the first valid
+    /// fallback, recording `name` as defaulted.
+    fn resolve<T, const N: usize>(
+        &mut self,
+        name: &'static str,
+        keys: &[&str],
+        fallbacks: [Option<String>; N],
+    ) -> Result<T>
+    where
+        T: TryFrom<String, Error = AppError>,
+    {
+        let content = keys
+            .iter()
+            .filter_map(|key| self.contents.iter().find(|(name, _)| name == key))
+            .find_map(|(_, content)| T::try_from(content.trim().to_string()).ok());
+
+        if let Some(content) = content {
+            return Ok(content);
+        }
+
+        self.defaulted.push(name);
+        fallbacks
+            .into_iter()
+            .flatten()
+            .find_map(|value| T::try_from(value.trim().to_string()).ok())
+            .ok_or(AppError::Unexpected(format!("No valid value for the {name} metatag")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn defaults_fixture() -> MetatagsDefaults {
+        MetatagsDefaults {
+            site_name: "Example".to_string(),
+            site_url: "https://example.com".to_string(),
+            description: "Example description".to_string(),
+            keywords: "example, rust".to_string(),
+            image: "https://example.com/logo.png".to_string(),
+            robots: "index, follow".to_string(),
+            twitter_handle: "@example".to_string(),
+        }
+    }
+
+    fn fallback_fixture() -> MetatagsFallback {
+        MetatagsFallback::default()
+            .title("Understanding Linked Lists")
+            .description("A linked list is a data structure")
+            .image("https://example.com/thumbnail.png")
+            .path("/en/articles/linked-lists")
+    }
+
+    #[test]
+    fn mapper_derives_tags_when_metatags_are_partial() {
+        let contents = [
+            ("title", "Understanding Linked Lists | Tiagocode"),
+            ("description", "A linked list is a fundamental data structure"),
+            ("robots", "noindex, nofollow"),
+            ("canonical", "https://tiagocode.com/en/articles/programming/understanding-linked-lists"),
+            ("og:site_name", "Tiagocode"),
+            ("og:title", "Understanding Linked Lists | Tiagocode"),
+            ("og:image", "https://tiagocode.com/media/21/edit"),
+            ("twitter:card", "summary"),
+        ];
+
+        let (m, defaulted) = metatags_with_defaults_mapper(&contents, &fallback_fixture(), &defaults_fixture()).unwrap();
+
+        assert_eq!(m.og_image().as_str(), "https://tiagocode.com/media/21/edit");
+        assert_eq!(m.twitter_image().as_str(), "https://tiagocode.com/media/21/edit");
+        assert_eq!(m.og_url().as_str(), m.canonical_url().as_str());
+        assert_eq!(m.keywords().as_str(), "example, rust");
+        assert_eq!(m.twitter_site().as_str(), "@example");
+        assert_eq!(m.twitter_description().as_str(), m.description().as_str());
+        assert_eq!(
+            defaulted,
+            vec![
+                "keywords",
+                "og:type",
+                "og:description",
+                "og:url",
+                "twitter:title",
+                "twitter:creator",
+                "twitter:description",
+                "twitter:image",
+                "twitter:site",
+            ]
+        );
+    }
+
+    #[test]
+    fn mapper_derives_tags_when_metatags_are_invalid() {
+        let contents = [
+            ("title", "   "),
+            ("description", ""),
+            ("canonical", "not a url"),
+            ("og:image:secure_url", "javascript:alert(1)"),
+            ("twitter:site", "@tiagocode"),
+        ];
+
+        let (m, defaulted) = metatags_with_defaults_mapper(&contents, &fallback_fixture(), &defaults_fixture()).unwrap();
+
+        assert_eq!(m.title().as_str(), "Understanding Linked Lists");
+        assert_eq!(m.description().as_str(), "A linked list is a data structure");
+        assert_eq!(m.canonical_url().as_str(), "https://example.com/en/articles/linked-lists");
+        assert_eq!(m.og_image().as_str(), "https://example.com/thumbnail.png");
+        assert_eq!(m.twitter_creator().as_str(), "@tiagocode");
+        assert!(defaulted.contains(&"title"));
+        assert!(defaulted.contains(&"canonical"));
+        assert!(!defaulted.contains(&"twitter:site"));
+    }
+
+    #[rstest]
+    #[case(MetatagsFallback::default(), "Example", "https://example.com", "https://example.com/logo.png")]
+    #[case(fallback_fixture(), "Understanding Linked Lists", "https://example.com/en/articles/linked-lists", "https://example.com/thumbnail.png")]
+    fn mapper_defaults_every_tag_when_metatags_are_missing(
+        #[case] fallback: MetatagsFallback,
+        #[case] title: &str,
+        #[case] canonical_url: &str,
+        #[case] image: &str,
+    ) {
+        let (m, defaulted) = metatags_with_defaults_mapper(&[], &fallback, &defaults_fixture()).unwrap();
+
+        assert_eq!(defaulted.len(), 17);
+        assert_eq!(m.title().as_str(), title);
+        assert_eq!(m.og_title().as_str(), title);
+        assert_eq!(m.canonical_url().as_str(), canonical_url);
+        assert_eq!(m.og_image().as_str(), image);
+        assert_eq!(m.og_site_name().as_str(), "Example");
+        assert_eq!(m.twitter_card().as_str(), "summary_large_image");
+    }
+
+    #[test]
+    fn fallback_prunes_description_when_it_is_too_long() {
+        let fallback = MetatagsFallback::default().description(&"lorem ipsum\n ".repeat(40));
+
+        let description = fallback.description.unwrap();
+        assert!(description.chars().count() <= MAX_DESCRIPTION_LENGTH + 1);
+        assert!(description.ends_with('…'));
+        assert!(!description.contains('\n'));
+    }
+}
@@ -1 +1,11 @@
+#[cfg(feature = "ssr")]
+pub mod cache;
+#[cfg(feature = "ssr")]
+pub mod content_source;
+#[cfg(feature = "ssr")]
 pub mod drupal_jsonapi;
+#[cfg(feature = "ssr")]
+pub mod mail_transport;
+#[cfg(feature = "ssr")]
+pub mod markdown_files;
+pub mod metatags;
@@ -0,0 +1,261 @@
+use serde::Serialize;
+use voca_rs::escape::unescape_html;
+use voca_rs::strip::strip_tags;
+
+use crate::application::domain::common::{Timeline, TimelineItem};
+use crate::application::domain::portfolio::{AboutMe, Portfolio, PortfolioSection};
+use crate::application::domain::project::Project;
+use crate::application::value_objects::Date;
+
+pub const JSON_RESUME_SCHEMA: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+/// A résumé document following the jsonresume.org schema (v1.0.0).
+///
+/// Only the parts of the schema the portfolio can fill are modeled, empty values are skipped.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonResume {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    basics: Basics,
+    work: Vec<Work>,
+    education: Vec<Education>,
+    skills: Vec<Skill>,
+    projects: Vec<JsonResumeProject>,
+    meta: Meta,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Basics {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    url: String,
+    // not part of the schema, but `basics` accepts additional properties
+    #[serde(skip_serializing_if = "Option::is_none")]
+    years_of_experience: Option<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Work {
+    name: String,
+    position: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Education {
+    institution: String,
+    area: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Skill {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResumeProject {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<String>,
+    start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Meta {
+    canonical: String,
+    version: &'static str,
+}
+
+/// Maps the `AboutMe`, `Resume` and `Projects` sections of a portfolio into a JSON Resume.
+///
+/// # Arguments
+/// * `portfolio` - The portfolio to export.
+/// * `canonical_url` - The absolute url the document is served from.
+pub fn json_resume_mapper(portfolio: &Portfolio, canonical_url: &str) -> JsonResume {
+    let mut resume = JsonResume {
+        schema: JSON_RESUME_SCHEMA,
+        basics: Basics {
+            name: portfolio.title().to_string(),
+            label: None,
+            image: None,
+            summary: None,
+            url: portfolio.metatags().canonical_url().to_string(),
+            years_of_experience: None,
+        },
+        work: vec![],
+        education: vec![],
+        skills: vec![],
+        projects: vec![],
+        meta: Meta {
+            canonical: canonical_url.to_string(),
+            version: "v1.0.0",
+        },
+    };
+
+    for section in portfolio.sections() {
+        match section {
+            PortfolioSection::AboutMe(about_me) => about_me_mapper(&mut resume, about_me),
+            PortfolioSection::Resume(r) => {
+                resume.work = timeline_items(r.experience()).map(work_mapper).collect();
+                resume.education = timeline_items(r.education()).map(education_mapper).collect();
+            }
+            PortfolioSection::Projects(p) => {
+                resume.projects = p.projects().iter().map(project_mapper).collect();
+            }
+            _ => {}
+        }
+    }
+
+    resume
+}
+
+fn about_me_mapper(resume: &mut JsonResume, about_me: &AboutMe) {
+    resume.basics.label = Some(plain_text(about_me.title()));
+    resume.basics.summary = Some(plain_text(about_me.text()));
+    resume.basics.image = Some(about_me.profile_picture().url().to_string());
+    resume.basics.years_of_experience = Some(*about_me.years_of_experience());
+    resume.skills = about_me
+        .skills()
+        .iter()
+        .map(|s| Skill { name: plain_text(s) })
+        .collect();
+}
+
+fn timeline_items(timeline: &Timeline) -> impl Iterator<Item = &TimelineItem> {
+    timeline.items().iter()
+}
+
+fn work_mapper(item: &TimelineItem) -> Work {
+    Work {
+        name: plain_text(item.subtitle()),
+        position: plain_text(item.title()),
+        start_date: Some(iso_date(item.start_date())),
+        end_date: item.end_date().as_ref().map(iso_date),
+        summary: item.text().as_deref().map(plain_text).filter(|t| !t.is_empty()),
+    }
+}
+
+fn education_mapper(item: &TimelineItem) -> Education {
+    Education {
+        institution: plain_text(item.subtitle()),
+        area: plain_text(item.title()),
+        start_date: Some(iso_date(item.start_date())),
+        end_date: item.end_date().as_ref().map(iso_date),
+    }
+}
+
+fn project_mapper(project: &Project) -> JsonResumeProject {
+    JsonResumeProject {
+        name: plain_text(project.title()),
+        description: Some(plain_text(project.text())).filter(|t| !t.is_empty()),
+        keywords: project.technologies().clone(),
+        roles: project.role().iter().cloned().collect(),
+        start_date: iso_date(project.start_date()),
+        end_date: project.end_date().as_ref().map(iso_date),
+        url: project.link().as_ref().map(|l| l.url().to_string()),
+    }
+}
+
+fn iso_date(date: &Date) -> String {
+    date.to_string_with_format("%Y-%m-%d")
+}
+
+fn plain_text(text: &str) -> String {
+    unescape_html(&strip_tags(text)).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::common::tests::timeline_item_with_dates;
+    use crate::application::domain::portfolio::tests::portfolio_fixture;
+    use serde_json::Value;
+
+    #[test]
+    fn export_is_valid_when_portfolio_is_complete() {
+        let resume = json_resume_mapper(&portfolio_fixture(), "https://example.com/en/resume.json");
+
+        assert_valid(&serde_json::to_value(&resume).unwrap());
+    }
+
+    #[test]
+    fn export_maps_sections_when_portfolio_is_complete() {
+        let portfolio = portfolio_fixture();
+        let resume = serde_json::to_value(json_resume_mapper(&portfolio, "https://example.com/en/resume.json")).unwrap();
+
+        assert_eq!(resume["basics"]["name"], portfolio.title().as_str());
+        assert_eq!(resume["basics"]["label"], "Example title");
+        assert_eq!(resume["basics"]["yearsOfExperience"], 5);
+        assert_eq!(resume["skills"][0]["name"], "Example skill 1");
+        assert_eq!(resume["work"][0]["name"], "Example subtitle");
+        assert_eq!(resume["work"][0]["position"], "Example title");
+        assert_eq!(resume["education"].as_array().unwrap().len(), 2);
+        assert_eq!(resume["projects"][0]["name"], "Example project");
+        assert_eq!(resume["projects"][0]["keywords"][0], "Rust");
+        assert_eq!(resume["projects"][0]["roles"][0], "Lead developer");
+        assert_eq!(resume["meta"]["canonical"], "https://example.com/en/resume.json");
+    }
+
+    #[test]
+    fn export_is_invalid_when_dates_are_not_iso_8601() {
+        let mut resume = serde_json::to_value(json_resume_mapper(&portfolio_fixture(), "https://example.com")).unwrap();
+        resume["work"][0]["startDate"] = Value::String("Aug 2022".to_string());
+
+        assert!(!schema_validator().is_valid(&resume));
+    }
+
+    #[test]
+    fn export_maps_dates_when_items_are_ongoing() {
+        let resume = json_resume_mapper(&portfolio_fixture(), "https://example.com");
+        let work = serde_json::to_value(&resume).unwrap()["work"][0].clone();
+
+        assert_eq!(work["startDate"], "2020-10-01");
+        assert_eq!(work["endDate"], "2022-06-01");
+
+        let ongoing = serde_json::to_value(work_mapper(&timeline_item_with_dates("2022-08-01", None))).unwrap();
+        assert_eq!(ongoing["startDate"], "2022-08-01");
+        assert!(ongoing.get("endDate").is_none());
+    }
+
+    fn schema_validator() -> jsonschema::Validator {
+        let schema = std::fs::read_to_string("tests/fixtures/json_resume_schema.json").unwrap();
+        jsonschema::validator_for(&serde_json::from_str(&schema).unwrap()).unwrap()
+    }
+
+    fn assert_valid(resume: &Value) {
+        let validator = schema_validator();
+        let errors = validator
+            .iter_errors(resume)
+            .map(|e| format!("{} at {}", e, e.instance_path()))
+            .collect::<Vec<_>>();
+
+        assert!(errors.is_empty(), "{errors:#?}");
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Instant;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::web::Data;
+use actix_web::{get, Error, HttpResponse};
+
+use crate::utilities::Metrics;
+
+/// Serves the metrics in the Prometheus text exposition format.
+#[get("/metrics")]
+pub async fn metrics_endpoint(metrics: Data<Metrics>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4; charset=utf-8")
+        .body(metrics.render())
+}
+
+/// Middleware recording the duration of every request, labelled with the route pattern it
+/// matched instead of its path, so slugs don't become labels.
+pub async fn track_requests(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let metrics = request.app_data::<Data<Metrics>>().cloned();
+    let method = request.method().to_string();
+    let started = Instant::now();
+
+    let response = next.call(request).await;
+
+    if let Some(metrics) = metrics {
+        // the route is only known once the request went through the router
+        let (route, status) = match &response {
+            Ok(response) => (response.request().match_pattern(), response.status()),
+            Err(e) => (None, e.as_response_error().status_code()),
+        };
+        let route = route.unwrap_or("unmatched".to_string());
+        metrics.observe_request(&route, &method, status.as_u16(), started.elapsed());
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::middleware::from_fn;
+    use actix_web::test::{call_service, init_service, read_body, TestRequest};
+    use actix_web::App;
+
+    #[get("/{lang}/articles/{slug}")]
+    async fn article() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    #[actix_rt::test]
+    async fn track_requests_records_route_pattern_when_request_matches() {
+        let metrics = Metrics::default();
+        let app = init_service(
+            App::new()
+                .wrap(from_fn(track_requests))
+                .app_data(Data::new(metrics.clone()))
+                .service(article)
+                .service(metrics_endpoint),
+        )
+        .await;
+
+        call_service(&app, TestRequest::get().uri("/en/articles/hello").to_request()).await;
+        call_service(&app, TestRequest::get().uri("/en/articles/world").to_request()).await;
+        call_service(&app, TestRequest::get().uri("/missing").to_request()).await;
+        let response = call_service(&app, TestRequest::get().uri("/metrics").to_request()).await;
+        let rendered = String::from_utf8(read_body(response).await.to_vec()).unwrap();
+
+        assert!(rendered.contains(
+            r#"website_http_request_duration_seconds_count{method="GET",route="/{lang}/articles/{slug}",status="200"} 2"#
+        ));
+        assert!(rendered.contains(
+            r#"website_http_request_duration_seconds_count{method="GET",route="unmatched",status="404"} 1"#
+        ));
+    }
+}
@@ -0,0 +1,20 @@
+mod json_resume_mapper;
+mod metrics_endpoints;
+mod request_tracing;
+mod resume_endpoints;
+mod resume_pdf_cache;
+mod resume_pdf_renderer;
+mod vcard_endpoints;
+mod vcard_mapper;
+
+pub use json_resume_mapper::*;
+pub use metrics_endpoints::*;
+pub use request_tracing::*;
+pub use resume_endpoints::*;
+pub use resume_pdf_cache::*;
+pub use resume_pdf_renderer::*;
+pub use vcard_endpoints::*;
+pub use vcard_mapper::*;
+
+/// Languages served by the endpoints, any other prefix answers with a 404.
+pub(crate) const LANGUAGES: [&str; 2] = ["en", "es"];
@@ -0,0 +1,156 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage, HttpRequest};
+use tracing::{field, info_span, Instrument, Span};
+use uuid::Uuid;
+
+use crate::adapters::driver::actix_endpoints::LANGUAGES;
+use crate::utilities::{HttpClient, REQUEST_ID_HEADER};
+
+/// Id and span of the incoming request, stored in its extensions by `trace_requests`.
+#[derive(Clone, Debug)]
+pub struct RequestTrace {
+    id: String,
+    span: Span,
+}
+
+impl RequestTrace {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+/// Middleware running every request within a span carrying its id, route and language.
+///
+/// The id comes from the `X-Request-Id` header set by the proxy when it's valid, otherwise
+/// a new one is generated. Either way it's sent back in the response headers.
+pub async fn trace_requests(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let lang = request
+        .path()
+        .split('/')
+        .nth(1)
+        .filter(|segment| LANGUAGES.contains(segment));
+
+    let span = info_span!(
+        "request",
+        request_id = %id,
+        method = %request.method(),
+        path = %request.path(),
+        lang,
+        route = field::Empty,
+        status = field::Empty,
+    );
+    request.extensions_mut().insert(RequestTrace {
+        id: id.clone(),
+        span: span.clone(),
+    });
+
+    let mut response = next.call(request).instrument(span.clone()).await;
+
+    match &mut response {
+        Ok(response) => {
+            // the route is only known once the request went through the router
+            let route = response.request().match_pattern().unwrap_or("unmatched".to_string());
+            span.record("route", route);
+            span.record("status", response.status().as_u16());
+
+            if let Ok(value) = HeaderValue::from_str(&id) {
+                response
+                    .headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+        }
+        Err(e) => {
+            span.record("status", e.as_response_error().status_code().as_u16());
+        }
+    }
+
+    response
+}
+
+/// The CMS client for the request, sending its id along so that Drupal logs can be correlated.
+pub fn traced_http_client(http_client: &HttpClient, request: &HttpRequest) -> HttpClient {
+    match request.extensions().get::<RequestTrace>() {
+        Some(trace) => http_client.clone().request_id(trace.id()),
+        None => http_client.clone(),
+    }
+}
+
+/// Ids of other systems are only trusted when they can't inject anything into the logs.
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::middleware::from_fn;
+    use actix_web::test::{call_service, init_service, read_body, TestRequest};
+    use actix_web::{get, App, HttpResponse};
+    use rstest::rstest;
+
+    #[get("/{lang}/articles")]
+    async fn articles(request: HttpRequest) -> HttpResponse {
+        let id = request
+            .extensions()
+            .get::<RequestTrace>()
+            .map(|trace| trace.id().to_string())
+            .unwrap_or_default();
+
+        HttpResponse::Ok().body(id)
+    }
+
+    #[actix_rt::test]
+    async fn trace_requests_keeps_request_id_when_header_is_valid() {
+        let app = init_service(App::new().wrap(from_fn(trace_requests)).service(articles)).await;
+        let request = TestRequest::get()
+            .uri("/en/articles")
+            .insert_header((REQUEST_ID_HEADER, "proxy-1234"))
+            .to_request();
+
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.headers().get(REQUEST_ID_HEADER).unwrap(), "proxy-1234");
+        assert_eq!(read_body(response).await, "proxy-1234");
+    }
+
+    #[actix_rt::test]
+    async fn trace_requests_generates_request_id_when_header_is_missing() {
+        let app = init_service(App::new().wrap(from_fn(trace_requests)).service(articles)).await;
+        let request = TestRequest::get().uri("/en/articles").to_request();
+
+        let response = call_service(&app, request).await;
+        let header = response.headers().get(REQUEST_ID_HEADER).unwrap().to_str().unwrap().to_string();
+
+        assert!(Uuid::parse_str(&header).is_ok());
+        assert_eq!(read_body(response).await, header);
+    }
+
+    #[rstest]
+    #[case("3f1c9a2e-6b1d-4c55-9a43-0d2f6f1f2a10", true)]
+    #[case("proxy_1234", true)]
+    #[case("", false)]
+    #[case("id with spaces", false)]
+    #[case("id\nforged log line", false)]
+    fn request_id_is_validated(#[case] id: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_request_id(id), expected);
+    }
+}
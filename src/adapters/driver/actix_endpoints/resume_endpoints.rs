@@ -0,0 +1,149 @@
+use actix_web::http::header::{self, ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web::{self, Data, Path};
+use actix_web::{get, HttpRequest, HttpResponse};
+use tracing::error;
+
+use crate::adapters::driven::content_source::{ContentRepositories, ContentSource};
+use crate::adapters::driver::actix_endpoints::{json_resume_mapper, resume_fingerprint};
+use crate::adapters::driver::actix_endpoints::{traced_http_client, ResumePdfCache, ResumePdfRenderer, LANGUAGES};
+use crate::application::domain::core::AppError;
+use crate::application::domain::portfolio::{Portfolio, PortfolioSection};
+use crate::application::ports::driver::ForDisplayingPortfolio;
+use crate::application::use_cases::ShowPortfolioDetailUseCase;
+use crate::utilities::HttpClient;
+
+/// Serves the résumé of the portfolio as a PDF rendered from the live content.
+///
+/// The document is cached until the portfolio changes and revalidated through its `ETag`.
+/// When rendering fails the visitor is redirected to the uploaded CV document instead.
+#[get("/{lang}/resume.pdf")]
+pub async fn resume_pdf_endpoint(
+    request: HttpRequest,
+    lang: Path<String>,
+    http_client: Data<HttpClient>,
+    content_source: Data<ContentSource>,
+    cache: Data<ResumePdfCache>,
+) -> HttpResponse {
+    let lang = lang.into_inner();
+    let repositories = content_source.repositories(&traced_http_client(&http_client, &request));
+
+    let portfolio = match fetch_portfolio(&lang, &repositories).await {
+        Ok(portfolio) => portfolio,
+        Err(response) => return response,
+    };
+
+    let fingerprint = resume_fingerprint(&portfolio);
+    let etag = format!("\"{fingerprint:x}-{lang}\"");
+
+    let is_fresh = request
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.split(',').any(|tag| tag.trim() == etag));
+
+    if is_fresh {
+        return HttpResponse::NotModified()
+            .insert_header((header::ETAG, etag))
+            .finish();
+    }
+
+    // rendering takes long enough to stall the other requests of the worker
+    let document = {
+        let (cache, portfolio, lang) = (cache.clone(), portfolio.clone(), lang.clone());
+        web::block(move || {
+            cache.get_or_render(&lang, fingerprint, || {
+                ResumePdfRenderer.render(&portfolio, &lang)
+            })
+        })
+        .await
+    };
+    let document = match document {
+        Ok(document) => document,
+        Err(e) => {
+            error!("Failed to render the résumé: {e}");
+            return HttpResponse::InternalServerError().finish();
+        }
+    };
+
+    match document {
+        Ok(document) => HttpResponse::Ok()
+            .content_type("application/pdf")
+            .insert_header((header::ETAG, etag))
+            .insert_header((header::CACHE_CONTROL, "no-cache"))
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Inline,
+                parameters: vec![DispositionParam::Filename(format!("resume-{lang}.pdf"))],
+            })
+            .body(document.as_ref().clone()),
+        Err(e) => {
+            error!("Failed to render the résumé, redirecting to the CV document: {e}");
+
+            let fallback = portfolio.sections().iter().find_map(|s| match s {
+                PortfolioSection::AboutMe(a) => Some(a.cv_document().url().to_string()),
+                _ => None,
+            });
+
+            match fallback {
+                Some(url) => HttpResponse::Found()
+                    .insert_header((header::LOCATION, url))
+                    .finish(),
+                None => HttpResponse::InternalServerError().finish(),
+            }
+        }
+    }
+}
+
+/// Serves the portfolio as a JSON Resume document (https://jsonresume.org/schema).
+#[get("/{lang}/resume.json")]
+pub async fn resume_json_endpoint(
+    request: HttpRequest,
+    lang: Path<String>,
+    http_client: Data<HttpClient>,
+    content_source: Data<ContentSource>,
+) -> HttpResponse {
+    let lang = lang.into_inner();
+    let repositories = content_source.repositories(&traced_http_client(&http_client, &request));
+
+    let portfolio = match fetch_portfolio(&lang, &repositories).await {
+        Ok(portfolio) => portfolio,
+        Err(response) => return response,
+    };
+
+    let connection = request.connection_info();
+    let canonical_url = format!(
+        "{}://{}{}",
+        connection.scheme(),
+        connection.host(),
+        request.path()
+    );
+
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .json(json_resume_mapper(&portfolio, &canonical_url))
+}
+
+/// Retrieves the portfolio behind the endpoints, answering with the response to send on failure.
+pub(crate) async fn fetch_portfolio(
+    lang: &str,
+    repositories: &ContentRepositories,
+) -> Result<Portfolio, HttpResponse> {
+    if !LANGUAGES.contains(&lang) {
+        return Err(HttpResponse::NotFound().finish());
+    }
+
+    let portfolio_service = ShowPortfolioDetailUseCase::new(
+        repositories.portfolio_data(),
+        repositories.articles_featured(),
+        repositories.projects(),
+    );
+
+    portfolio_service.execute().await.map_err(|e| {
+        error!("{e}");
+        match e {
+            AppError::Unauthorized(_) | AppError::ResourceNotFound(_) => {
+                HttpResponse::NotFound().finish()
+            }
+            _ => HttpResponse::BadGateway().finish(),
+        }
+    })
+}
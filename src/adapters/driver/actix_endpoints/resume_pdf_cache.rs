@@ -0,0 +1,125 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::portfolio::{Portfolio, PortfolioSection};
+
+type Entries = HashMap<String, (u64, Arc<Vec<u8>>)>;
+
+/// In-memory cache of the rendered résumés, one entry per language.
+///
+/// Every entry is stored next to the fingerprint of the portfolio it was rendered from,
+/// so a document is only rendered again once the portfolio content changes in the CMS.
+#[derive(Clone, Debug, Default)]
+pub struct ResumePdfCache {
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl ResumePdfCache {
+    /// Returns the cached document for the language and fingerprint, calling `render` on a miss.
+    pub fn get_or_render<F>(&self, lang: &str, fingerprint: u64, render: F) -> Result<Arc<Vec<u8>>>
+    where
+        F: FnOnce() -> Result<Vec<u8>>,
+    {
+        let cached = self
+            .entries
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))?
+            .get(lang)
+            .filter(|(f, _)| *f == fingerprint)
+            .map(|(_, document)| document.clone());
+
+        if let Some(document) = cached {
+            return Ok(document);
+        }
+
+        // rendered without holding the lock, a concurrent miss just renders the same document twice
+        let document = Arc::new(render()?);
+
+        self.entries
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))?
+            .insert(lang.to_string(), (fingerprint, document.clone()));
+
+        Ok(document)
+    }
+}
+
+/// Computes a fingerprint of the portfolio sections that end up in the résumé.
+pub fn resume_fingerprint(portfolio: &Portfolio) -> u64 {
+    let sections = portfolio
+        .sections()
+        .iter()
+        .filter(|s| {
+            matches!(
+                s,
+                PortfolioSection::AboutMe(_) | PortfolioSection::Resume(_) | PortfolioSection::Projects(_)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut hasher = DefaultHasher::new();
+    portfolio.title().as_str().hash(&mut hasher);
+    serde_json::to_string(&sections)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::portfolio::tests::portfolio_fixture;
+    use std::cell::Cell;
+
+    #[test]
+    fn get_or_render_succeeds_when_document_is_cached() {
+        let cache = ResumePdfCache::default();
+        let renders = Cell::new(0);
+        let render = || {
+            renders.set(renders.get() + 1);
+            Ok(b"%PDF".to_vec())
+        };
+
+        cache.get_or_render("en", 1, render).unwrap();
+        let document = cache.get_or_render("en", 1, render).unwrap();
+
+        assert_eq!(renders.get(), 1);
+        assert_eq!(document.as_slice(), b"%PDF");
+    }
+
+    #[test]
+    fn get_or_render_renders_again_when_fingerprint_changes() {
+        let cache = ResumePdfCache::default();
+        let renders = Cell::new(0);
+        let render = || {
+            renders.set(renders.get() + 1);
+            Ok(vec![])
+        };
+
+        cache.get_or_render("en", 1, render).unwrap();
+        cache.get_or_render("en", 2, render).unwrap();
+        cache.get_or_render("es", 2, render).unwrap();
+
+        assert_eq!(renders.get(), 3);
+    }
+
+    #[test]
+    fn get_or_render_fails_when_render_fails() {
+        let cache = ResumePdfCache::default();
+        let result = cache.get_or_render("en", 1, || Err(AppError::Unexpected("boom".to_string())));
+
+        assert!(result.is_err());
+        assert!(cache.get_or_render("en", 1, || Ok(vec![])).is_ok());
+    }
+
+    #[test]
+    fn fingerprint_is_stable_when_portfolio_is_unchanged() {
+        assert_eq!(
+            resume_fingerprint(&portfolio_fixture()),
+            resume_fingerprint(&portfolio_fixture())
+        );
+    }
+}
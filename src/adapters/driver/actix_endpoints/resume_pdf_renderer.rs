@@ -0,0 +1,385 @@
+use owned_ttf_parser::Face;
+use printpdf::{
+    Color, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Point,
+    Rgb,
+};
+use std::any::type_name;
+use voca_rs::escape::unescape_html;
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driver::formatters::format_date_range;
+use crate::application::domain::common::Timeline;
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::portfolio::{AboutMe, Portfolio, PortfolioSection, Resume};
+use crate::application::domain::project::Project;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 18.0;
+const PT_TO_MM: f32 = 0.352_778;
+const LINE_SPACING: f32 = 1.35;
+
+const TEAL: (f32, f32, f32) = (0.031, 0.498, 0.549);
+const ZEUS: (f32, f32, f32) = (0.141, 0.141, 0.141);
+const GRAY: (f32, f32, f32) = (0.45, 0.45, 0.45);
+
+/// Renders the printable résumé of a portfolio as a PDF document.
+///
+/// Only the `AboutMe`, `Resume` and `Projects` sections are rendered, any other section
+/// is ignored. The DejaVu Sans family is embedded (subset to the used glyphs), so the
+/// document looks the same on every reader.
+#[derive(Default)]
+pub struct ResumePdfRenderer;
+
+impl ResumePdfRenderer {
+    pub fn render(&self, portfolio: &Portfolio, lang: &str) -> Result<Vec<u8>> {
+        let adapter = type_name::<Self>();
+        let labels = Labels::from(lang);
+
+        let (document, page, layer) = PdfDocument::new(
+            format!("{} - {}", portfolio.title().as_str(), labels.resume),
+            Mm(PAGE_WIDTH),
+            Mm(PAGE_HEIGHT),
+            "content",
+        );
+
+        let regular = document
+            .add_external_font_with_subsetting(dejavu::sans::regular(), true)
+            .map_err(|e| AppError::Unexpected(format!("{adapter}: {e}")))?;
+        let bold = document
+            .add_external_font_with_subsetting(dejavu::sans::bold(), true)
+            .map_err(|e| AppError::Unexpected(format!("{adapter}: {e}")))?;
+
+        let fonts = Fonts {
+            regular: (regular, parse_face(dejavu::sans::regular())?),
+            bold: (bold, parse_face(dejavu::sans::bold())?),
+        };
+
+        let layer = document.get_page(page).get_layer(layer);
+        let mut writer = PageWriter::new(&document, layer, fonts);
+
+        writer.text(portfolio.title().as_str(), 22.0, Weight::Bold, ZEUS);
+
+        let sections = portfolio.sections();
+        let about_me = sections.iter().find_map(|s| match s {
+            PortfolioSection::AboutMe(a) => Some(a),
+            _ => None,
+        });
+        let resume = sections.iter().find_map(|s| match s {
+            PortfolioSection::Resume(r) => Some(r),
+            _ => None,
+        });
+        let projects = sections.iter().find_map(|s| match s {
+            PortfolioSection::Projects(p) => Some(p.projects()),
+            _ => None,
+        });
+
+        if let Some(about_me) = about_me {
+            render_about_me(&mut writer, about_me, &labels);
+        }
+
+        if let Some(resume) = resume {
+            render_resume(&mut writer, resume, &labels, lang);
+        }
+
+        if let Some(projects) = projects.filter(|p| !p.is_empty()) {
+            render_projects(&mut writer, projects, labels.projects);
+        }
+
+        document
+            .save_to_bytes()
+            .map_err(|e| AppError::Unexpected(format!("{adapter}: {e}")))
+    }
+}
+
+fn render_about_me(writer: &mut PageWriter, about_me: &AboutMe, labels: &Labels) {
+    writer.text(about_me.title().as_str(), 12.0, Weight::Regular, TEAL);
+    writer.space(4.0);
+    writer.section(labels.profile);
+    writer.text(about_me.text().as_str(), 10.0, Weight::Regular, ZEUS);
+    writer.space(1.5);
+    writer.text(
+        &format!("{}+ {}", about_me.years_of_experience(), labels.years_of_experience),
+        10.0,
+        Weight::Bold,
+        ZEUS,
+    );
+
+    if !about_me.skills().is_empty() {
+        writer.section(labels.skills);
+        writer.text(&about_me.skills().join("  ·  "), 10.0, Weight::Regular, ZEUS);
+    }
+}
+
+fn render_resume(writer: &mut PageWriter, resume: &Resume, labels: &Labels, lang: &str) {
+    render_timeline(writer, resume.experience(), labels.experience, lang);
+    render_timeline(writer, resume.education(), labels.education, lang);
+}
+
+fn render_timeline(writer: &mut PageWriter, timeline: &Timeline, label: &str, lang: &str) {
+    if timeline.items().is_empty() {
+        return;
+    }
+
+    writer.section(label);
+
+    for item in timeline.items() {
+        writer.keep_together(14.0);
+        writer.text(item.title().trim(), 11.0, Weight::Bold, ZEUS);
+        writer.text(
+            &format!("{}  ·  {}", item.subtitle().as_str(), format_date_range(item, lang)),
+            9.0,
+            Weight::Regular,
+            GRAY,
+        );
+        if let Some(text) = item.text().as_ref().filter(|t| !t.trim().is_empty()) {
+            writer.text(text, 10.0, Weight::Regular, ZEUS);
+        }
+        writer.space(2.5);
+    }
+}
+
+fn render_projects(writer: &mut PageWriter, projects: &[Project], label: &str) {
+    writer.section(label);
+
+    for project in projects {
+        writer.keep_together(10.0);
+        writer.text(project.title().as_str(), 11.0, Weight::Bold, ZEUS);
+        let details = project
+            .role()
+            .iter()
+            .chain(project.technologies())
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if !details.is_empty() {
+            writer.text(&details.join("  ·  "), 9.0, Weight::Regular, GRAY);
+        }
+        if let Some(link) = project.link() {
+            writer.text(link.url().as_str(), 9.0, Weight::Regular, GRAY);
+        }
+        if !project.text().trim().is_empty() {
+            writer.text(project.text(), 10.0, Weight::Regular, ZEUS);
+        }
+        writer.space(2.5);
+    }
+}
+
+fn parse_face(data: &'static [u8]) -> Result<Face<'static>> {
+    Face::parse(data, 0).map_err(|e| AppError::Unexpected(e.to_string()))
+}
+
+struct Labels {
+    resume: &'static str,
+    profile: &'static str,
+    skills: &'static str,
+    experience: &'static str,
+    education: &'static str,
+    projects: &'static str,
+    years_of_experience: &'static str,
+}
+
+impl From<&str> for Labels {
+    fn from(lang: &str) -> Self {
+        match lang {
+            "es" => Labels {
+                resume: "Hoja de vida",
+                profile: "Perfil",
+                skills: "Habilidades",
+                experience: "Experiencia",
+                education: "Educación",
+                projects: "Proyectos",
+                years_of_experience: "años de experiencia",
+            },
+            _ => Labels {
+                resume: "Resume",
+                profile: "Profile",
+                skills: "Skills",
+                experience: "Experience",
+                education: "Education",
+                projects: "Projects",
+                years_of_experience: "years of experience",
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Weight {
+    Regular,
+    Bold,
+}
+
+struct Fonts {
+    regular: (IndirectFontRef, Face<'static>),
+    bold: (IndirectFontRef, Face<'static>),
+}
+
+impl Fonts {
+    fn get(&self, weight: Weight) -> &(IndirectFontRef, Face<'static>) {
+        match weight {
+            Weight::Regular => &self.regular,
+            Weight::Bold => &self.bold,
+        }
+    }
+}
+
+/// Keeps track of the vertical position on the current page, adding pages as the content flows.
+struct PageWriter<'a> {
+    document: &'a PdfDocumentReference,
+    layer: PdfLayerReference,
+    fonts: Fonts,
+    y: f32,
+}
+
+impl<'a> PageWriter<'a> {
+    fn new(document: &'a PdfDocumentReference, layer: PdfLayerReference, fonts: Fonts) -> Self {
+        Self {
+            document,
+            layer,
+            fonts,
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn space(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    /// Starts a new page when less than `height` millimeters are left on the current one.
+    fn keep_together(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            let (page, layer) = self.document.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+            self.layer = self.document.get_page(page).get_layer(layer);
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn section(&mut self, title: &str) {
+        self.space(5.0);
+        self.keep_together(20.0);
+        self.text(&title.to_uppercase(), 12.0, Weight::Bold, TEAL);
+
+        let (r, g, b) = TEAL;
+        self.layer.set_outline_color(Color::Rgb(Rgb::new(r, g, b, None)));
+        self.layer.set_outline_thickness(0.6);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), Mm(self.y + 1.0)), false),
+                (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(self.y + 1.0)), false),
+            ],
+            is_closed: false,
+        });
+        self.space(3.0);
+    }
+
+    /// Writes a paragraph, wrapping it to the printable width of the page.
+    fn text(&mut self, text: &str, size: f32, weight: Weight, color: (f32, f32, f32)) {
+        let line_height = size * LINE_SPACING * PT_TO_MM;
+        let (r, g, b) = color;
+
+        for line in self.wrap(&sanitize(text, &self.fonts.get(weight).1), size, weight) {
+            self.keep_together(line_height);
+            self.y -= line_height;
+            self.layer.set_fill_color(Color::Rgb(Rgb::new(r, g, b, None)));
+            self.layer
+                .use_text(line, size, Mm(MARGIN), Mm(self.y), &self.fonts.get(weight).0);
+        }
+    }
+
+    fn wrap(&self, text: &str, size: f32, weight: Weight) -> Vec<String> {
+        let face = &self.fonts.get(weight).1;
+        let max_width = PAGE_WIDTH - 2.0 * MARGIN;
+        let space_width = text_width(face, " ", size);
+        let mut lines = vec![];
+        let mut line = String::new();
+        let mut line_width = 0.0;
+
+        for word in text.split_whitespace() {
+            let word_width = text_width(face, word, size);
+
+            if !line.is_empty() && line_width + space_width + word_width > max_width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0.0;
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+                line_width += space_width;
+            }
+
+            line.push_str(word);
+            line_width += word_width;
+        }
+
+        if !line.is_empty() {
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+/// Measures the width in millimeters of a text written with the given face and size in points.
+fn text_width(face: &Face, text: &str, size: f32) -> f32 {
+    let units = text
+        .chars()
+        .filter_map(|c| face.glyph_index(c))
+        .filter_map(|g| face.glyph_hor_advance(g))
+        .map(f32::from)
+        .sum::<f32>();
+
+    units / f32::from(face.units_per_em()) * size * PT_TO_MM
+}
+
+/// Removes markup, decodes entities and drops the characters the face cannot draw (e.g. emojis).
+fn sanitize(text: &str, face: &Face) -> String {
+    unescape_html(&strip_tags(text))
+        .chars()
+        .filter(|c| c.is_whitespace() || face.glyph_index(*c).is_some())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::portfolio::tests::portfolio_fixture;
+
+    #[test]
+    fn render_succeeds_when_valid_portfolio() {
+        let pdf = ResumePdfRenderer.render(&portfolio_fixture(), "en").unwrap();
+
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sanitize_succeeds_when_text_has_markup_and_emojis() {
+        let face = parse_face(dejavu::sans::regular()).unwrap();
+
+        assert_eq!(sanitize("<p>Hey  there! 👋 VMLY&amp;R</p>", &face), "Hey there! VMLY&R");
+    }
+
+    #[test]
+    fn wrap_succeeds_when_text_exceeds_page_width() {
+        let (document, page, layer) = PdfDocument::new("test", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+        let fonts = Fonts {
+            regular: (
+                document.add_external_font(dejavu::sans::regular()).unwrap(),
+                parse_face(dejavu::sans::regular()).unwrap(),
+            ),
+            bold: (
+                document.add_external_font(dejavu::sans::bold()).unwrap(),
+                parse_face(dejavu::sans::bold()).unwrap(),
+            ),
+        };
+        let writer = PageWriter::new(&document, document.get_page(page).get_layer(layer), fonts);
+        let lines = writer.wrap(&"lorem ipsum ".repeat(40), 10.0, Weight::Regular);
+
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|l| text_width(&writer.fonts.regular.1, l, 10.0) <= PAGE_WIDTH - 2.0 * MARGIN));
+    }
+}
@@ -0,0 +1,58 @@
+use actix_web::http::header::{self, ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web::{Data, Path};
+use actix_web::{get, HttpRequest, HttpResponse};
+use tracing::error;
+
+use crate::adapters::driven::content_source::ContentSource;
+use crate::adapters::driver::actix_endpoints::{fetch_portfolio, traced_http_client, vcard_mapper};
+use crate::application::ports::driver::ForDisplayingLayout;
+use crate::application::use_cases::GetLayoutUseCase;
+use crate::utilities::HttpClient;
+
+/// Serves the contact card of the portfolio owner as a vCard 4.0.
+///
+/// The social networks are optional, when the menu can't be fetched the card is served without them.
+#[get("/{lang}/contact.vcf")]
+pub async fn contact_vcard_endpoint(
+    request: HttpRequest,
+    lang: Path<String>,
+    http_client: Data<HttpClient>,
+    content_source: Data<ContentSource>,
+) -> HttpResponse {
+    let lang = lang.into_inner();
+    let repositories = content_source.repositories(&traced_http_client(&http_client, &request));
+
+    let portfolio = match fetch_portfolio(&lang, &repositories).await {
+        Ok(portfolio) => portfolio,
+        Err(response) => return response,
+    };
+
+    let layout_service = GetLayoutUseCase::new(repositories.menu_data(), repositories.site_branding());
+
+    let layout = layout_service
+        .execute()
+        .await
+        .inspect_err(|e| error!("Serving the contact card without social networks: {e}"))
+        .ok();
+
+    let connection = request.connection_info();
+    let source_url = format!(
+        "{}://{}{}",
+        connection.scheme(),
+        connection.host(),
+        request.path()
+    );
+
+    HttpResponse::Ok()
+        .content_type("text/vcard; charset=utf-8")
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename("contact.vcf".to_string())],
+        })
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .body(vcard_mapper(
+            &portfolio,
+            layout.as_ref().map(|l| l.social_menu()),
+            &source_url,
+        ))
+}
@@ -0,0 +1,165 @@
+use voca_rs::escape::unescape_html;
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driver::structured_data::AUTHOR_NAME;
+use crate::application::domain::layout::MenuTree;
+use crate::application::domain::portfolio::{Portfolio, PortfolioSection};
+
+/// Maximum length of a content line in octets, longer lines are folded (RFC 6350, section 3.2).
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Maps the author, the portfolio `AboutMe` section and the social networks into a vCard 4.0.
+///
+/// # Arguments
+/// * `portfolio` - The portfolio providing the title and photo.
+/// * `social_menu` - The social networks, hidden items are skipped.
+/// * `source_url` - The absolute url the card is served from.
+pub fn vcard_mapper(portfolio: &Portfolio, social_menu: Option<&MenuTree>, source_url: &str) -> String {
+    let (given_name, family_name) = match AUTHOR_NAME.rsplit_once(' ') {
+        Some((given, family)) => (given, family),
+        None => (AUTHOR_NAME, ""),
+    };
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:4.0".to_string(),
+        "KIND:individual".to_string(),
+        format!("FN:{}", escape(AUTHOR_NAME)),
+        format!("N:{};{};;;", escape(family_name), escape(given_name)),
+    ];
+
+    let about_me = portfolio.sections().iter().find_map(|s| match s {
+        PortfolioSection::AboutMe(a) => Some(a),
+        _ => None,
+    });
+
+    if let Some(about_me) = about_me {
+        lines.push(format!("TITLE:{}", escape(&plain_text(about_me.title()))));
+        lines.push(format!("ROLE:{}", escape(&plain_text(about_me.subtitle()))));
+        lines.push(format!("PHOTO:{}", about_me.profile_picture().url().as_str()));
+    }
+
+    lines.push(format!("URL:{}", portfolio.metatags().canonical_url().as_str()));
+
+    let mut social_networks = social_menu
+        .map(|menu| menu.items().iter().filter(|i| !i.hidden()).collect::<Vec<_>>())
+        .unwrap_or_default();
+    social_networks.sort_by_key(|i| *i.weight());
+
+    for network in social_networks {
+        lines.push(format!(
+            "SOCIALPROFILE;SERVICE-TYPE={}:{}",
+            parameter(network.title()),
+            network.url().as_str()
+        ));
+    }
+
+    lines.push(format!("SOURCE:{source_url}"));
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|l| fold(l)).collect::<String>()
+}
+
+/// Escapes the characters with special meaning in property values (RFC 6350, section 3.4).
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+/// Quotes parameter values containing characters not allowed unquoted.
+fn parameter(value: &str) -> String {
+    let value = value.replace('"', "");
+
+    match value.contains([':', ';', ',']) {
+        true => format!("\"{value}\""),
+        false => value,
+    }
+}
+
+/// Splits a content line in chunks of at most 75 octets, without breaking characters,
+/// continuation lines start with a single space.
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+
+        folded.push(c);
+        length += c.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
+
+fn plain_text(text: &str) -> String {
+    unescape_html(&strip_tags(text)).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::layout::tests::menu_item_fixture;
+    use crate::application::domain::layout::{MenuItemBuilder, MenuTreeBuilder};
+    use crate::application::domain::portfolio::tests::portfolio_fixture;
+
+    #[test]
+    fn export_maps_about_me_when_portfolio_is_complete() {
+        let vcard = vcard_mapper(&portfolio_fixture(), None, "https://example.com/en/contact.vcf");
+
+        assert!(vcard.starts_with("BEGIN:VCARD\r\nVERSION:4.0\r\n"));
+        assert!(vcard.ends_with("END:VCARD\r\n"));
+        assert!(vcard.contains(&format!("FN:{AUTHOR_NAME}\r\n")));
+        assert!(vcard.contains("N:Molina;Santiago Marulanda;;;\r\n"));
+        assert!(vcard.contains("TITLE:Example title\r\n"));
+        assert!(vcard.contains("ROLE:Example subtitle\r\n"));
+        assert!(vcard.contains("PHOTO:https://example.com/example.png\r\n"));
+        assert!(vcard.contains("SOURCE:https://example.com/en/contact.vcf\r\n"));
+        assert!(!vcard.contains("SOCIALPROFILE"));
+    }
+
+    #[test]
+    fn export_maps_social_networks_when_menu_is_given() {
+        let hidden = MenuItemBuilder::default()
+            .id("513b8ba1-2bc4-4fda-8455-0c0dea528ca0".try_into().unwrap())
+            .url("https://hidden.example.com".try_into().unwrap())
+            .title("Hidden".into())
+            .hidden(true)
+            .weight(0)
+            .icon(None)
+            .external(true)
+            .expanded(false)
+            .children(vec![])
+            .build()
+            .unwrap();
+        let menu = MenuTreeBuilder::default()
+            .items(vec![menu_item_fixture(), hidden])
+            .build()
+            .unwrap();
+
+        let vcard = vcard_mapper(&portfolio_fixture(), Some(&menu), "https://example.com/en/contact.vcf");
+
+        assert!(vcard.contains("SOCIALPROFILE;SERVICE-TYPE=Example:https://example.com"));
+        assert!(!vcard.contains("Hidden"));
+    }
+
+    #[test]
+    fn escape_succeeds_when_value_has_special_characters() {
+        assert_eq!(escape("Rust, Leptos; Drupal\\"), "Rust\\, Leptos\\; Drupal\\\\");
+    }
+
+    #[test]
+    fn fold_succeeds_when_line_is_too_long() {
+        let folded = fold(&format!("NOTE:{}", "é".repeat(60)));
+
+        assert!(folded.split("\r\n").all(|l| l.len() <= MAX_LINE_LENGTH));
+        assert_eq!(folded.replace("\r\n ", ""), format!("NOTE:{}\r\n", "é".repeat(60)));
+    }
+}
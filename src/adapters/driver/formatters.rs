@@ -0,0 +1,112 @@
+use chrono::Datelike;
+
+use crate::application::domain::common::TimelineItem;
+use crate::application::value_objects::Date;
+
+const MONTHS_EN: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const MONTHS_ES: [&str; 12] = [
+    "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+];
+
+/// Formats the period of a timeline item for the given language, e.g. `Mar 2021 – Present · 3 yrs 7 mos`.
+///
+/// # Arguments
+/// * `item` - The timeline item to format.
+/// * `lang` - The language code, anything but `es` falls back to English.
+pub fn format_date_range(item: &TimelineItem, lang: &str) -> String {
+    format_period(item.start_date(), item.end_date().as_ref(), lang, *item.duration())
+}
+
+/// Formats a period lasting the given months for the given language, periods without end are
+/// shown as ongoing.
+///
+/// Periods starting and ending in the same month are shown as a single month without duration.
+pub fn format_period(start: &Date, end: Option<&Date>, lang: &str, months: u32) -> String {
+    let formatted_start = format_month(start, lang);
+
+    let formatted_end = match end {
+        Some(end) if end.year() == start.year() && end.month() == start.month() => {
+            return formatted_start;
+        }
+        Some(end) => format_month(end, lang),
+        None if lang == "es" => "Actualidad".to_string(),
+        None => "Present".to_string(),
+    };
+
+    format!(
+        "{formatted_start} – {formatted_end} · {}",
+        format_duration(months, lang)
+    )
+}
+
+/// Formats a month and year, e.g. `Mar 2021` or `mar 2021`.
+pub fn format_month(date: &Date, lang: &str) -> String {
+    let months = match lang {
+        "es" => MONTHS_ES,
+        _ => MONTHS_EN,
+    };
+
+    format!("{} {}", months[date.month0() as usize], date.year())
+}
+
+/// Formats an amount of months as years and months, e.g. `3 yrs 7 mos` or `3 años 7 meses`.
+pub fn format_duration(months: u32, lang: &str) -> String {
+    let (years, months) = (months / 12, months % 12);
+    let ((year, years_label), (month, months_label)) = match lang {
+        "es" => (("año", "años"), ("mes", "meses")),
+        _ => (("yr", "yrs"), ("mo", "mos")),
+    };
+
+    let mut parts = vec![];
+
+    if years > 0 {
+        parts.push(format!("{years} {}", if years == 1 { year } else { years_label }));
+    }
+
+    if months > 0 || years == 0 {
+        parts.push(format!("{months} {}", if months == 1 { month } else { months_label }));
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::common::tests::timeline_item_with_dates;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2021-03-01", None, "en", "Mar 2021 – Present · 3 yrs 7 mos")]
+    #[case("2021-03-01", None, "es", "mar 2021 – Actualidad · 3 años 7 meses")]
+    #[case("2020-10-01", Some("2022-06-01"), "en", "Oct 2020 – Jun 2022 · 1 yr 9 mos")]
+    #[case("2024-01-01", Some("2024-12-01"), "es", "ene 2024 – dic 2024 · 1 año")]
+    #[case("2021-08-01", Some("2021-08-01"), "en", "Aug 2021")]
+    fn format_succeeds_when_item_has_dates(
+        #[case] start: &str,
+        #[case] end: Option<&str>,
+        #[case] lang: &str,
+        #[case] expected: &str,
+    ) {
+        let today = Date::try_from("2024-09-19").unwrap();
+        let mut item = timeline_item_with_dates(start, end);
+        item.count_duration(&today);
+
+        assert_eq!(format_date_range(&item, lang), expected);
+    }
+
+    #[rstest]
+    #[case(1, "en", "1 mo")]
+    #[case(12, "en", "1 yr")]
+    #[case(25, "en", "2 yrs 1 mo")]
+    #[case(13, "es", "1 año 1 mes")]
+    fn format_duration_succeeds_when_counting_months(
+        #[case] months: u32,
+        #[case] lang: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(format_duration(months, lang), expected);
+    }
+}
@@ -1,70 +1,80 @@
-use leptos::prelude::ServerFnError;
 use leptos::prelude::*;
 
+use crate::adapters::driver::leptos_webui::controllers::{Cached, WebError};
 use crate::application::domain::article::{Article, Category};
 use crate::application::domain::page::Page;
 
 #[server]
 pub async fn articles_list_controller(
     slug: String,
-) -> Result<(Page, Vec<Category>, Vec<Article>), ServerFnError> {
+) -> Result<Cached<(Page, Vec<Category>, Vec<Article>)>, WebError> {
     use actix_web::web::Data;
-    use leptos::logging::error;
     use leptos_actix::extract;
+    use tracing::Instrument;
 
-    use crate::adapters::driven::drupal_jsonapi::repositories::ArticleRepository;
-    use crate::adapters::driven::drupal_jsonapi::repositories::CategoryRepository;
-    use crate::adapters::driven::drupal_jsonapi::repositories::PageRepository;
-    use crate::application::domain::core::AppError;
+    use crate::adapters::driven::cache::{ContentCache, Freshness};
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driver::leptos_webui::controllers::{request_http_client, request_span, server_fn_error};
     use crate::application::ports::driver::ForDisplayingArticlesList;
     use crate::application::use_cases::ShowArticlesListUseCase;
     use crate::utilities::HttpClient;
 
     let http_client: Data<HttpClient> = extract().await?;
+    let http_client = request_http_client(&http_client);
+    let cache: Data<ContentCache> = extract().await?;
+    let content_source: Data<ContentSource> = extract().await?;
+    let freshness = Freshness::default();
 
-    let page_repository = PageRepository::new(http_client.get_ref().clone());
-    let article_repository = ArticleRepository::new(http_client.get_ref().clone());
-    let category_repository = CategoryRepository::new(http_client.get_ref().clone());
+    let repositories = content_source
+        .repositories(&http_client)
+        .cached(cache.get_ref().clone(), freshness.clone());
 
     let use_case = ShowArticlesListUseCase::new(
-        Box::new(article_repository),
-        Box::new(category_repository),
-        Box::new(page_repository),
+        repositories.articles_list(),
+        repositories.categories_list(),
+        repositories.page_data(),
     );
 
     let category = slug.clone().split("/").nth(3).map(|s| s.to_owned());
     let result = use_case
         .execute("/en/articles", category)
+        .instrument(request_span())
         .await
-        .map_err(|e| {
-            error!("{}", e.to_string());
-            ServerFnError::<AppError>::ServerError(e.to_string())
-        })?;
+        .map_err(server_fn_error)?;
 
-    Ok(result)
+    Ok(Cached::new(result, freshness.is_stale()))
 }
 
 #[server]
-pub async fn article_detail_controller(slug: String) -> Result<Article, ServerFnError> {
+pub async fn article_detail_controller(slug: String) -> Result<Cached<Article>, WebError> {
     use actix_web::web::Data;
-    use leptos::logging::error;
     use leptos_actix::extract;
+    use tracing::Instrument;
 
-    use crate::adapters::driven::drupal_jsonapi::repositories::ArticleRepository;
-    use crate::application::domain::core::AppError;
+    use crate::adapters::driven::cache::{ContentCache, Freshness};
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driver::leptos_webui::controllers::{request_http_client, request_span, server_fn_error};
     use crate::application::ports::driver::ForDisplayingArticle;
     use crate::application::use_cases::ShowArticleDetailUseCase;
     use crate::utilities::HttpClient;
 
     let http_client: Data<HttpClient> = extract().await?;
-    let article_repository = ArticleRepository::new(http_client.get_ref().clone());
+    let http_client = request_http_client(&http_client);
+    let cache: Data<ContentCache> = extract().await?;
+    let content_source: Data<ContentSource> = extract().await?;
+    let freshness = Freshness::default();
 
-    let use_case = ShowArticleDetailUseCase::new(Box::new(article_repository));
+    let repositories = content_source
+        .repositories(&http_client)
+        .cached(cache.get_ref().clone(), freshness.clone());
 
-    let result = use_case.execute(slug.as_str()).await.map_err(|e| {
-        error!("{}", e.to_string());
-        ServerFnError::<AppError>::ServerError(e.to_string())
-    })?;
+    let use_case = ShowArticleDetailUseCase::new(repositories.article_data());
 
-    Ok(result)
+    let result = use_case
+        .execute(slug.as_str())
+        .instrument(request_span())
+        .await
+        .map_err(server_fn_error)?;
+
+    Ok(Cached::new(result, freshness.is_stale()))
 }
@@ -0,0 +1,20 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Data returned by a server function, flagged when any part of it came from a stale cache
+/// entry because the CMS could not provide a fresh copy in time.
+#[derive(Debug, Clone, Getters, Serialize, Deserialize)]
+pub struct Cached<T> {
+    data: T,
+    stale: bool,
+}
+
+impl<T> Cached<T> {
+    pub fn new(data: T, stale: bool) -> Self {
+        Self { data, stale }
+    }
+
+    pub fn into_parts(self) -> (T, bool) {
+        (self.data, self.stale)
+    }
+}
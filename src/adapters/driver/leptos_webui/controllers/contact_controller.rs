@@ -0,0 +1,96 @@
+use leptos::prelude::*;
+
+use crate::adapters::driver::leptos_webui::controllers::WebError;
+
+/// Query parameter appended to the page url once a contact message has been sent.
+pub const CONTACT_SENT_QUERY: &str = "contact";
+
+#[server]
+pub async fn send_contact_message_controller(
+    name: String,
+    email: String,
+    message: String,
+    website: String,
+) -> Result<(), WebError> {
+    use actix_web::http::header::REFERER;
+    use actix_web::web::Data;
+    use actix_web::HttpRequest;
+    use leptos_actix::{extract, redirect};
+    use reqwest::Url;
+    use tracing::{error, warn, Instrument};
+
+    use crate::adapters::driven::mail_transport::MailTransport;
+    use crate::adapters::driver::leptos_webui::controllers::{request_span, set_error_response};
+    use crate::application::domain::contact::ContactMessageBuilder;
+    use crate::application::ports::driver::ForSubmittingContactForm;
+    use crate::application::use_cases::SendContactMessageUseCase;
+    use crate::utilities::RateLimiter;
+
+    let request: HttpRequest = extract().await?;
+    let mail_transport: Data<MailTransport> = extract().await?;
+    let rate_limiter: Data<RateLimiter> = extract().await?;
+
+    // only the path of the referer is kept, so the redirect never leaves the site
+    let success_url = request
+        .headers()
+        .get(REFERER)
+        .and_then(|referer| referer.to_str().ok())
+        .and_then(|referer| Url::parse(referer).ok())
+        .map(|referer| referer.path().to_string())
+        .unwrap_or_else(|| "/".to_string());
+    let success_url = format!("{success_url}?{CONTACT_SENT_QUERY}=sent#contact");
+
+    // every proxy may add its own header line, they are read as a single list
+    let forwarded_for = request
+        .headers()
+        .get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join(",");
+    let client_ip = rate_limiter.client_key(request.peer_addr().map(|addr| addr.ip()), Some(&forwarded_for));
+
+    rate_limiter.check(&client_ip).map_err(|e| {
+        warn!(parent: &request_span(), client_ip, "{e}");
+        // rounded up, so the visitor never comes back a moment too early
+        let retry_after = rate_limiter.retry_after(&client_ip).as_secs_f64().ceil() as u32;
+        let web_error = WebError::TooManyRequests { retry_after };
+        set_error_response(&web_error);
+        web_error
+    })?;
+
+    // the honeypot is hidden from humans, bots filling it get a fake success
+    if !website.trim().is_empty() {
+        redirect(&success_url);
+        return Ok(());
+    }
+
+    let contact_message = ContactMessageBuilder::default()
+        .name(name.try_into().map_err(|_| {
+            WebError::Rejected("Please enter your name.".to_string())
+        })?)
+        .email(email.try_into().map_err(|_| {
+            WebError::Rejected("Please enter a valid email address.".to_string())
+        })?)
+        .message(message.try_into().map_err(|_| {
+            WebError::Rejected("Please enter a message.".to_string())
+        })?)
+        .build()
+        .map_err(|e| WebError::Rejected(format!("Please check the form, the {e}.")))?;
+
+    let contact_service = SendContactMessageUseCase::new(Box::new(mail_transport.get_ref().clone()));
+
+    contact_service
+        .execute(contact_message)
+        .instrument(request_span())
+        .await
+        .map_err(|e| {
+            error!(parent: &request_span(), "{e}");
+            WebError::Rejected(
+                "The message could not be sent, please try again later.".to_string(),
+            )
+        })?;
+
+    redirect(&success_url);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use leptos::server_fn::codec::JsonEncoding;
+use leptos::server_fn::error::{FromServerFnError, ServerFnErrorErr};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::application::domain::core::AppError;
+
+/// Seconds a client is asked to wait before retrying when the CMS is unavailable.
+pub const RETRY_AFTER_SECONDS: u32 = 30;
+
+/// Error returned by the server functions, serialized so the pages can tell failures apart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Error)]
+pub enum WebError {
+    #[error("Not found resource `{0}`")]
+    NotFound(String),
+
+    #[error("Access denied to `{0}`")]
+    Forbidden(String),
+
+    #[error("The content service answered with `{status}`, retry after `{retry_after}` seconds")]
+    Unavailable { status: u16, retry_after: u32 },
+
+    #[error("The resource has moved to `{0}`")]
+    Moved(String),
+
+    #[error("{0}")]
+    Rejected(String),
+
+    #[error("Too many requests, retry after `{retry_after}` seconds")]
+    TooManyRequests { retry_after: u32 },
+
+    #[error("Unexpected error with reference `{0}`")]
+    Internal(String),
+
+    #[error("Server function error `{0}`")]
+    ServerFn(String),
+}
+
+impl WebError {
+    /// HTTP status code of the response that carries the error.
+    pub fn status(&self) -> u16 {
+        match self {
+            WebError::NotFound(_) => 404,
+            WebError::Forbidden(_) => 403,
+            WebError::Unavailable { status, .. } => *status,
+            WebError::Moved(_) => 302,
+            WebError::Rejected(_) => 400,
+            WebError::TooManyRequests { .. } => 429,
+            WebError::Internal(_) | WebError::ServerFn(_) => 500,
+        }
+    }
+
+    /// Seconds to wait before retrying, only set for temporary failures.
+    pub fn retry_after(&self) -> Option<u32> {
+        match self {
+            WebError::Unavailable { retry_after, .. } | WebError::TooManyRequests { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Reference shown to the visitor so the failure can be found in the logs.
+    pub fn correlation_id(&self) -> Option<&str> {
+        match self {
+            WebError::Internal(id) => Some(id.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl From<AppError> for WebError {
+    fn from(e: AppError) -> Self {
+        match e {
+            AppError::ResourceNotFound(resource) => WebError::NotFound(resource),
+            AppError::Unauthorized(resource) => WebError::Forbidden(resource),
+            AppError::Moved(path, _) => WebError::Moved(path),
+            AppError::TooManyRequests(_) => WebError::TooManyRequests {
+                retry_after: RETRY_AFTER_SECONDS,
+            },
+            // the CMS could not be reached at all
+            AppError::External(_, _) => WebError::Unavailable {
+                status: 503,
+                retry_after: RETRY_AFTER_SECONDS,
+            },
+            // the CMS answered, but with an error
+            AppError::ApiFailure(_, _) => WebError::Unavailable {
+                status: 502,
+                retry_after: RETRY_AFTER_SECONDS,
+            },
+            _ => WebError::Internal(Uuid::new_v4().to_string()),
+        }
+    }
+}
+
+impl From<ServerFnErrorErr> for WebError {
+    fn from(e: ServerFnErrorErr) -> Self {
+        WebError::ServerFn(e.to_string())
+    }
+}
+
+impl FromServerFnError for WebError {
+    type Encoder = JsonEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        value.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(AppError::ResourceNotFound("/en/articles/missing".to_string()), 404)]
+    #[case(AppError::Unauthorized("/jsonapi".to_string()), 403)]
+    #[case(AppError::External("HttpClient", "connection refused".to_string()), 503)]
+    #[case(AppError::ApiFailure(500, "{\"errors\":[]}".to_string()), 502)]
+    #[case(AppError::Moved("/en/articles/rust/new".to_string(), 301), 302)]
+    #[case(AppError::TooManyRequests("127.0.0.1".to_string()), 429)]
+    #[case(AppError::Deserialization("missing field".to_string(), "{}".to_string()), 500)]
+    #[case(AppError::Unexpected("boom".to_string()), 500)]
+    fn status_succeeds_when_error_is_mapped(#[case] error: AppError, #[case] expected: u16) {
+        assert_eq!(WebError::from(error).status(), expected);
+    }
+
+    #[test]
+    fn retry_after_succeeds_when_cms_is_unavailable() {
+        let error = WebError::from(AppError::External("HttpClient", "timeout".to_string()));
+
+        assert_eq!(error.retry_after(), Some(RETRY_AFTER_SECONDS));
+        assert_eq!(error.correlation_id(), None);
+    }
+
+    #[test]
+    fn retry_after_succeeds_when_requests_are_too_many() {
+        let error = WebError::from(AppError::TooManyRequests("127.0.0.1".to_string()));
+
+        assert_eq!(error.retry_after(), Some(RETRY_AFTER_SECONDS));
+    }
+
+    #[test]
+    fn correlation_id_succeeds_when_error_is_internal() {
+        let first = WebError::from(AppError::Unexpected("boom".to_string()));
+        let second = WebError::from(AppError::Unexpected("boom".to_string()));
+
+        assert!(first.correlation_id().is_some_and(|id| Uuid::parse_str(id).is_ok()));
+        assert_ne!(first.correlation_id(), second.correlation_id());
+        assert_eq!(first.retry_after(), None);
+    }
+
+    #[test]
+    fn de_succeeds_when_error_was_serialized() {
+        let error = WebError::Unavailable {
+            status: 503,
+            retry_after: RETRY_AFTER_SECONDS,
+        };
+
+        let decoded = WebError::de(error.ser());
+
+        assert_eq!(decoded, error);
+    }
+}
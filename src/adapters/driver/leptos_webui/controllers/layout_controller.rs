@@ -1,28 +1,38 @@
-use leptos::prelude::ServerFnError;
 use leptos::prelude::*;
 
+use crate::adapters::driver::leptos_webui::controllers::WebError;
 use crate::application::domain::layout::Layout;
 
 #[server]
-pub async fn layout_controller() -> Result<Layout, ServerFnError> {
+pub async fn layout_controller() -> Result<Layout, WebError> {
     use actix_web::web::Data;
-    use leptos::logging::error;
     use leptos_actix::extract;
+    use tracing::Instrument;
 
-    use crate::adapters::driven::drupal_jsonapi::repositories::LayoutRepository;
-    use crate::application::domain::core::AppError;
+    use crate::adapters::driven::cache::{ContentCache, Freshness};
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driver::leptos_webui::controllers::{request_http_client, request_span, server_fn_error};
     use crate::application::ports::driver::ForDisplayingLayout;
     use crate::application::use_cases::GetLayoutUseCase;
     use crate::utilities::HttpClient;
 
     let http_client: Data<HttpClient> = extract().await?;
-    let repository = LayoutRepository::new(http_client.get_ref().clone());
-    let layout_service = GetLayoutUseCase::new(Box::new(repository));
+    let http_client = request_http_client(&http_client);
+    let cache: Data<ContentCache> = extract().await?;
+    let content_source: Data<ContentSource> = extract().await?;
 
-    let layout = layout_service.execute().await.map_err(|e| {
-        error!("{}", e.to_string());
-        ServerFnError::<AppError>::ServerError(e.to_string())
-    })?;
+    // the outdated notice is only shown for the page content, not for the menus
+    let freshness = Freshness::default();
+    let repositories = content_source
+        .repositories(&http_client)
+        .cached(cache.get_ref().clone(), freshness);
+    let layout_service = GetLayoutUseCase::new(repositories.menu_data(), repositories.site_branding());
+
+    let layout = layout_service
+        .execute()
+        .instrument(request_span())
+        .await
+        .map_err(server_fn_error)?;
 
     Ok(layout)
 }
@@ -1,7 +1,19 @@
 mod article_controller;
+mod cached;
+mod contact_controller;
+mod errors;
 mod layout_controller;
 mod portfolio_controller;
+mod project_controller;
+#[cfg(feature = "ssr")]
+mod responses;
 
 pub use article_controller::*;
+pub use cached::*;
+pub use contact_controller::*;
+pub use errors::*;
 pub use layout_controller::*;
 pub use portfolio_controller::*;
+pub use project_controller::*;
+#[cfg(feature = "ssr")]
+pub use responses::*;
@@ -1,35 +1,42 @@
-use leptos::prelude::ServerFnError;
 use leptos::prelude::*;
 
+use crate::adapters::driver::leptos_webui::controllers::{Cached, WebError};
 use crate::application::domain::portfolio::Portfolio;
 
 #[server]
-pub async fn portfolio_detail_controller() -> Result<Portfolio, ServerFnError> {
+pub async fn portfolio_detail_controller() -> Result<Cached<Portfolio>, WebError> {
     use actix_web::web::Data;
-    use leptos::logging::error;
     use leptos_actix::extract;
+    use tracing::Instrument;
 
-    use crate::adapters::driven::drupal_jsonapi::repositories::ArticleRepository;
-    use crate::adapters::driven::drupal_jsonapi::repositories::PortfolioRepository;
-    use crate::application::domain::core::AppError;
+    use crate::adapters::driven::cache::{ContentCache, Freshness};
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driver::leptos_webui::controllers::{request_http_client, request_span, server_fn_error};
     use crate::application::ports::driver::ForDisplayingPortfolio;
     use crate::application::use_cases::ShowPortfolioDetailUseCase;
     use crate::utilities::HttpClient;
 
     let http_client: Data<HttpClient> = extract().await?;
+    let http_client = request_http_client(&http_client);
+    let cache: Data<ContentCache> = extract().await?;
+    let content_source: Data<ContentSource> = extract().await?;
+    let freshness = Freshness::default();
 
-    let article_repository = ArticleRepository::new(http_client.get_ref().clone());
-    let portfolio_repository = PortfolioRepository::new(http_client.get_ref().clone());
+    let repositories = content_source
+        .repositories(&http_client)
+        .cached(cache.get_ref().clone(), freshness.clone());
 
     let portfolio_service = ShowPortfolioDetailUseCase::new(
-        Box::new(portfolio_repository),
-        Box::new(article_repository),
+        repositories.portfolio_data(),
+        repositories.articles_featured(),
+        repositories.projects(),
     );
 
-    let portfolio = portfolio_service.execute().await.map_err(|e| {
-        error!("{}", e.to_string());
-        ServerFnError::<AppError>::ServerError(e.to_string())
-    })?;
+    let portfolio = portfolio_service
+        .execute()
+        .instrument(request_span())
+        .await
+        .map_err(server_fn_error)?;
 
-    Ok(portfolio)
+    Ok(Cached::new(portfolio, freshness.is_stale()))
 }
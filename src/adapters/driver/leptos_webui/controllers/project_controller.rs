@@ -0,0 +1,38 @@
+use leptos::prelude::*;
+
+use crate::adapters::driver::leptos_webui::controllers::{Cached, WebError};
+use crate::application::domain::project::Project;
+
+#[server]
+pub async fn project_detail_controller(slug: String) -> Result<Cached<Project>, WebError> {
+    use actix_web::web::Data;
+    use leptos_actix::extract;
+    use tracing::Instrument;
+
+    use crate::adapters::driven::cache::{ContentCache, Freshness};
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driver::leptos_webui::controllers::{request_http_client, request_span, server_fn_error};
+    use crate::application::ports::driver::ForDisplayingProject;
+    use crate::application::use_cases::ShowProjectDetailUseCase;
+    use crate::utilities::HttpClient;
+
+    let http_client: Data<HttpClient> = extract().await?;
+    let http_client = request_http_client(&http_client);
+    let cache: Data<ContentCache> = extract().await?;
+    let content_source: Data<ContentSource> = extract().await?;
+    let freshness = Freshness::default();
+
+    let repositories = content_source
+        .repositories(&http_client)
+        .cached(cache.get_ref().clone(), freshness.clone());
+
+    let use_case = ShowProjectDetailUseCase::new(repositories.projects());
+
+    let result = use_case
+        .execute(slug.as_str())
+        .instrument(request_span())
+        .await
+        .map_err(server_fn_error)?;
+
+    Ok(Cached::new(result, freshness.is_stale()))
+}
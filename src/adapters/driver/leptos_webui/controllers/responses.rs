@@ -0,0 +1,76 @@
+use actix_web::http::header::{HeaderValue, ACCEPT, RETRY_AFTER};
+use actix_web::http::StatusCode;
+use actix_web::HttpMessage;
+use leptos::prelude::*;
+use leptos_actix::{redirect, Request, ResponseOptions};
+use tracing::{error, Span};
+
+use crate::adapters::driver::actix_endpoints::{traced_http_client, RequestTrace};
+use crate::adapters::driver::leptos_webui::controllers::WebError;
+use crate::application::domain::core::AppError;
+use crate::utilities::HttpClient;
+
+/// Converts an application error into the error returned by the server functions.
+///
+/// The response status follows the error, e.g. a `404` for a missing article, and temporary
+/// failures of the CMS add a `Retry-After` header. Unexpected errors are logged together with
+/// the correlation id shown to the visitor.
+///
+/// Moved resources redirect the visitor instead, the `302` set by `redirect` is replaced
+/// with the status Drupal answered, e.g. a permanent `301`, when rendering the page.
+pub fn server_fn_error(e: AppError) -> WebError {
+    if let AppError::Moved(path, status) = &e {
+        redirect(path);
+
+        let accepts_html = use_context::<Request>()
+            .and_then(|req| req.headers().get(ACCEPT).cloned())
+            .is_some_and(|accept| accept.to_str().unwrap_or_default().contains("text/html"));
+        let status = StatusCode::from_u16(*status).ok().filter(|s| s.is_redirection());
+
+        if let (true, Some(status), Some(response)) = (accepts_html, status, use_context::<ResponseOptions>()) {
+            response.set_status(status);
+        }
+
+        return WebError::from(e);
+    }
+
+    let message = e.to_string();
+    let web_error = WebError::from(e);
+
+    match web_error.correlation_id() {
+        Some(id) => error!(parent: &request_span(), correlation_id = id, "{message}"),
+        None => error!(parent: &request_span(), "{message}"),
+    }
+
+    set_error_response(&web_error);
+
+    web_error
+}
+
+/// Sets the status of the response, and its `Retry-After` header for temporary failures.
+pub fn set_error_response(web_error: &WebError) {
+    if let Some(response) = use_context::<ResponseOptions>() {
+        if let Ok(status) = StatusCode::from_u16(web_error.status()) {
+            response.set_status(status);
+        }
+        if let Some(retry_after) = web_error.retry_after() {
+            response.insert_header(RETRY_AFTER, HeaderValue::from(retry_after));
+        }
+    }
+}
+
+/// The CMS client for the request being rendered, sending its id along to Drupal.
+pub fn request_http_client(http_client: &HttpClient) -> HttpClient {
+    match use_context::<Request>() {
+        Some(request) => traced_http_client(http_client, &request),
+        None => http_client.clone(),
+    }
+}
+
+/// The span of the request being rendered, the use cases run within it so that their spans
+/// are its children even when the render runs them in separate tasks.
+pub fn request_span() -> Span {
+    use_context::<Request>()
+        .and_then(|request| request.extensions().get::<RequestTrace>().map(|trace| trace.span().clone()))
+        .unwrap_or_else(Span::current)
+}
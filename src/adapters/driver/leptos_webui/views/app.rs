@@ -5,7 +5,7 @@ use leptos_router::components::{Redirect, Route, Router, Routes};
 use leptos_router::{path, SsrMode, WildcardSegment};
 
 use crate::adapters::driver::leptos_webui::views::pages::{
-    BlogListPage, BlogDetailPage, NotFoundPage, PortfolioPage,
+    BlogListPage, BlogDetailPage, NotFoundPage, PortfolioPage, ProjectDetailPage,
 };
 
 #[component]
@@ -26,6 +26,7 @@ pub fn App() -> impl IntoView {
                 <Route ssr=SsrMode::Async path=path!("/:lang/articles") view=BlogListPage/>
                 <Route ssr=SsrMode::Async path=path!("/:lang/articles/:category") view=BlogListPage/>
                 <Route ssr=SsrMode::Async path=path!("/:lang/articles/:category/:slug") view=BlogDetailPage/>
+                <Route ssr=SsrMode::Async path=path!("/:lang/projects/:slug") view=ProjectDetailPage/>
                 <Route ssr=SsrMode::Async path=WildcardSegment("any") view=NotFoundPage/>
             </Routes>
         </Router>
@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+
+use crate::adapters::driver::leptos_webui::views::components::common::StructuredData;
+use crate::adapters::driver::structured_data::breadcrumb_list_mapper;
+use crate::application::domain::breadcrumb::{BreadcrumbLabels, BreadcrumbTrail};
+
+/// Returns the titles of the pages that aren't content in the given language, English by default.
+pub fn breadcrumb_labels(lang: &str) -> BreadcrumbLabels<'static> {
+    match lang {
+        "es" => BreadcrumbLabels {
+            home: "Inicio",
+            blog: "Blog",
+            projects: "Proyectos",
+        },
+        _ => BreadcrumbLabels {
+            home: "Home",
+            blog: "Blog",
+            projects: "Projects",
+        },
+    }
+}
+
+/// Renders the breadcrumb navigation and its `BreadcrumbList` structured data.
+///
+/// # Arguments
+/// * `trail` - The breadcrumbs, the last one is the current page.
+/// * `base_url` - Any absolute url of the site, its origin prefixes the relative breadcrumb urls.
+#[component]
+pub fn Breadcrumbs(trail: BreadcrumbTrail, base_url: String) -> impl IntoView {
+    let json_ld = breadcrumb_list_mapper(&trail, &base_url).to_script();
+    let items = trail.items().clone();
+
+    view! {
+        <nav aria-label="Breadcrumb" class="mb-6">
+            <ol class="flex flex-wrap items-center font-mono text-sm text-gray-500">
+                {items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, breadcrumb)| {
+                        let is_current = trail.is_current(index);
+
+                        view! {
+                            <li class="inline-flex items-center">
+                                <Show when=move || { index > 0 }>
+                                    <span class="mx-2" aria-hidden="true">"/"</span>
+                                </Show>
+                                <Show
+                                    when=move || is_current
+                                    fallback={
+                                        let breadcrumb = breadcrumb.clone();
+                                        move || view! {
+                                            <a href=breadcrumb.url().to_string() class="hover:text-asparagus">
+                                                {breadcrumb.title().to_string()}
+                                            </a>
+                                        }
+                                    }
+                                >
+                                    <span aria-current="page" class="text-zeus">{breadcrumb.title().to_string()}</span>
+                                </Show>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ol>
+        </nav>
+        <StructuredData script=json_ld />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("en", "Home")]
+    #[case("es", "Inicio")]
+    #[case("fr", "Home")]
+    fn breadcrumb_labels_translates_home_when_lang_is_given(#[case] lang: &str, #[case] expected: &str) {
+        let trail = BreadcrumbTrail::for_blog(lang, &breadcrumb_labels(lang));
+
+        assert_eq!(trail.items()[0].title(), expected);
+    }
+}
@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+
+use crate::adapters::driver::leptos_webui::views::components::common::{Logo, Menu};
+use crate::application::domain::layout::Layout;
+
+#[component]
+pub fn Footer(layout: Layout) -> impl IntoView {
+    let site_name = layout.site_name().as_ref().map(|n| n.to_string());
+    let copyright = format!(
+        "© {} {}",
+        chrono::Utc::now().format("%Y"),
+        site_name.clone().unwrap_or("Tiagocode".to_string())
+    );
+    let footer_menu = layout.footer_menu().clone();
+
+    view! {
+        <footer class="bg-white border-t border-gray-200">
+            <div class="max-w-[1320px] mx-auto px-5 xl:px-0 py-10 grid grid-cols-1 md:grid-cols-3 gap-8">
+                <div class="space-y-3">
+                    <Logo logo=layout.logo().clone() site_name=site_name />
+                    {layout.slogan().clone().map(|slogan| view! {
+                        <p class="text-sm text-gray-500">{slogan}</p>
+                    })}
+                </div>
+                <div>
+                    {footer_menu.map(|menu| view! {
+                        <Menu
+                            items=menu.items().clone()
+                            item_class="block py-1 text-sm text-gray-600"
+                            container_class="flex flex-col"
+                        />
+                    })}
+                </div>
+                <div class="md:justify-self-end">
+                    <Menu
+                        items=layout.social_menu().items().clone()
+                        item_class="block py-0 px-3 -mr-3"
+                        container_class="flex items-center font-medium"
+                    />
+                </div>
+            </div>
+            <div class="border-t border-gray-200 py-4 text-center text-sm text-gray-500">
+                <p>{copyright}</p>
+            </div>
+        </footer>
+    }
+}
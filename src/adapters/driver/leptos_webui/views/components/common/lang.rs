@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+/// Returns the language of the current page, taken from the first segment of the path.
+pub fn use_lang() -> String {
+    use_location()
+        .pathname
+        .get_untracked()
+        .split('/')
+        .nth(1)
+        .filter(|l| !l.is_empty())
+        .unwrap_or("en")
+        .to_string()
+}
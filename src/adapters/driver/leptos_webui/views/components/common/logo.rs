@@ -1,12 +1,23 @@
 use leptos::prelude::*;
 
+use crate::application::domain::common::Image;
+
+const DEFAULT_LOGO: &str = "/assets/images/logo_teal.svg";
+const DEFAULT_SITE_NAME: &str = "Tiagocode";
+
 #[component]
-pub fn Logo() -> impl IntoView {
+pub fn Logo(logo: Option<Image>, site_name: Option<String>) -> impl IntoView {
+    let site_name = site_name.unwrap_or(DEFAULT_SITE_NAME.to_string());
+    let (src, alt) = match logo {
+        Some(logo) => (logo.url().to_string(), logo.alt().to_string()),
+        None => (DEFAULT_LOGO.to_string(), format!("{site_name} Logo")),
+    };
+
     view! {
         <a href="/" target="_self" class="flex items-center space-x-3">
-            <img src="/assets/images/logo_teal.svg" class="h-8" alt="Tiagocode Logo" />
+            <img src=src class="h-8" alt=alt />
             <span class="hidden md:block self-center text-2xl font-semibold whitespace-nowrap uppercase tracking-widest text-teal   ">
-                Tiagocode
+                {site_name}
             </span>
         </a>
     }
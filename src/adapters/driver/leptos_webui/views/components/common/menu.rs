@@ -1,4 +1,5 @@
 use leptos::prelude::*;
+use leptos_router::hooks::use_location;
 
 use crate::application::domain::common::Image;
 use crate::application::domain::layout::MenuItem;
@@ -14,15 +15,19 @@ pub fn Menu(
         <ul class=container_class>
             {items
                 .into_iter()
+                .filter(|item| !item.hidden())
                 .map(|item| {
                     view! {
                         <MenuItem
+                            id=item.id().to_string()
                             url=item.url().clone().to_string()
                             icon=item.icon().clone()
                             title=item.title().to_string()
                             class=item_class.to_string()
                             anchor_class=anchor_class.to_string()
-                            is_external=item.url().is_absolute()
+                            is_external=*item.external()
+                            is_expanded=*item.expanded()
+                            submenu=item.children().clone()
                         />
                     }
                 })
@@ -33,29 +38,81 @@ pub fn Menu(
 
 #[component]
 pub fn MenuItem(
+    id: String,
     url: String,
     title: String,
     class: String,
     anchor_class: String,
     icon: Option<Image>,
     #[prop(default = false)] is_external: bool,
+    #[prop(default = false)] is_expanded: bool,
+    #[prop(default = vec![])] submenu: Vec<MenuItem>,
 ) -> impl IntoView {
     let target = if is_external { "_blank" } else { "_self" };
+    let rel = is_external.then_some("noopener");
+    let has_submenu = submenu.iter().any(|i| !i.hidden());
+
+    let location = use_location();
+    let is_current = {
+        let url = url.clone();
+        Memo::new(move |_| location.pathname.with(|path| path.trim_end_matches('/') == url.trim_end_matches('/')))
+    };
+    let aria_current = move || is_current.get().then_some("page");
+
+    let link = match icon.clone() {
+        Some(icon) => view! {
+            <a target=target rel=rel href=url.to_string() title=title.to_string() aria-current=aria_current class=anchor_class.clone()>
+                <img src=icon.url().to_string() alt=icon.alt().to_string() class="h-8" />
+            </a>
+        }.into_any(),
+        None => view! {
+            <a target=target rel=rel href=url.to_string() title=title.to_string() aria-current=aria_current class=format!("{} hover:text-asparagus aria-[current=page]:text-asparagus w-full", anchor_class.clone())>
+                <span class="h-8">{title.to_string()}</span>
+            </a>
+        }.into_any()
+    };
+
+    if !has_submenu {
+        return view! { <li class=class.clone()>{link}</li> }.into_any();
+    }
+
+    let (is_open, set_is_open) = signal(is_expanded);
+    let submenu_id = format!("submenu-{id}");
 
     view! {
-        <li class=class.clone()>
-            {match icon.clone() {
-                Some(icon) => view! {
-                    <a target=target href=url.to_string() title=title.to_string() class=anchor_class.clone()>
-                        <img src=icon.url().to_string() alt=icon.alt().to_string() class="h-8" />
-                    </a>
-                }.into_any(),
-                None => view! {
-                    <a target=target href=url.to_string() title=title.to_string() class=format!("{} hover:text-asparagus w-full", anchor_class.clone())>
-                        <span class="h-8">{title.to_string()}</span>
-                    </a>
-                }.into_any()
-            }}
+        <li
+            class=format!("{class} relative group")
+            on:keydown=move |e| if e.key() == "Escape" { set_is_open.set(false) }
+        >
+            <div class="flex items-center">
+                {link}
+                <button
+                    type="button"
+                    class="ml-1 p-1 rounded-sm hover:text-asparagus focus:outline-none focus:ring-2 focus:ring-gray-200"
+                    aria-haspopup="true"
+                    aria-controls=submenu_id.clone()
+                    aria-expanded=move || is_open.get().to_string()
+                    on:click=move |_| set_is_open.update(|v| *v = !*v)
+                >
+                    <span class="sr-only">{format!("Toggle the {title} submenu")}</span>
+                    <svg class="w-3 h-3" aria-hidden="true" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 10 6">
+                        <path stroke="currentColor" stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="m1 1 4 4 4-4" />
+                    </svg>
+                </button>
+            </div>
+            <div
+                id=submenu_id
+                class="md:absolute md:left-0 md:top-full md:z-30 md:min-w-48 md:py-2 md:bg-white md:rounded-lg md:shadow-smoke-shadow pl-4 md:pl-0 group-hover:block group-focus-within:block"
+                class:hidden=move || !is_open.get()
+            >
+                <Menu
+                    items=submenu
+                    item_class="block py-2 px-3 normal-case"
+                    container_class="flex flex-col"
+                    anchor_class="block"
+                />
+            </div>
         </li>
     }
+    .into_any()
 }
@@ -1,6 +1,7 @@
 use leptos::prelude::*;
 use leptos_meta::{Link, Meta, Title};
 
+use crate::adapters::driver::structured_data::AUTHOR_NAME;
 use crate::application::domain::common::MetaTags;
 
 #[component]
@@ -11,7 +12,7 @@ pub fn MetaTags(metatags: MetaTags) -> impl IntoView {
         <Meta name="description" content=metatags.description().to_string() />
         <Meta name="keywords" content=metatags.keywords().to_string() />
         <Meta name="robots" content=metatags.robots().to_string() />
-        <Meta name="author" content="Santiago Marulanda Molina" />
+        <Meta name="author" content=AUTHOR_NAME />
         <Meta name="copyright" content="Copyright owner" />
 
         <Meta name="og:site_name" content=metatags.og_site_name().to_string() />
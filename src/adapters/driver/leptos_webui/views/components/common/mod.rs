@@ -1,25 +1,35 @@
 mod background_animation;
+mod breadcrumbs;
 mod container;
 mod errors;
+mod footer;
 mod image;
+mod lang;
 mod logo;
 mod menu;
 mod metatags;
 mod missing;
 mod navbar;
+mod outdated;
 mod pill;
 mod raw_html;
 mod slider;
+mod structured_data;
 
 pub use background_animation::*;
+pub use breadcrumbs::*;
 pub use container::*;
 pub use errors::*;
+pub use footer::*;
 pub use image::*;
+pub use lang::*;
 pub use logo::*;
 pub use menu::*;
 pub use metatags::*;
 pub use missing::*;
 pub use navbar::*;
+pub use outdated::*;
 pub use pill::*;
 pub use raw_html::*;
 pub use slider::*;
+pub use structured_data::*;
@@ -2,16 +2,22 @@ use leptos::prelude::*;
 
 use crate::adapters::driver::leptos_webui::views::components::common::Logo;
 use crate::adapters::driver::leptos_webui::views::components::common::Menu;
+use crate::application::domain::common::Image;
 use crate::application::domain::layout::MenuTree;
 
 #[component]
-pub fn Navbar(main_menu: MenuTree, social_menu: MenuTree) -> impl IntoView {
+pub fn Navbar(
+    main_menu: MenuTree,
+    social_menu: MenuTree,
+    logo: Option<Image>,
+    site_name: Option<String>,
+) -> impl IntoView {
     let (open_mobile_menu, set_open_mobile_menu) = signal(false);
 
     view! {
         <nav>
             <div class="flex flex-wrap items-center justify-between max-w-[1320px] mx-auto py-4 px-5 xl:px-0">
-                <Logo />
+                <Logo logo=logo site_name=site_name />
                 <div class="flex items-center">
                     <div class="md:order-2">
                         <Menu
@@ -0,0 +1,13 @@
+use leptos::prelude::*;
+
+/// Subtle notice shown when the page was served from a stale copy of the CMS content.
+#[component]
+pub fn OutdatedNotice(stale: bool) -> impl IntoView {
+    stale.then(|| {
+        view! {
+            <p role="status" class="font-mono text-xs text-center text-gray-500 mb-4">
+                "Content may be outdated, it will be refreshed shortly."
+            </p>
+        }
+    })
+}
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+use leptos_meta::Script;
+
+/// Emits a JSON-LD document in the `<head>`.
+///
+/// # Arguments
+/// * `script` - The serialized document, see `JsonLd::to_script`.
+#[component]
+pub fn StructuredData(script: String) -> impl IntoView {
+    view! { <Script type_="application/ld+json">{script}</Script> }
+}
@@ -1,3 +1,4 @@
 pub mod blog;
 pub mod common;
 pub mod portfolio;
+pub mod project;
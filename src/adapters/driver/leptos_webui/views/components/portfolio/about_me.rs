@@ -13,6 +13,8 @@ pub fn AboutMeSection(
     years_of_experience: u8,
     cv_document: Document,
 ) -> impl IntoView {
+    let lang = use_lang();
+
     view! {
         <Container id="about".into()>
             <div class="lg:flex space-y-5 lg:space-x-10">
@@ -39,8 +41,15 @@ pub fn AboutMeSection(
                     <div class="space-y-3 mb-2">
                         {skills.into_iter().map(|skill| view! { <Pill text=skill.to_string() /> }).collect_view()}
                     </div>
-                    <div class="space-y-3 mb-2 flex justify-end">
-                        <a href=cv_document.url().to_string() class="btn btn-primary" target="_blank">
+                    <div class="space-y-3 mb-2 flex justify-end items-center space-x-4">
+                        <a href=format!("/{lang}/contact.vcf") class="font-mono text-sm underline" rel="external" download>
+                            "Save contact"
+                            <i class="bi bi-person-vcard ps-1"></i>
+                        </a>
+                        <a href=cv_document.url().to_string() class="font-mono text-sm underline" target="_blank">
+                            "Uploaded version"
+                        </a>
+                        <a href=format!("/{lang}/resume.pdf") class="btn btn-primary" target="_blank" rel="external">
                             <span class="btn-text">"Download cv"</span>
                             <i class="bi bi-cloud-download ps-1"></i>
                         </a>
@@ -0,0 +1,82 @@
+use leptos::either::Either;
+use leptos::prelude::*;
+use leptos_router::hooks::use_query_map;
+
+use crate::adapters::driver::leptos_webui::controllers::{
+    SendContactMessageController, WebError, CONTACT_SENT_QUERY,
+};
+use crate::adapters::driver::leptos_webui::views::components::common::*;
+
+const INPUT_CLASS: &str = "w-full border border-black/20 rounded-lg px-4 py-3 mt-1 focus:outline-none focus:border-teal";
+
+#[component]
+pub fn ContactSection(title: String, subtitle: String, text: String) -> impl IntoView {
+    let send_message = ServerAction::<SendContactMessageController>::new();
+    let query = use_query_map();
+
+    // the query parameter covers plain form posts, the action value covers hydrated submissions
+    let is_sent = move || {
+        query.read().get(CONTACT_SENT_QUERY).is_some_and(|v| v == "sent")
+            || matches!(send_message.value().get(), Some(Ok(_)))
+    };
+
+    let error_message = move || match send_message.value().get() {
+        Some(Err(WebError::Rejected(message))) => Some(message),
+        Some(Err(WebError::TooManyRequests { .. })) => {
+            Some("Too many messages were sent, please try again later.".to_string())
+        }
+        Some(Err(_)) => Some("The message could not be sent, please try again later.".to_string()),
+        _ => None,
+    };
+
+    view! {
+        <Container id="contact">
+            <div class="">
+                <Decoration text=subtitle />
+                <SecondaryTitle text=title />
+                <Description text=text />
+            </div>
+            <div class="mt-6 lg:mt-12">
+                {move || match is_sent() {
+                    true => Either::Left(view! {
+                        <p role="status" class="font-mono font-medium uppercase text-sm text-center tracking-wider relative pt-4 mb-5 text-asparagus">
+                            "Thanks for your message, I will get back to you soon!"
+                        </p>
+                    }),
+                    false => Either::Right(view! {
+                        <ActionForm action=send_message attr:class="space-y-4">
+                            <Show when=move || error_message().is_some()>
+                                <p role="alert" class="text-red-700">{error_message}</p>
+                            </Show>
+                            <label class="block">
+                                <span class="font-mono text-sm uppercase tracking-[0.5px]">"Name"</span>
+                                <input type="text" name="name" required maxlength="100" autocomplete="name" class=INPUT_CLASS />
+                            </label>
+                            <label class="block">
+                                <span class="font-mono text-sm uppercase tracking-[0.5px]">"Email"</span>
+                                <input type="email" name="email" required maxlength="254" autocomplete="email" class=INPUT_CLASS />
+                            </label>
+                            <label class="block">
+                                <span class="font-mono text-sm uppercase tracking-[0.5px]">"Message"</span>
+                                <textarea name="message" required minlength="10" maxlength="5000" rows="6" class=INPUT_CLASS></textarea>
+                            </label>
+                            // honeypot, hidden from humans and assistive technologies
+                            <div class="hidden" aria-hidden="true">
+                                <label>
+                                    "Website"
+                                    <input type="text" name="website" tabindex="-1" autocomplete="off" />
+                                </label>
+                            </div>
+                            <div class="flex justify-end">
+                                <button type="submit" class="btn btn-primary" disabled=move || send_message.pending().get()>
+                                    <span class="btn-text">"Send message"</span>
+                                    <i class="bi bi-send ps-1"></i>
+                                </button>
+                            </div>
+                        </ActionForm>
+                    }),
+                }}
+            </div>
+        </Container>
+    }
+}
@@ -1,4 +1,4 @@
-use leptos::either::EitherOf5;
+use leptos::either::EitherOf6;
 use leptos::prelude::*;
 
 use crate::adapters::driver::leptos_webui::views::components::common::MissingSection;
@@ -13,7 +13,7 @@ pub fn DynamicSections(sections: Vec<PortfolioSection>) -> impl IntoView {
             .map(|content| {
                 match content {
                     PortfolioSection::AboutMe(section) => {
-                        EitherOf5::A(
+                        EitherOf6::A(
                             view! {
                                 <AboutMeSection
                                     subtitle=section.subtitle().to_string()
@@ -28,7 +28,7 @@ pub fn DynamicSections(sections: Vec<PortfolioSection>) -> impl IntoView {
                         )
                     }
                     PortfolioSection::Resume(section) => {
-                        EitherOf5::B(
+                        EitherOf6::B(
                             view! {
                                 <ResumeSection
                                     title=section.title().to_string()
@@ -41,19 +41,20 @@ pub fn DynamicSections(sections: Vec<PortfolioSection>) -> impl IntoView {
                         )
                     }
                     PortfolioSection::Projects(section) => {
-                        EitherOf5::C(
+                        EitherOf6::C(
                             view! {
                                 <ProjectsSection
                                     title=section.title().to_string()
                                     subtitle=section.subtitle().to_string()
                                     text=section.text().to_string()
+                                    technologies=section.technologies()
                                     projects=section.projects().clone()
                                 />
                             },
                         )
                     },
                     PortfolioSection::Blogs(section) => {
-                        EitherOf5::D(
+                        EitherOf6::D(
                             view! {
                                 <BlogSection
                                     title=section.title().to_string()
@@ -64,7 +65,18 @@ pub fn DynamicSections(sections: Vec<PortfolioSection>) -> impl IntoView {
                             },
                         )
                     },
-                    _ => EitherOf5::E(view! { <MissingSection /> }),
+                    PortfolioSection::Contact(section) => {
+                        EitherOf6::E(
+                            view! {
+                                <ContactSection
+                                    title=section.title().to_string()
+                                    subtitle=section.subtitle().to_string()
+                                    text=section.text().to_string()
+                                />
+                            },
+                        )
+                    },
+                    _ => EitherOf6::F(view! { <MissingSection /> }),
                 }
             }).collect_view()
         }
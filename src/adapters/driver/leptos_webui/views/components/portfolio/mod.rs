@@ -1,5 +1,6 @@
 mod about_me;
 mod blogs;
+mod contact;
 mod dynamic;
 mod projects;
 mod resume;
@@ -8,6 +9,7 @@ mod timeline;
 
 pub use about_me::*;
 pub use blogs::*;
+pub use contact::*;
 pub use dynamic::*;
 pub use projects::*;
 pub use resume::*;
@@ -1,15 +1,20 @@
 use leptos::prelude::*;
 
 use crate::adapters::driver::leptos_webui::views::components::common::*;
-use crate::application::domain::common::{Image, Link, Project};
+use crate::application::domain::common::Image;
+use crate::application::domain::project::Project;
 
 #[component]
 pub fn ProjectsSection(
     title: String,
     subtitle: String,
     text: String,
+    technologies: Vec<String>,
     projects: Vec<Project>,
 ) -> impl IntoView {
+    let selected = RwSignal::new(None::<String>);
+    let without_technologies = technologies.is_empty();
+
     view! {
         <Container id="portfolio".into()>
             <div class="">
@@ -17,16 +22,31 @@ pub fn ProjectsSection(
                 <SecondaryTitle text=title />
                 <Description text=text />
             </div>
+            <div class="pt-6" class:hidden=without_technologies>
+                <TechnologyFilter text="All".into() value=None selected=selected />
+                {technologies
+                    .into_iter()
+                    .map(|t| view! { <TechnologyFilter text=t.clone() value=Some(t) selected=selected /> })
+                    .collect_view()
+                }
+            </div>
             <div class="mt-6 lg:mt-12">
                 <div class="portfolio-grid grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-6 mt-6">
                     {projects
                     .into_iter()
                     .map(|p| {
+                        let project = p.clone();
+                        let is_hidden = move || {
+                            selected.get().is_some_and(|technology| !project.uses(&technology))
+                        };
+
                         view! {
                             <FeaturedProjectCard
                                 title=p.title().to_string()
-                                link=p.link().clone()
+                                slug=p.slug().to_string()
                                 image=p.image().clone()
+                                technologies=p.technologies().clone()
+                                is_hidden=Signal::derive(is_hidden)
                             />
                         }
                     }).collect_view()}
@@ -37,15 +57,45 @@ pub fn ProjectsSection(
 }
 
 #[component]
-pub fn FeaturedProjectCard(title: String, link: Link, image: Image) -> impl IntoView {
+fn TechnologyFilter(
+    text: String,
+    value: Option<String>,
+    selected: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let is_active = {
+        let value = value.clone();
+        move || selected.get() == value
+    };
+
+    view! {
+        <button
+            type="button"
+            class="inline-block px-4 py-2 me-2 mb-2 rounded-full border border-black/20 border-dashed text-zeus hover:bg-sheengold/70 transition ease-linear duration-100"
+            class:bg-sheengold=is_active
+            on:click=move |_| selected.set(value.clone())
+        >
+            <span class="inline-block font-mono text-sm">{text}</span>
+        </button>
+    }
+}
+
+#[component]
+pub fn FeaturedProjectCard(
+    title: String,
+    slug: String,
+    image: Image,
+    technologies: Vec<String>,
+    #[prop(into)] is_hidden: Signal<bool>,
+) -> impl IntoView {
     view! {
-        <div class="portfolio-item category-1">
-            <div class="relative overflow-hidden group rounded-lg after:content-[''] after:absolute after:top-0 after:left-0 after:w-full after:h-full after:bg-gradient-to-t after:from-black/30 after:to-transparent after:opacity-0 after:transition after:ease-out after:duration-[160ms] hover:after:opacity-100 category-1">
+        <div class="portfolio-item" class:hidden=is_hidden>
+            <div class="relative overflow-hidden group rounded-lg after:content-[''] after:absolute after:top-0 after:left-0 after:w-full after:h-full after:bg-gradient-to-t after:from-black/30 after:to-transparent after:opacity-0 after:transition after:ease-out after:duration-[160ms] hover:after:opacity-100">
                <Img image=image class="transition ease-custom duration-500 group-hover:scale-105 group-hover:blur-[1.4px]" />
                 <div class="z-[1] text-center absolute bottom-0 left-0 w-full px-2 pb-6 invisible opacity-0 translate-y-2 group-hover:translate-y-0 group-hover:visible group-hover:opacity-100 group-hover:mb-0 transition ease-out duration-[160ms]">
-                    <a target="_blank" href=link.url().to_string() class="font-poppins font-semibold text-3xl lg:text-4xl tracking-[0.5px] text-white transition-all ease-linear duration-100">
-                        {title.to_string()}
+                    <a href=slug class="font-poppins font-semibold text-3xl lg:text-4xl tracking-[0.5px] text-white transition-all ease-linear duration-100">
+                        {title}
                     </a>
+                    <p class="font-mono text-sm text-white mt-2">{technologies.join(" · ")}</p>
                 </div>
             </div>
         </div>
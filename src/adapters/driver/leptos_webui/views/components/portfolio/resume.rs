@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 
+use crate::adapters::driver::formatters::format_date_range;
 use crate::adapters::driver::leptos_webui::views::components::common::*;
 use crate::adapters::driver::leptos_webui::views::components::portfolio::{Timeline, TimelineItem};
 use crate::application::domain::common::Timeline;
@@ -12,6 +13,10 @@ pub fn ResumeSection(
     education: Timeline,
     experience: Timeline,
 ) -> impl IntoView {
+    let lang = use_lang();
+    let education_dates = education.items().iter().map(|e| format_date_range(e, &lang)).collect::<Vec<_>>();
+    let experience_dates = experience.items().iter().map(|e| format_date_range(e, &lang)).collect::<Vec<_>>();
+
     view! {
         <Container id="resume".into()>
             <div class="">
@@ -23,11 +28,12 @@ pub fn ResumeSection(
                 <Timeline icon="bi bi-mortarboard">
                     {education
                     .items()
-                    .into_iter()
-                    .map(|e| {
+                    .iter()
+                    .zip(education_dates)
+                    .map(|(e, date)| {
                         view! {
                             <TimelineItem
-                                date=e.date().clone().to_string()
+                                date=date
                                 title=e.title().to_string()
                                 subtitle=e.subtitle().to_string()
                             />
@@ -37,11 +43,12 @@ pub fn ResumeSection(
                 <Timeline icon="bi bi-briefcase">
                     {experience
                     .items()
-                    .into_iter()
-                    .map(|e| {
+                    .iter()
+                    .zip(experience_dates)
+                    .map(|(e, date)| {
                         view! {
                             <TimelineItem
-                                date=e.date().to_string()
+                                date=date
                                 title=e.title().to_string()
                                 subtitle=e.subtitle().to_string()
                             />
@@ -1,19 +1,54 @@
 use leptos::prelude::*;
 
+use crate::adapters::driver::leptos_webui::views::layouts::LayoutResource;
+
+/// Sections linked when editors haven't configured the sidebar menu.
+const DEFAULT_ITEMS: [(&str, &str); 5] = [
+    ("About Me", "#about"),
+    ("Tech Blog", "#blog"),
+    ("Resume", "#resume"),
+    ("Projects", "#portfolio"),
+    ("Contact", "#contact"),
+];
+
 #[component]
 pub fn Sidebar() -> impl IntoView {
+    let layout = use_context::<LayoutResource>();
+
+    let items = move || {
+        let menu = layout
+            .and_then(|l| l.get())
+            .and_then(|data| data.ok())
+            .and_then(|data| data.sidebar_menu().clone());
+
+        match menu {
+            Some(menu) => menu
+                .items()
+                .iter()
+                .filter(|i| !i.hidden())
+                .map(|i| (i.title().to_string(), i.url().to_string()))
+                .collect::<Vec<_>>(),
+            None => DEFAULT_ITEMS
+                .iter()
+                .map(|(title, href)| (title.to_string(), href.to_string()))
+                .collect(),
+        }
+    };
+
     view! {
         <ul class="font-mono font-normal uppercase text-sm tracking-wider text-center lg:text-left space-x-4 lg:space-x-0">
-            <SidebarItem title="About Me" href="#about" />
-            <SidebarItem title="Tech Blog" href="#blog" />
-            <SidebarItem title="Resume" href="#resume" />
-            <SidebarItem title="Projects" href="#portfolio" />
+            {move || {
+                items()
+                    .into_iter()
+                    .map(|(title, href)| view! { <SidebarItem title=title href=href /> })
+                    .collect_view()
+            }}
         </ul>
     }
 }
 
 #[component]
-fn SidebarItem(title: &'static str, href: &'static str) -> impl IntoView {
+fn SidebarItem(title: String, href: String) -> impl IntoView {
     view! {
         <li class="list-none inline-block lg:block">
             <a href=href class="section-link group inline-flex justify-center items-center lg:block lg:justify-normal relative w-9 h-9 border border-transparent border-dashed rounded-full lg:w-auto lg:h-auto lg:border-none lg:rounded-none text-white py-3 transition ease-linear duration-100">
@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+use crate::adapters::driver::formatters::format_period;
+use crate::adapters::driver::leptos_webui::views::components::common::*;
+use crate::application::domain::project::Project;
+
+#[component]
+pub fn ProjectHeader(project: Project) -> impl IntoView {
+    let lang = use_lang();
+    let period = format_period(project.start_date(), project.end_date().as_ref(), &lang, *project.duration());
+
+    view! {
+        <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
+            <div class="category">{project.role().clone().unwrap_or_default()}</div>
+            <time class="category md:text-right">{period}</time>
+        </div>
+        <div class="my-6 md:my-10">
+            <Decoration text="Project detail".into() />
+            <PrimaryTitle text=project.title().to_string() />
+        </div>
+        <div class="pb-6">
+            {project
+                .technologies()
+                .iter()
+                .map(|t| view! { <Pill text=t.to_string() /> })
+                .collect_view()
+            }
+            {project.link().clone().map(|link| view! {
+                <a href=link.url().to_string() target="_blank" rel="noopener" class="inline-block font-mono text-sm underline mt-4 md:mt-0">
+                    {if link.title().is_empty() { link.url().to_string() } else { link.title().to_string() }}
+                </a>
+            })}
+        </div>
+    }
+}
@@ -1,12 +1,17 @@
 use leptos::either::Either;
 use leptos::prelude::*;
 
-use crate::adapters::driver::leptos_webui::controllers::layout_controller;
-use crate::adapters::driver::leptos_webui::views::components::common::Navbar;
+use crate::adapters::driver::leptos_webui::controllers::{layout_controller, WebError};
+use crate::adapters::driver::leptos_webui::views::components::common::{Footer, Navbar};
+use crate::application::domain::layout::Layout;
+
+/// The layout data shared with the pages rendered inside `BasicLayout`.
+pub type LayoutResource = OnceResource<Result<Layout, WebError>>;
 
 #[component]
 pub fn BasicLayout(children: Children) -> impl IntoView {
-    let layout = OnceResource::new(layout_controller());
+    let layout: LayoutResource = OnceResource::new(layout_controller());
+    provide_context(layout);
 
     view! {
         <Transition fallback=move || { view! { <div class="bg-white"></div> }}>
@@ -22,6 +27,8 @@ pub fn BasicLayout(children: Children) -> impl IntoView {
                                     <Navbar
                                         main_menu=data.main_menu().clone()
                                         social_menu=data.social_menu().clone()
+                                        logo=data.logo().clone()
+                                        site_name=data.site_name().as_ref().map(|n| n.to_string())
                                     />
                                 },
                             ),
@@ -34,9 +41,12 @@ pub fn BasicLayout(children: Children) -> impl IntoView {
                     {children()}
                 </div>
             </main>
-            <footer class="bg-smoke text-center py-8 text-sm text-gray-500">
-                <p>"Made with love by Santiago Marulanda ❤️."</p>
-            </footer>
+            {move || {
+                layout
+                .get_untracked()
+                .and_then(|data| data.ok())
+                .map(|data| view! { <Footer layout=data /> })
+            }}
         </Transition>
     }
 }
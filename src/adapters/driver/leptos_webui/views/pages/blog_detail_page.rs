@@ -6,10 +6,13 @@ use crate::adapters::driver::leptos_webui::controllers::article_detail_controlle
 use crate::adapters::driver::leptos_webui::views::components::blog::{DynamicContent, Header};
 use crate::adapters::driver::leptos_webui::views::components::common::*;
 use crate::adapters::driver::leptos_webui::views::layouts::BasicLayout;
+use crate::adapters::driver::structured_data::blog_posting_mapper;
+use crate::application::domain::breadcrumb::BreadcrumbTrail;
 
 #[component]
 pub fn BlogDetailPage() -> impl IntoView {
     let route = use_location();
+    let lang = use_lang();
     let page_data = Resource::new(
         move || route.pathname.read().to_string(),
         |slug| article_detail_controller(slug),
@@ -22,14 +25,16 @@ pub fn BlogDetailPage() -> impl IntoView {
                     page_data
                     .get_untracked()
                     .map(|data| {
-                        if let Err(_) = data {
-                            return view! { <UnexpectedError /> }.into_any();
+                        if let Err(error) = data {
+                            return view! { <ErrorPage error=error /> }.into_any();
                         }
 
-                        let article = data.unwrap();
+                        let (article, stale) = data.unwrap().into_parts();
+                        let trail = BreadcrumbTrail::for_article(&lang, &breadcrumb_labels(&lang), &article);
 
                         view! {
                             <MetaTags metatags=article.metatags().clone() />
+                            <StructuredData script=blog_posting_mapper(&article).to_script() />
                             <Stylesheet href="/assets/plugins/splidejs/css/splide.min.css" />
                             <Script src="/assets/plugins/splidejs/js/splide.min.js" />
                             <Script src="/assets/plugins/highlightjs/highlight.min.js" />
@@ -37,6 +42,11 @@ pub fn BlogDetailPage() -> impl IntoView {
 
                             <div class="justify-center space-y-6 lg:flex lg:space-x-8 lg:space-y-0 xl:space-x-12">
                                 <div class="lg:w-3/4 pb-12 article-detail section bg-white px-6 py-8 md:px-8 md:py-10 lg:p-12 shadow-smoke-shadow hover:shadow-smoke-shadowHover transition ease-out duration-[160ms]">
+                                    <OutdatedNotice stale=stale />
+                                    <Breadcrumbs
+                                        trail=trail
+                                        base_url=article.metatags().canonical_url().to_string()
+                                    />
                                     <Header article=article.clone() />
                                     <DynamicContent content=article.content().clone() />
                                 </div>
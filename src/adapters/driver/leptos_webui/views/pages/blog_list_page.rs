@@ -5,10 +5,12 @@ use crate::adapters::driver::leptos_webui::controllers::articles_list_controller
 use crate::adapters::driver::leptos_webui::views::components::blog::ListSection;
 use crate::adapters::driver::leptos_webui::views::components::common::*;
 use crate::adapters::driver::leptos_webui::views::layouts::BasicLayout;
+use crate::application::domain::breadcrumb::BreadcrumbTrail;
 
 #[component]
 pub fn BlogListPage() -> impl IntoView {
     let route = use_location();
+    let lang = use_lang();
     let page_data = Resource::new(
         move || route.pathname.read().to_string(),
         |slug| articles_list_controller(slug),
@@ -21,16 +23,27 @@ pub fn BlogListPage() -> impl IntoView {
                     page_data
                     .get_untracked()
                     .map(|data| {
-                        if let Err(_) = data {
-                            return view! { <UnexpectedError /> }.into_any();
+                        if let Err(error) = data {
+                            return view! { <ErrorPage error=error /> }.into_any();
                         }
 
-                        let (page, categories, articles) = data.unwrap();
-                        
+                        let ((page, categories, articles), stale) = data.unwrap().into_parts();
+                        let path = route.pathname.get_untracked();
+                        let labels = breadcrumb_labels(&lang);
+                        let trail = match categories.iter().find(|c| c.slug().as_str() == path) {
+                            Some(category) => BreadcrumbTrail::for_category(&lang, &labels, category),
+                            None => BreadcrumbTrail::for_blog(&lang, &labels),
+                        };
+
                         view! {
                             <MetaTags metatags=page.metatags().clone() />
                             <div class="justify-center space-y-6 lg:flex lg:space-x-8 lg:space-y-0 xl:space-x-12">
                                 <div class="w-full space-y-6 mb-12">
+                                    <OutdatedNotice stale=stale />
+                                    <Breadcrumbs
+                                        trail=trail
+                                        base_url=page.metatags().canonical_url().to_string()
+                                    />
                                     <ListSection articles=articles categories=categories />
                                 </div>
                             </div>
@@ -2,8 +2,10 @@ mod blog_list_page;
 mod blog_detail_page;
 mod not_found_page;
 mod portfolio_page;
+mod project_detail_page;
 
 pub use blog_list_page::*;
 pub use blog_detail_page::*;
 pub use not_found_page::*;
 pub use portfolio_page::*;
+pub use project_detail_page::*;
@@ -1,13 +1,15 @@
 use leptos::prelude::*;
 
 use crate::adapters::driver::leptos_webui::controllers::portfolio_detail_controller;
-use crate::adapters::driver::leptos_webui::views::components::common::{UnexpectedError,  MetaTags};
+use crate::adapters::driver::leptos_webui::views::components::common::{use_lang, ErrorPage, MetaTags, OutdatedNotice, StructuredData};
 use crate::adapters::driver::leptos_webui::views::components::portfolio::*;
 use crate::adapters::driver::leptos_webui::views::layouts::*;
+use crate::adapters::driver::structured_data::{profile_page_mapper, website_mapper};
 
 #[component]
 pub fn PortfolioPage() -> impl IntoView {
     let page_data = OnceResource::new(portfolio_detail_controller());
+    let lang = use_lang();
 
     view! {
         <BasicLayout>
@@ -16,18 +18,21 @@ pub fn PortfolioPage() -> impl IntoView {
                     page_data
                     .get_untracked()
                     .map(|data| {
-                        if let Err(_) = data {
-                            return view! { <UnexpectedError /> }.into_any();
+                        if let Err(error) = data {
+                            return view! { <ErrorPage error=error /> }.into_any();
                         }
 
-                        let portfolio = data.unwrap();
+                        let (portfolio, stale) = data.unwrap().into_parts();
                         view! {
                             <MetaTags metatags=portfolio.metatags().clone() />
+                            <StructuredData script=website_mapper(portfolio.metatags(), &lang).to_script() />
+                            <StructuredData script=profile_page_mapper(&portfolio).to_script() />
                             <div class="justify-center lg:flex lg:space-x-8 lg:space-y-0 xl:space-x-12">
                                 <div class="lg:w-1/4 hidden lg:block sticky px-4 lg:px-8 xl:px-10 py-3 lg:py-5 xl:py-7 lg:h-fit top-2 lg:top-[80px] bg-teal shadow-smoke-shadow hover:shadow-smoke-shadowHover rounded-lg">
                                     <Sidebar />
                                 </div>
                                 <div class="lg:w-3/4 space-y-6">
+                                    <OutdatedNotice stale=stale />
                                     <DynamicSections sections=portfolio.sections().clone() />
                                 </div>
                             </div>
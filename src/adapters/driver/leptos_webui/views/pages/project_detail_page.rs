@@ -0,0 +1,94 @@
+use leptos::prelude::*;
+use leptos_meta::{Script, Stylesheet};
+use leptos_router::hooks::use_location;
+
+use crate::adapters::driver::leptos_webui::controllers::project_detail_controller;
+use crate::adapters::driver::leptos_webui::views::components::common::*;
+use crate::adapters::driver::leptos_webui::views::components::project::ProjectHeader;
+use crate::adapters::driver::leptos_webui::views::layouts::BasicLayout;
+use crate::application::domain::breadcrumb::BreadcrumbTrail;
+
+#[component]
+pub fn ProjectDetailPage() -> impl IntoView {
+    let route = use_location();
+    let lang = use_lang();
+    let page_data = Resource::new(
+        move || route.pathname.read().to_string(),
+        project_detail_controller,
+    );
+
+    view! {
+        <BasicLayout>
+            <Suspense fallback=move || { view! { <div>"Loading..."</div> } }>
+                {move || {
+                    page_data
+                    .get_untracked()
+                    .map(|data| {
+                        let (project, stale) = match data {
+                            Ok(project) => project.into_parts(),
+                            Err(error) => return view! { <ErrorPage error=error /> }.into_any(),
+                        };
+
+                        let gallery = project.gallery().clone();
+                        let trail = BreadcrumbTrail::for_project(&lang, &breadcrumb_labels(&lang), &project);
+
+                        view! {
+                            <MetaTags metatags=project.metatags().clone() />
+                            <Stylesheet href="/assets/plugins/splidejs/css/splide.min.css" />
+                            <Script src="/assets/plugins/splidejs/js/splide.min.js" />
+
+                            <div class="justify-center space-y-6 lg:flex lg:space-x-8 lg:space-y-0 xl:space-x-12">
+                                <div class="lg:w-3/4 pb-12 article-detail section bg-white px-6 py-8 md:px-8 md:py-10 lg:p-12 shadow-smoke-shadow hover:shadow-smoke-shadowHover transition ease-out duration-[160ms]">
+                                    <OutdatedNotice stale=stale />
+                                    <Breadcrumbs
+                                        trail=trail
+                                        base_url=project.metatags().canonical_url().to_string()
+                                    />
+                                    <ProjectHeader project=project.clone() />
+                                    <RawHtml html=project.body().to_string() class="" />
+                                    <Show when=move || !gallery.is_empty()>
+                                        <Slider
+                                            thumbnails=project.gallery().thumbnails().clone()
+                                            images=project.gallery().images().clone()
+                                        />
+                                    </Show>
+                                </div>
+                            </div>
+
+                            <script>
+                                "document.addEventListener('DOMContentLoaded', (event) => {
+                                    if (window.Splide) {
+                                        document.querySelectorAll('[data-slider]').forEach(function(element) {
+                                            let identifier = element.dataset.slider;
+
+                                            var main = new Splide(`#main-slider-${identifier}`, {
+                                                type      : 'fade',
+                                                rewind    : true,
+                                                pagination: false,
+                                                arrows    : false,
+                                            });
+
+                                            var thumbnails = new Splide(`#thumbnail-slider-${identifier}`, {
+                                                fixedWidth  : 100,
+                                                fixedHeight : 60,
+                                                gap         : 10,
+                                                rewind      : true,
+                                                pagination  : false,
+                                                isNavigation: true,
+                                                focus: 'center',
+                                            });
+
+                                            main.sync(thumbnails);
+                                            main.mount();
+                                            thumbnails.mount();
+                                        });
+                                    }
+                                });"
+                            </script>
+                        }.into_any()
+                    })
+                }}
+            </Suspense>
+        </BasicLayout>
+    }
+}
@@ -1 +1,7 @@
+#[cfg(feature = "ssr")]
+pub mod actix_endpoints;
+#[cfg(feature = "ssr")]
+pub mod static_export;
+pub mod formatters;
+pub mod structured_data;
 pub mod leptos_webui;
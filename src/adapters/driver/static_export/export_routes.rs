@@ -0,0 +1,141 @@
+use itertools::Itertools;
+
+use crate::adapters::driven::content_source::ContentRepositories;
+use crate::adapters::driver::actix_endpoints::LANGUAGES;
+use crate::application::domain::core::Result;
+
+/// The paths to export, built from the route patterns of the router, e.g. `/{lang}/articles`.
+///
+/// Routes without parameters are exported as they are, the ones whose only parameter is the
+/// language once per language. The others are exported for the content matching them.
+pub struct ExportRoutes {
+    patterns: Vec<String>,
+}
+
+impl ExportRoutes {
+    pub fn new(patterns: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            patterns: patterns.into_iter().collect(),
+        }
+    }
+
+    /// The paths of the routes and of the content, each content path in every language.
+    pub fn paths(&self, content_paths: &[String]) -> Vec<String> {
+        let route_paths = self.patterns.iter().flat_map(|pattern| {
+            let segments = pattern.split('/').collect::<Vec<_>>();
+
+            match segments.iter().filter(|s| s.starts_with('{')).collect::<Vec<_>>()[..] {
+                [] => vec![pattern.to_string()],
+                [&"{lang}"] => LANGUAGES.iter().map(|lang| pattern.replace("{lang}", lang)).collect(),
+                _ => vec![],
+            }
+        });
+        let content_paths = content_paths
+            .iter()
+            .flat_map(|path| LANGUAGES.iter().map(|lang| localized_path(path, lang)))
+            .filter(|path| self.patterns.iter().any(|pattern| matches_pattern(pattern, path)));
+
+        route_paths.chain(content_paths).unique().collect()
+    }
+}
+
+/// The paths of every page, article, category and project of the content source.
+pub async fn content_paths(repositories: &ContentRepositories) -> Result<Vec<String>> {
+    let pages = repositories.page_data().get_list().await?;
+    let articles = repositories.articles_list().get_all().await?;
+    let categories = repositories.categories_list().find_all_categories().await?;
+    let projects = repositories.projects().get_list().await?;
+
+    Ok(pages
+        .iter()
+        .map(|p| p.slug().to_string())
+        .chain(articles.iter().map(|a| a.slug().to_string()))
+        .chain(categories.iter().map(|c| c.slug().to_string()))
+        .chain(projects.iter().map(|p| p.slug().to_string()))
+        .collect())
+}
+
+/// The path in the given language, paths without a language prefix are kept as they are.
+fn localized_path(path: &str, lang: &str) -> String {
+    match path.split('/').nth(1) {
+        Some(prefix) if LANGUAGES.contains(&prefix) => format!("/{lang}{}", &path[prefix.len() + 1..]),
+        _ => path.to_string(),
+    }
+}
+
+/// Whether the path matches the pattern, parameters match any segment but the wildcard ones
+/// match nothing, as they only exist to render the not found page.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.split('/').collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+
+    pattern.len() == path.len()
+        && pattern.iter().zip(path).all(|(expected, segment)| match expected.strip_prefix('{') {
+            Some(parameter) => !parameter.contains(':') && !segment.is_empty(),
+            None => *expected == segment,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::driven::content_source::ContentSource;
+    use crate::adapters::driven::markdown_files::services::ContentFilesService;
+    use crate::utilities::HttpClient;
+    use rstest::rstest;
+
+    fn routes() -> ExportRoutes {
+        ExportRoutes::new(
+            [
+                "/",
+                "/en",
+                "/{lang}/articles",
+                "/{lang}/articles/{category}",
+                "/{lang}/articles/{category}/{slug}",
+                "/{any:.*}",
+            ]
+            .map(String::from),
+        )
+    }
+
+    #[test]
+    fn paths_succeeds_when_routes_have_language() {
+        let paths = routes().paths(&[]);
+
+        assert_eq!(paths, vec!["/", "/en", "/en/articles", "/es/articles"]);
+    }
+
+    #[test]
+    fn paths_succeeds_when_content_matches_routes() {
+        let paths = routes().paths(&[
+            "/en/articles/rust/error-handling".to_string(),
+            "/en/articles/rust".to_string(),
+            "/en/projects/website".to_string(),
+        ]);
+
+        assert!(paths.contains(&"/en/articles/rust/error-handling".to_string()));
+        assert!(paths.contains(&"/es/articles/rust/error-handling".to_string()));
+        assert!(paths.contains(&"/es/articles/rust".to_string()));
+        assert!(!paths.iter().any(|p| p.contains("/projects/")));
+    }
+
+    #[actix_rt::test]
+    async fn content_paths_succeeds_when_source_has_pages() {
+        let repositories = ContentSource::Markdown(ContentFilesService::new("tests/fixtures/content"))
+            .repositories(&HttpClient::default());
+
+        let paths = content_paths(&repositories).await.unwrap();
+
+        assert!(paths.contains(&"/en/articles".to_string()));
+        assert!(paths.contains(&"/en/articles/algorithms/linked-lists".to_string()));
+        assert!(paths.contains(&"/en/articles/algorithms".to_string()));
+    }
+
+    #[rstest]
+    #[case("/en/articles/rust", "es", "/es/articles/rust")]
+    #[case("/en", "es", "/es")]
+    #[case("/node/21", "es", "/node/21")]
+    fn localized_path_replaces_language_prefix(#[case] path: &str, #[case] lang: &str, #[case] expected: &str) {
+        assert_eq!(localized_path(path, lang), expected);
+    }
+}
@@ -0,0 +1,5 @@
+mod export_routes;
+mod static_exporter;
+
+pub use export_routes::*;
+pub use static_exporter::*;
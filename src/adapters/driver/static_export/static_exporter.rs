@@ -0,0 +1,418 @@
+use derive_getters::Getters;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::header::{CONTENT_TYPE, LOCATION};
+use reqwest::redirect::Policy;
+use reqwest::Client;
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+lazy_static! {
+    static ref PAGE_LINK: Regex = Regex::new(r#"href="(/[^"/][^"]*|/)""#).unwrap();
+    static ref ROOT_RELATIVE_LINK: Regex = Regex::new(r#"(?P<attribute>href|src|action)="/(?P<next>[^/])"#).unwrap();
+    static ref TITLE: Regex = Regex::new(r"(?s)<title>(.*?)</title>").unwrap();
+    static ref DESCRIPTION: Regex = Regex::new(r#"<meta name="description" content="([^"]*)""#).unwrap();
+    static ref HYDRATION_PRELOAD: Regex =
+        Regex::new(r#"<link rel="(?:modulepreload|preload)" href="/pkg/[^"]*\.(?:js|wasm)"[^>]*>"#).unwrap();
+    static ref MODULE_SCRIPT: Regex = Regex::new(r#"(?s)<script type="module"[^>]*>.*?</script>"#).unwrap();
+}
+
+/// Path no route matches, its answer is exported as the `404.html` served by static hosts.
+const NOT_FOUND_PATH: &str = "/static-export/not-found";
+
+/// Paths served from the assets directory instead of being rendered.
+const ASSET_PREFIXES: [&str; 3] = ["/assets/", "/pkg/", "/api/"];
+
+/// Metadata of an exported page, listed in the `pages.json` of the export.
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct ExportedPage {
+    path: String,
+    file: String,
+    content_type: String,
+    title: Option<String>,
+    description: Option<String>,
+}
+
+/// Renders the pages of the running app into a directory deployable on any static host.
+///
+/// Every page is requested from the app and written as `<path>/index.html`, the pages it links
+/// to are exported as well. Redirects become pages refreshing to their destination, links to
+/// the app are rewritten to the public url of the site and the hydration scripts are removed.
+pub struct StaticExporter {
+    output_dir: PathBuf,
+    base_url: String,
+    assets_dir: Option<PathBuf>,
+    client: Client,
+}
+
+impl StaticExporter {
+    pub fn new(output_dir: &str) -> Self {
+        Self {
+            output_dir: PathBuf::from(output_dir),
+            base_url: String::new(),
+            assets_dir: None,
+            client: Client::builder().redirect(Policy::none()).build().unwrap_or_default(),
+        }
+    }
+
+    /// The public url of the site, e.g. `https://tiagocode.com` or `https://example.github.io/website`.
+    ///
+    /// Without it, links to the app become relative to the root of the host.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// The directory served at `/assets`, its `pkg` directory is served at `/pkg`.
+    pub fn assets(mut self, assets_dir: &str) -> Self {
+        self.assets_dir = Some(PathBuf::from(assets_dir));
+        self
+    }
+
+    /// Exports the paths served by the app at `server_url`, and every page reachable from them.
+    ///
+    /// Pages answering with an error are skipped, the export fails when the app can't be reached
+    /// or the files can't be written.
+    pub async fn export(&self, server_url: &str, paths: Vec<String>) -> io::Result<Vec<ExportedPage>> {
+        let mut queue = VecDeque::from(paths);
+        let mut visited = HashSet::new();
+        let mut pages = vec![];
+
+        while let Some(path) = queue.pop_front() {
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+
+            let Some((page, links)) = self.export_page(server_url, &path, &export_file(&path)).await? else {
+                continue;
+            };
+            queue.extend(links.into_iter().filter(|link| !visited.contains(link)));
+            pages.push(page);
+        }
+
+        // answered with a 404, which is the page static hosts serve for unknown paths
+        self.export_page(server_url, NOT_FOUND_PATH, "404.html").await?;
+        self.copy_assets()?;
+
+        let manifest = serde_json::to_string_pretty(&pages).map_err(io::Error::other)?;
+        self.write("pages.json", manifest.as_bytes())?;
+        info!("Exported {} pages into `{}`", pages.len(), self.output_dir.display());
+
+        Ok(pages)
+    }
+
+    /// Writes the page into the file, returning it with the paths of the pages it links to.
+    async fn export_page(
+        &self,
+        server_url: &str,
+        path: &str,
+        file: &str,
+    ) -> io::Result<Option<(ExportedPage, Vec<String>)>> {
+        let response = self
+            .client
+            .get(format!("{server_url}{path}"))
+            .send()
+            .await
+            .map_err(io::Error::other)?;
+        let status = response.status();
+        let header = |name| response.headers().get(name).and_then(|v| v.to_str().ok()).map(String::from);
+        let content_type = header(CONTENT_TYPE).unwrap_or("text/html".to_string());
+
+        // the redirects of the router answer with a location but a successful status
+        if let Some(location) = header(LOCATION) {
+            let page = ExportedPage::new(path, file, "text/html", None, None);
+            self.write(file, redirect_page(&self.public_link(&location, server_url)).as_bytes())?;
+
+            let links = Some(location).filter(|l| l.starts_with('/')).into_iter().collect();
+            return Ok(Some((page, links)));
+        }
+
+        if !status.is_success() && path != NOT_FOUND_PATH {
+            warn!("Skipping `{path}`, it answered with {status}");
+            return Ok(None);
+        }
+
+        let body = response.bytes().await.map_err(io::Error::other)?;
+
+        if !is_text(&content_type) {
+            self.write(file, &body)?;
+            return Ok(Some((ExportedPage::new(path, file, &content_type, None, None), vec![])));
+        }
+
+        let body = String::from_utf8_lossy(&body);
+        let links = PAGE_LINK
+            .captures_iter(&body)
+            .map(|c| c[1].split(['?', '#']).next().unwrap_or_default().to_string())
+            .filter(|link| !ASSET_PREFIXES.iter().any(|prefix| link.starts_with(prefix)))
+            .collect();
+        let capture = |regex: &Regex| regex.captures(&body).map(|c| c[1].trim().to_string());
+        let page = ExportedPage::new(path, file, &content_type, capture(&TITLE), capture(&DESCRIPTION));
+
+        let body = match content_type.starts_with("text/html") {
+            true => strip_hydration(&body),
+            false => body.to_string(),
+        };
+        self.write(file, self.rewrite_links(&body, server_url).as_bytes())?;
+
+        Ok(Some((page, links)))
+    }
+
+    /// Points the links to the app to the public url, root relative links included when the
+    /// site is hosted under a path.
+    fn rewrite_links(&self, body: &str, server_url: &str) -> String {
+        let body = body.replace(server_url, &self.base_url);
+        let base_path = self.base_path();
+
+        if base_path.is_empty() {
+            return body;
+        }
+
+        ROOT_RELATIVE_LINK
+            .replace_all(&body, format!("$attribute=\"{base_path}/$next"))
+            .to_string()
+    }
+
+    /// The public url of a single link, e.g. the location of a redirect.
+    fn public_link(&self, link: &str, server_url: &str) -> String {
+        match link.strip_prefix(server_url) {
+            Some(path) => format!("{}{path}", self.base_url),
+            None if link.starts_with('/') && !link.starts_with("//") => format!("{}{link}", self.base_path()),
+            None => link.to_string(),
+        }
+    }
+
+    /// The path the site is hosted under, empty when it's hosted at the root.
+    fn base_path(&self) -> String {
+        reqwest::Url::parse(&self.base_url)
+            .map(|url| url.path().trim_end_matches('/').to_string())
+            .unwrap_or_default()
+    }
+
+    fn copy_assets(&self) -> io::Result<()> {
+        let Some(assets_dir) = &self.assets_dir else {
+            return Ok(());
+        };
+
+        if !assets_dir.is_dir() {
+            warn!("The assets directory `{}` doesn't exist, build the site first", assets_dir.display());
+            return Ok(());
+        }
+
+        copy_dir(assets_dir, &self.output_dir.join("assets"))?;
+        copy_dir(&assets_dir.join("pkg"), &self.output_dir.join("pkg"))?;
+
+        let favicon = assets_dir.join("favicon.ico");
+        if favicon.is_file() {
+            fs::copy(favicon, self.output_dir.join("favicon.ico"))?;
+        }
+
+        Ok(())
+    }
+
+    fn write(&self, file: &str, content: &[u8]) -> io::Result<()> {
+        let path = self.output_dir.join(file);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, content)
+    }
+}
+
+impl ExportedPage {
+    fn new(path: &str, file: &str, content_type: &str, title: Option<String>, description: Option<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            file: file.to_string(),
+            content_type: content_type.to_string(),
+            title,
+            description,
+        }
+    }
+}
+
+/// The file of the path, pages are written as `index.html` so hosts serve them without extension.
+fn export_file(path: &str) -> String {
+    let path = path
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != "." && *segment != "..")
+        .collect::<Vec<_>>()
+        .join("/");
+
+    match path.rsplit('/').next() {
+        Some(segment) if segment.contains('.') => path,
+        _ if path.is_empty() => "index.html".to_string(),
+        _ => format!("{path}/index.html"),
+    }
+}
+
+fn is_text(content_type: &str) -> bool {
+    ["text/", "application/json", "application/ld+json", "application/xml"]
+        .iter()
+        .any(|text| content_type.starts_with(text))
+}
+
+/// Removes the scripts hydrating the page, so links keep loading whole pages.
+///
+/// Once hydrated, the router would load the data of the next page through the `/api` server
+/// functions, which a static host doesn't serve.
+fn strip_hydration(body: &str) -> String {
+    let body = HYDRATION_PRELOAD.replace_all(body, "");
+
+    MODULE_SCRIPT
+        .replace_all(&body, |c: &regex::Captures| match c[0].contains(".hydrate()") {
+            true => String::new(),
+            false => c[0].to_string(),
+        })
+        .to_string()
+}
+
+/// Static hosts can't answer with a redirect, the page refreshes to the destination instead.
+fn redirect_page(location: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8"><meta http-equiv="refresh" content="0; url={location}"><link rel="canonical" href="{location}"></head><body><a href="{location}">{location}</a></body></html>"#
+    )
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    if !from.is_dir() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)?.flatten() {
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use rstest::rstest;
+
+    fn output_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("static-export-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[rstest]
+    #[case("/", "index.html")]
+    #[case("/en", "en/index.html")]
+    #[case("/en/articles/rust/", "en/articles/rust/index.html")]
+    #[case("/en/resume.pdf", "en/resume.pdf")]
+    #[case("/en/../../etc", "en/etc/index.html")]
+    fn export_file_succeeds_when_path_is_given(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(export_file(path), expected);
+    }
+
+    #[rstest]
+    #[case("https://tiagocode.com", r#"<a href="/en/articles">"#, r#"<a href="/en/articles">"#)]
+    #[case("https://example.github.io/website", r#"<a href="/en/articles">"#, r#"<a href="/website/en/articles">"#)]
+    #[case("https://example.github.io/website", r#"<a href="/">"#, r#"<a href="/website/">"#)]
+    #[case("https://example.github.io/website", r#"<a href="//cdn.example.com">"#, r#"<a href="//cdn.example.com">"#)]
+    #[case("https://tiagocode.com", r#"<link href="http://127.0.0.1:3000/en">"#, r#"<link href="https://tiagocode.com/en">"#)]
+    fn rewrite_links_succeeds_when_base_url_is_given(#[case] base_url: &str, #[case] body: &str, #[case] expected: &str) {
+        let exporter = StaticExporter::new("dist").base_url(base_url);
+
+        assert_eq!(exporter.rewrite_links(body, "http://127.0.0.1:3000"), expected);
+    }
+
+    #[rstest]
+    #[case("", "/en", "/en")]
+    #[case("https://example.github.io/website", "/en", "/website/en")]
+    #[case("https://tiagocode.com", "http://127.0.0.1:3000/en", "https://tiagocode.com/en")]
+    #[case("https://tiagocode.com", "https://github.com", "https://github.com")]
+    fn public_link_succeeds_when_base_url_is_given(#[case] base_url: &str, #[case] link: &str, #[case] expected: &str) {
+        let exporter = StaticExporter::new("dist").base_url(base_url);
+
+        assert_eq!(exporter.public_link(link, "http://127.0.0.1:3000"), expected);
+    }
+
+    #[actix_rt::test]
+    async fn export_succeeds_when_pages_link_to_each_other() {
+        let mut server = Server::new_async().await;
+        let html = |body: &str| format!("<html><head><title>Tiagocode</title></head><body>{body}</body></html>");
+        server.mock("GET", "/").with_header("location", "/en").create_async().await;
+        server
+            .mock("GET", "/en")
+            .with_header("content-type", "text/html; charset=utf-8")
+            .with_body(html(r#"<a href="/en/articles?page=2">Articles</a><img src="/assets/logo.svg">"#))
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/en/articles")
+            .with_header("content-type", "text/html; charset=utf-8")
+            .with_body(html(r#"<a href="/en/missing">Missing</a>"#))
+            .create_async()
+            .await;
+        server.mock("GET", "/en/missing").with_status(500).create_async().await;
+        server
+            .mock("GET", NOT_FOUND_PATH)
+            .with_status(404)
+            .with_header("content-type", "text/html; charset=utf-8")
+            .with_body(html("Not found"))
+            .create_async()
+            .await;
+        let output_dir = output_dir();
+
+        let pages = StaticExporter::new(output_dir.to_str().unwrap())
+            .export(&server.url(), vec!["/".to_string()])
+            .await
+            .unwrap();
+
+        let paths = pages.iter().map(|p| p.path().as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, vec!["/", "/en", "/en/articles"]);
+        assert!(fs::read_to_string(output_dir.join("index.html")).unwrap().contains(r#"url=/en""#));
+        assert!(output_dir.join("en/articles/index.html").is_file());
+        assert!(!output_dir.join("en/missing/index.html").exists());
+        assert!(fs::read_to_string(output_dir.join("404.html")).unwrap().contains("Not found"));
+        assert!(fs::read_to_string(output_dir.join("pages.json")).unwrap().contains(r#""title": "Tiagocode""#));
+    }
+
+    #[actix_rt::test]
+    async fn export_strips_hydration_when_page_is_html() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/en")
+            .with_header("content-type", "text/html; charset=utf-8")
+            .with_body(
+                r#"<html><head><link rel="modulepreload" href="/pkg/website.js" crossorigin="n">
+<link rel="preload" href="/pkg/website_bg.wasm" as="fetch" type="application/wasm" crossorigin="n">
+<script type="module" nonce="n">(function (root, pkg_path, output_name, wasm_output_name) {
+    import(`${root}/${pkg_path}/${output_name}.js`).then(mod => { mod.default().then(() => { mod.hydrate(); }); })
+})("", "pkg", "website", "website_bg");</script>
+<link id="leptos" rel="stylesheet" href="/pkg/website.css"></head>
+<body><script type="application/ld+json">{}</script><a href="/en">Home</a></body></html>"#,
+            )
+            .create_async()
+            .await;
+        server.mock("GET", NOT_FOUND_PATH).with_status(404).create_async().await;
+        let output_dir = output_dir();
+
+        StaticExporter::new(output_dir.to_str().unwrap())
+            .export(&server.url(), vec!["/en".to_string()])
+            .await
+            .unwrap();
+
+        let page = fs::read_to_string(output_dir.join("en/index.html")).unwrap();
+        assert!(!page.contains("modulepreload"));
+        assert!(!page.contains("website_bg.wasm"));
+        assert!(!page.contains("hydrate()"));
+        assert!(page.contains(r#"href="/pkg/website.css""#));
+        assert!(page.contains(r#"<script type="application/ld+json">"#));
+    }
+}
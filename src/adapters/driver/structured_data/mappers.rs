@@ -0,0 +1,228 @@
+use voca_rs::escape::unescape_html;
+use voca_rs::strip::strip_tags;
+
+use crate::adapters::driver::structured_data::schema::*;
+use crate::application::domain::article::Article;
+use crate::application::domain::breadcrumb::BreadcrumbTrail;
+use crate::application::domain::common::{MetaTags, Timeline};
+use crate::application::domain::portfolio::{Portfolio, PortfolioSection};
+
+/// The author of every article, matching the `author` meta tag.
+pub const AUTHOR_NAME: &str = "Santiago Marulanda Molina";
+
+const DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%:z";
+
+/// Maps an article into a `BlogPosting`.
+pub fn blog_posting_mapper(article: &Article) -> JsonLd<BlogPosting> {
+    let canonical_url = article.metatags().canonical_url().to_string();
+    let thumbnail = article.thumbnail();
+
+    let image = ImageObjectBuilder::default()
+        .url(thumbnail.url().to_string())
+        .width(*thumbnail.width())
+        .height(*thumbnail.height())
+        .build()
+        .unwrap();
+
+    let author = PersonBuilder::default()
+        .name(AUTHOR_NAME.to_string())
+        .url(Some(origin(&canonical_url)))
+        .build()
+        .unwrap();
+
+    let posting = BlogPostingBuilder::default()
+        .headline(plain_text(article.title()))
+        .description(plain_text(article.summary()))
+        .image(image)
+        .date_published(article.created_at().to_string_with_format(DATE_FORMAT))
+        .author(author)
+        .article_section(article.category().title().to_string())
+        .url(canonical_url.clone())
+        .main_entity_of_page(canonical_url)
+        .build()
+        .unwrap();
+
+    JsonLd::new(posting)
+}
+
+/// Maps a portfolio into a `ProfilePage` whose main entity is the author, described
+/// by the `AboutMe` and `Resume` sections.
+pub fn profile_page_mapper(portfolio: &Portfolio) -> JsonLd<ProfilePage> {
+    let canonical_url = portfolio.metatags().canonical_url().to_string();
+    let mut person = PersonBuilder::default();
+    person
+        .name(AUTHOR_NAME.to_string())
+        .url(Some(canonical_url.clone()));
+
+    for section in portfolio.sections() {
+        match section {
+            PortfolioSection::AboutMe(about_me) => {
+                person
+                    .job_title(Some(plain_text(about_me.title())))
+                    .description(Some(plain_text(about_me.text())))
+                    .image(Some(about_me.profile_picture().url().to_string()))
+                    .knows_about(about_me.skills().clone());
+            }
+            PortfolioSection::Resume(resume) => {
+                person
+                    .works_for(organizations(resume.experience(), true))
+                    .alumni_of(organizations(resume.education(), false));
+            }
+            _ => {}
+        }
+    }
+
+    let page = ProfilePageBuilder::default()
+        .url(canonical_url)
+        .date_created(portfolio.created_at().to_string_with_format(DATE_FORMAT))
+        .main_entity(person.build().unwrap())
+        .build()
+        .unwrap();
+
+    JsonLd::new(page)
+}
+
+/// Maps the site into a `WebSite`.
+///
+/// # Arguments
+/// * `metatags` - The metatags of any page, providing the site name and origin.
+/// * `lang` - The language of the home page.
+pub fn website_mapper(metatags: &MetaTags, lang: &str) -> JsonLd<WebSite> {
+    let origin = origin(metatags.canonical_url().as_str());
+
+    let website = WebSiteBuilder::default()
+        .name(metatags.og_site_name().to_string())
+        .url(format!("{origin}/{lang}"))
+        .build()
+        .unwrap();
+
+    JsonLd::new(website)
+}
+
+/// Maps a breadcrumb trail into a `BreadcrumbList`, relative urls are resolved against `base_url`.
+pub fn breadcrumb_list_mapper(trail: &BreadcrumbTrail, base_url: &str) -> JsonLd<BreadcrumbList> {
+    let origin = origin(base_url);
+
+    let items = trail
+        .items()
+        .iter()
+        .enumerate()
+        .map(|(index, breadcrumb)| {
+            let url = breadcrumb.url();
+            let item = match url.is_absolute() {
+                true => url.to_string(),
+                false => format!("{origin}{}", url.as_str()),
+            };
+
+            ListItemBuilder::default()
+                .position(index + 1)
+                .name(breadcrumb.title().to_string())
+                .item(item)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    let list = BreadcrumbListBuilder::default()
+        .item_list_element(items)
+        .build()
+        .unwrap();
+
+    JsonLd::new(list)
+}
+
+/// Returns the scheme and host of an absolute url, e.g. `https://example.com`.
+fn origin(url: &str) -> String {
+    url.splitn(4, '/').take(3).collect::<Vec<_>>().join("/")
+}
+
+/// Lists the organizations of a timeline, `ongoing` keeps only the items without end date.
+fn organizations(timeline: &Timeline, ongoing: bool) -> Vec<Organization> {
+    timeline
+        .items()
+        .iter()
+        .filter(|item| !ongoing || item.end_date().is_none())
+        .map(|item| {
+            OrganizationBuilder::default()
+                .name(plain_text(item.subtitle()))
+                .build()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn plain_text(text: &str) -> String {
+    unescape_html(&strip_tags(text)).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::article::tests::article_fixture;
+    use crate::application::domain::breadcrumb::tests::labels_fixture;
+    use crate::application::domain::common::tests::metatags_fixture;
+    use crate::application::domain::portfolio::tests::portfolio_fixture;
+    use serde_json::Value;
+
+    fn to_json<T: serde::Serialize>(json_ld: &JsonLd<T>) -> Value {
+        serde_json::from_str(&json_ld.to_script()).unwrap()
+    }
+
+    #[test]
+    fn blog_posting_succeeds_when_article_is_given() {
+        let article = article_fixture();
+        let json = to_json(&blog_posting_mapper(&article));
+
+        assert_eq!(json["@context"], "https://schema.org");
+        assert_eq!(json["@type"], "BlogPosting");
+        assert_eq!(json["headline"], "What is Lorem Ipsum?");
+        assert_eq!(json["articleSection"], "Example");
+        assert_eq!(json["datePublished"], "2024-12-15T14:03:56+00:00");
+        assert_eq!(json["author"]["@type"], "Person");
+        assert_eq!(json["image"]["@type"], "ImageObject");
+        assert!(json["author"].get("@context").is_none());
+    }
+
+    #[test]
+    fn profile_page_succeeds_when_portfolio_is_given() {
+        let json = to_json(&profile_page_mapper(&portfolio_fixture()));
+        let person = &json["mainEntity"];
+
+        assert_eq!(json["@type"], "ProfilePage");
+        assert_eq!(person["@type"], "Person");
+        assert_eq!(person["name"], AUTHOR_NAME);
+        assert_eq!(person["jobTitle"], "Example title");
+        assert_eq!(person["knowsAbout"][0], "Example skill 1");
+        assert_eq!(person["alumniOf"][0]["@type"], "Organization");
+    }
+
+    #[test]
+    fn website_succeeds_when_metatags_are_given() {
+        let json = to_json(&website_mapper(&metatags_fixture(), "en"));
+
+        assert_eq!(json["@type"], "WebSite");
+        assert!(json["url"].as_str().unwrap().ends_with("/en"));
+        assert!(json.get("potentialAction").is_none());
+    }
+
+    #[test]
+    fn breadcrumb_list_succeeds_when_trail_is_given() {
+        let trail = BreadcrumbTrail::for_article("en", &labels_fixture(), &article_fixture());
+        let json = to_json(&breadcrumb_list_mapper(&trail, "https://example.com/en/articles/rust"));
+
+        assert_eq!(json["@type"], "BreadcrumbList");
+        assert_eq!(json["itemListElement"][0]["position"], 1);
+        assert_eq!(json["itemListElement"][0]["item"], "https://example.com/en");
+        assert_eq!(json["itemListElement"][3]["name"], "What is Lorem Ipsum?");
+    }
+
+    #[test]
+    fn to_script_escapes_closing_tags() {
+        let person = PersonBuilder::default()
+            .name("</script><script>alert(1)</script>".to_string())
+            .build()
+            .unwrap();
+
+        assert!(!JsonLd::new(person).to_script().contains("</script>"));
+    }
+}
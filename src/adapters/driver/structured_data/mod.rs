@@ -0,0 +1,5 @@
+mod mappers;
+mod schema;
+
+pub use mappers::*;
+pub use schema::*;
@@ -0,0 +1,115 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+const SCHEMA_CONTEXT: &str = "https://schema.org";
+
+/// A schema.org entity ready to be embedded in a `<script type="application/ld+json">`.
+///
+/// The `@type` of every entity comes from the struct name, so only the top-level
+/// document carries the `@context`.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonLd<T: Serialize> {
+    #[serde(rename = "@context")]
+    context: &'static str,
+    #[serde(flatten)]
+    entity: T,
+}
+
+impl<T: Serialize> JsonLd<T> {
+    pub fn new(entity: T) -> Self {
+        Self {
+            context: SCHEMA_CONTEXT,
+            entity,
+        }
+    }
+
+    /// Serializes the document, escaping `</` so it can't close the script element.
+    pub fn to_script(&self) -> String {
+        serde_json::to_string(self)
+            .unwrap_or_default()
+            .replace("</", "<\\/")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BlogPosting {
+    headline: String,
+    description: String,
+    image: ImageObject,
+    date_published: String,
+    author: Person,
+    article_section: String,
+    url: String,
+    main_entity_of_page: String,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct ImageObject {
+    url: String,
+    width: u16,
+    height: u16,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Person {
+    name: String,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_title: Option<String>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    knows_about: Vec<String>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    works_for: Vec<Organization>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    alumni_of: Vec<Organization>,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct Organization {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct ProfilePage {
+    url: String,
+    date_created: String,
+    main_entity: Person,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct WebSite {
+    name: String,
+    url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct BreadcrumbList {
+    item_list_element: Vec<ListItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(tag = "@type", rename_all = "camelCase")]
+pub struct ListItem {
+    position: usize,
+    name: String,
+    item: String,
+}
@@ -0,0 +1,113 @@
+use derive_builder::Builder;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::application::domain::article::{Article, Category};
+use crate::application::domain::project::Project;
+use crate::application::value_objects::Url;
+
+#[derive(Debug, Clone, PartialEq, Getters, Serialize, Deserialize, Builder)]
+pub struct Breadcrumb {
+    title: String,
+    url: Url,
+}
+
+/// The trail of pages leading to the current one, the last breadcrumb is the current page.
+#[derive(Debug, Clone, Default, Getters, Serialize, Deserialize)]
+pub struct BreadcrumbTrail {
+    items: Vec<Breadcrumb>,
+}
+
+/// The titles of the pages that aren't content, given by the views in the language of the trail.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BreadcrumbLabels<'a> {
+    pub home: &'a str,
+    pub blog: &'a str,
+    pub projects: &'a str,
+}
+
+impl BreadcrumbTrail {
+    /// Home → Blog
+    pub fn for_blog(lang: &str, labels: &BreadcrumbLabels) -> Self {
+        Self::default()
+            .push(labels.home, format!("/{lang}"))
+            .push(labels.blog, format!("/{lang}/articles"))
+    }
+
+    /// Home → Blog → Category
+    pub fn for_category(lang: &str, labels: &BreadcrumbLabels, category: &Category) -> Self {
+        Self::for_blog(lang, labels).push(category.title().to_string(), category.slug().to_string())
+    }
+
+    /// Home → Blog → Category → Article
+    pub fn for_article(lang: &str, labels: &BreadcrumbLabels, article: &Article) -> Self {
+        Self::for_category(lang, labels, article.category())
+            .push(article.title().to_string(), article.slug().to_string())
+    }
+
+    /// Home → Projects → Project
+    pub fn for_project(lang: &str, labels: &BreadcrumbLabels, project: &Project) -> Self {
+        Self::default()
+            .push(labels.home, format!("/{lang}"))
+            .push(labels.projects, format!("/{lang}#portfolio"))
+            .push(project.title().to_string(), project.slug().to_string())
+    }
+
+    pub fn is_current(&self, index: usize) -> bool {
+        index + 1 == self.items.len()
+    }
+
+    fn push(mut self, title: impl Into<String>, url: String) -> Self {
+        if let Ok(url) = Url::try_from(url) {
+            self.items.push(Breadcrumb {
+                title: title.into(),
+                url,
+            });
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::application::domain::article::tests::{article_fixture, category_fixture};
+    use crate::application::domain::project::tests::project_fixture;
+
+    pub fn labels_fixture() -> BreadcrumbLabels<'static> {
+        BreadcrumbLabels {
+            home: "Home",
+            blog: "Blog",
+            projects: "Projects",
+        }
+    }
+
+    #[test]
+    fn trail_succeeds_when_article_is_given() {
+        let trail = BreadcrumbTrail::for_article("en", &labels_fixture(), &article_fixture());
+        let titles = trail.items().iter().map(|b| b.title().as_str()).collect::<Vec<_>>();
+        let urls = trail.items().iter().map(|b| b.url().as_str()).collect::<Vec<_>>();
+
+        assert_eq!(titles, vec!["Home", "Blog", "Example", "What is Lorem Ipsum?"]);
+        assert_eq!(urls, vec!["/en", "/en/articles", "/example", "/what-is-lorem-ipsum"]);
+        assert!(trail.is_current(3));
+        assert!(!trail.is_current(2));
+    }
+
+    #[test]
+    fn trail_succeeds_when_category_is_given() {
+        let trail = BreadcrumbTrail::for_category("en", &labels_fixture(), &category_fixture());
+
+        assert_eq!(trail.items().len(), 3);
+        assert_eq!(trail.items()[2].title(), "Example");
+    }
+
+    #[test]
+    fn trail_succeeds_when_project_is_given() {
+        let trail = BreadcrumbTrail::for_project("en", &labels_fixture(), &project_fixture());
+
+        assert_eq!(trail.items()[1].url().as_str(), "/en#portfolio");
+        assert_eq!(trail.items()[2].title(), project_fixture().title().as_str());
+    }
+}
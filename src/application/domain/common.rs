@@ -1,17 +1,10 @@
+use chrono::Datelike;
 use derive_builder::Builder;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 
-use crate::application::value_objects::{Identifier, RequiredText, Url};
-
-#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
-pub struct Project {
-    id: Identifier,
-    title: RequiredText,
-    text: String,
-    link: Link,
-    image: Image,
-}
+use crate::application::value_objects::{Date, Identifier, RequiredText, Url};
 
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
 pub struct Timeline {
@@ -22,10 +15,95 @@ pub struct Timeline {
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
 pub struct TimelineItem {
     id: Identifier,
-    date: RequiredText,
+    start_date: Date,
+    /// `None` when the item is still ongoing.
+    end_date: Option<Date>,
     title: RequiredText,
     subtitle: RequiredText,
     text: Option<String>,
+    /// The calendar months of the item, counted by `count_duration` when it's shown.
+    #[builder(default)]
+    #[serde(default)]
+    duration: u32,
+}
+
+impl Timeline {
+    /// Sorts the items from the most recent to the oldest, ongoing items come first.
+    pub fn sort_by_most_recent(&mut self) {
+        self.items.sort_by_key(|item| {
+            let (start, end) = item.month_span(None);
+            Reverse((end, start))
+        });
+    }
+
+    /// Counts the duration of every item, see `TimelineItem::count_duration`.
+    pub fn count_durations(&mut self, today: &Date) {
+        self.items.iter_mut().for_each(|item| item.count_duration(today));
+    }
+
+    /// Counts the months covered by the items, overlapping periods are only counted once.
+    ///
+    /// # Arguments
+    /// * `today` - The date ongoing items are considered to end at.
+    pub fn covered_months(&self, today: &Date) -> u32 {
+        let mut spans = self
+            .items
+            .iter()
+            .map(|item| item.month_span(Some(today)))
+            .collect::<Vec<_>>();
+        spans.sort();
+
+        let mut months = 0;
+        let mut current: Option<(i32, i32)> = None;
+
+        for (start, end) in spans {
+            current = match current {
+                Some((s, e)) if start <= e + 1 => Some((s, e.max(end))),
+                Some((s, e)) => {
+                    months += e - s + 1;
+                    Some((start, end))
+                }
+                None => Some((start, end)),
+            };
+        }
+
+        if let Some((s, e)) = current {
+            months += e - s + 1;
+        }
+
+        months.max(0) as u32
+    }
+}
+
+impl TimelineItem {
+    /// Counts the calendar months of the item, both the start and the end month included.
+    ///
+    /// # Arguments
+    /// * `today` - The date ongoing items are considered to end at.
+    pub fn duration_in_months(&self, today: &Date) -> u32 {
+        self.start_date.months_until(self.end_date.as_ref().unwrap_or(today))
+    }
+
+    /// Stores the duration of the item, so views don't depend on the day they're rendered.
+    ///
+    /// # Arguments
+    /// * `today` - The date ongoing items are considered to end at.
+    pub fn count_duration(&mut self, today: &Date) {
+        self.duration = self.duration_in_months(today);
+    }
+
+    /// Returns the start and end as month indexes, ongoing items end at `today`
+    /// or, when it's not given, after any other item.
+    fn month_span(&self, today: Option<&Date>) -> (i32, i32) {
+        let month_index = |date: &Date| date.year() * 12 + date.month0() as i32;
+        let end = match (&self.end_date, today) {
+            (Some(end), _) => month_index(end),
+            (None, Some(today)) => month_index(today),
+            (None, None) => i32::MAX,
+        };
+
+        (month_index(&self.start_date), end)
+    }
 }
 
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
@@ -76,6 +154,7 @@ pub struct MetaTags {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn creation_succeeds_when_valid_timeline() {
@@ -83,12 +162,6 @@ pub mod tests {
         timeline_fixture(); 
     }
 
-    #[test]
-    fn creation_succeeds_when_valid_project() {
-        // the fixture calls the builder methods, so we just ensure that it doesn't panic
-        project_fixture(); 
-    }
-
     #[test]
     fn creation_succeeds_when_valid_image() {
         // the fixture calls the builder methods, so we just ensure that it doesn't panic
@@ -127,19 +200,59 @@ pub mod tests {
     }
 
     #[test]
-    fn serialization_succeeds_when_valid_project() {
-        let p = project_fixture();
-        let serialized = serde_json::json!(&p).to_string();
-
-        assert!(serialized.contains(p.id().to_string().as_str()));
-        assert!(serialized.contains(p.title().to_string().as_str()));
-        assert!(serialized.contains(p.text().as_str()));
-        assert!(serialized.contains(p.link().url().as_str()));
-        assert!(serialized.contains(p.image().url().as_str()));
-        assert!(serialized.contains(p.image().alt().to_string().as_str()));
-        assert!(serialized.contains(p.image().title().to_string().as_str()));
-        assert!(serialized.contains(p.image().width().to_string().as_str()));
-        assert!(serialized.contains(p.image().height().to_string().as_str()));
+    fn sorting_succeeds_when_items_are_unordered() {
+        let mut t = TimelineBuilder::default()
+            .id("d4e5f6a7-8b9c-0d1e-2f3a-4b5c6d7e8f9a".try_into().unwrap())
+            .items(vec![
+                timeline_item_with_dates("2016-01-01", Some("2019-12-01")),
+                timeline_item_with_dates("2022-08-01", None),
+                timeline_item_with_dates("2020-10-01", Some("2022-06-01")),
+            ])
+            .build()
+            .unwrap();
+
+        t.sort_by_most_recent();
+
+        let starts = t
+            .items()
+            .iter()
+            .map(|i| i.start_date().to_string_with_format("%Y-%m"))
+            .collect::<Vec<_>>();
+        assert_eq!(starts, vec!["2022-08", "2020-10", "2016-01"]);
+    }
+
+    #[rstest]
+    #[case("2021-03-01", Some("2021-03-20"), 1)]
+    #[case("2021-03-01", Some("2022-02-01"), 12)]
+    #[case("2021-03-01", None, 44)]
+    fn duration_succeeds_when_counting_months(
+        #[case] start: &str,
+        #[case] end: Option<&str>,
+        #[case] expected: u32,
+    ) {
+        let today = Date::try_from("2024-10-19").unwrap();
+        let item = timeline_item_with_dates(start, end);
+
+        assert_eq!(item.duration_in_months(&today), expected);
+    }
+
+    #[test]
+    fn covered_months_succeeds_when_items_overlap() {
+        let today = Date::try_from("2024-12-15").unwrap();
+        let t = TimelineBuilder::default()
+            .id("d4e5f6a7-8b9c-0d1e-2f3a-4b5c6d7e8f9a".try_into().unwrap())
+            .items(vec![
+                // contiguous and overlapping items from 2018-04 to 2022-06 are 51 months
+                timeline_item_with_dates("2018-04-01", Some("2019-05-01")),
+                timeline_item_with_dates("2019-05-01", Some("2020-10-01")),
+                timeline_item_with_dates("2020-10-01", Some("2022-06-01")),
+                // after a one month gap, 2022-08 to 2024-12 are 29 months
+                timeline_item_with_dates("2022-08-01", None),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(t.covered_months(&today), 51 + 29);
     }
 
     #[test]
@@ -199,7 +312,7 @@ pub mod tests {
         assert!(serialized.contains(m.twitter_creator().as_str()));
     }
 
-    fn link_fixture() -> Link {
+    pub fn link_fixture() -> Link {
         LinkBuilder::default()
             .url("https://example.com".try_into().unwrap())
             .title("Example link".to_string())
@@ -229,17 +342,6 @@ pub mod tests {
             .unwrap()
     }
 
-    pub fn project_fixture() -> Project {
-        ProjectBuilder::default()
-            .id("c3d4e5f6-7a8b-9c0d-1e2f-3a4b5c6d7e8f".try_into().unwrap())
-            .title("Example project".try_into().unwrap())
-            .text("Example project description".to_string())
-            .link(link_fixture())
-            .image(image_fixture())
-            .build()
-            .unwrap()
-    }
-
     pub fn timeline_fixture() -> Timeline {
         TimelineBuilder::default()
             .id("d4e5f6a7-8b9c-0d1e-2f3a-4b5c6d7e8f9a".try_into().unwrap())
@@ -249,9 +351,14 @@ pub mod tests {
     }
 
     fn timeline_item_fixture() -> TimelineItem {
+        timeline_item_with_dates("2020-10-01", Some("2022-06-01"))
+    }
+
+    pub fn timeline_item_with_dates(start: &str, end: Option<&str>) -> TimelineItem {
         TimelineItemBuilder::default()
             .id("e5f6a7b8-9c0d-1e2f-3a4b-5c6d7e8f9a0b".try_into().unwrap())
-            .date("2024-12-15 14:03:56".try_into().unwrap())
+            .start_date(start.try_into().unwrap())
+            .end_date(end.map(|e| e.try_into().unwrap()))
             .title("Example title".try_into().unwrap())
             .subtitle("Example subtitle".try_into().unwrap())
             .text(Some("Example text".to_string()))
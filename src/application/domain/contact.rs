@@ -0,0 +1,94 @@
+use derive_builder::Builder;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::application::value_objects::{Email, RequiredText};
+
+const MAX_NAME_LENGTH: usize = 100;
+const MIN_MESSAGE_LENGTH: usize = 10;
+const MAX_MESSAGE_LENGTH: usize = 5000;
+
+#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ContactMessage {
+    name: RequiredText,
+    email: Email,
+    message: RequiredText,
+}
+
+impl ContactMessageBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(name) = &self.name {
+            if name.chars().count() > MAX_NAME_LENGTH {
+                return Err(format!("name must not exceed {MAX_NAME_LENGTH} characters"));
+            }
+
+            if name.chars().any(char::is_control) {
+                return Err("name must not contain control characters".to_string());
+            }
+        }
+
+        if let Some(message) = &self.message {
+            let length = message.trim().chars().count();
+            if !(MIN_MESSAGE_LENGTH..=MAX_MESSAGE_LENGTH).contains(&length) {
+                return Err(format!(
+                    "message must contain between {MIN_MESSAGE_LENGTH} and {MAX_MESSAGE_LENGTH} characters"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn creation_succeeds_when_valid_contact_message() {
+        // the fixture calls the builder methods, so we just ensure that it doesn't panic
+        contact_message_fixture();
+    }
+
+    #[test]
+    fn creation_fails_when_message_is_too_short() {
+        let result = ContactMessageBuilder::default()
+            .name("John Doe".try_into().unwrap())
+            .email("john@example.com".try_into().unwrap())
+            .message("Hi!".try_into().unwrap())
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn creation_fails_when_name_is_too_long() {
+        let result = ContactMessageBuilder::default()
+            .name("a".repeat(MAX_NAME_LENGTH + 1).try_into().unwrap())
+            .email("john@example.com".try_into().unwrap())
+            .message("Hello, I would like to talk about a project.".try_into().unwrap())
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialization_succeeds_when_valid_contact_message() {
+        let m = contact_message_fixture();
+        let serialized = serde_json::json!(&m).to_string();
+
+        assert!(serialized.contains(m.name().as_str()));
+        assert!(serialized.contains(m.email().as_str()));
+        assert!(serialized.contains(m.message().as_str()));
+    }
+
+    pub fn contact_message_fixture() -> ContactMessage {
+        ContactMessageBuilder::default()
+            .name("John Doe".try_into().unwrap())
+            .email("john@example.com".try_into().unwrap())
+            .message("Hello, I would like to talk about a project.".try_into().unwrap())
+            .build()
+            .unwrap()
+    }
+}
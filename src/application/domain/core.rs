@@ -1,4 +1,4 @@
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, Clone)]
 pub enum AppError {
     #[error("Failed to authenticate `{0}`")]
     Unauthorized(String),
@@ -6,6 +6,9 @@ pub enum AppError {
     #[error("Not found resource `{0}`")]
     ResourceNotFound(String),
 
+    #[error("The resource has moved to `{0}` with status `{1}`")]
+    Moved(String, u16),
+
     #[error("The external layer `{0}` has failed with `{1}`")]
     External(&'static str, String),
 
@@ -15,11 +18,15 @@ pub enum AppError {
     #[error("Unexpected error `{0}`")]
     Unexpected(String),
 
+    #[error("Too many requests from `{0}`")]
+    TooManyRequests(String),
+
     #[error("The value `{0}` is not valid for `{1}`")]
     InvalidValue(&'static str, String),
 
+    /// The status and body of a response the upstream answered with an error.
     #[error("")]
-    ApiFailure(String),
+    ApiFailure(u16, String),
 }
 
 pub type Result<T> = core::result::Result<T, AppError>;
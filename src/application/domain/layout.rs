@@ -3,17 +3,26 @@ use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
 use crate::application::domain::common::Image;
-use crate::application::value_objects::{Identifier, Url};
+use crate::application::value_objects::{Identifier, RequiredText, Url};
 
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
 pub struct Layout {
     logo: Option<Image>,
+    site_name: Option<RequiredText>,
+    slogan: Option<String>,
     main_menu: MenuTree,
     social_menu: MenuTree,
     sidebar_menu: Option<MenuTree>,
     footer_menu: Option<MenuTree>,
 }
 
+#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
+pub struct SiteBranding {
+    name: RequiredText,
+    slogan: Option<String>,
+    logo: Option<Image>,
+}
+
 #[derive(Serialize, Deserialize, Builder, Getters, Clone, Debug)]
 pub struct MenuTree {
     items: Vec<MenuItem>,
@@ -27,6 +36,15 @@ pub struct MenuItem {
     hidden: bool,
     weight: isize,
     icon: Option<Image>,
+    external: bool,
+    expanded: bool,
+    children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn has_children(&self) -> bool {
+        self.children.iter().any(|c| !c.hidden)
+    }
 }
 
 #[cfg(test)]
@@ -71,6 +89,48 @@ pub mod tests {
         assert!(deserialized.icon().is_some());
     }
 
+    #[test]
+    fn deserialization_succeeds_when_menu_item_has_children() {
+        let m = menu_item_with_children_fixture();
+        let serialized = serde_json::to_string(&m).unwrap();
+        let deserialized: MenuItem = serde_json::from_str(&serialized).unwrap();
+
+        assert!(deserialized.has_children());
+        assert_eq!(deserialized.children()[0].id(), m.children()[0].id());
+        assert_eq!(deserialized.children()[0].title(), "Blog");
+    }
+
+    #[test]
+    fn has_children_fails_when_every_child_is_hidden() {
+        assert!(!menu_item_fixture().has_children());
+    }
+
+    #[test]
+    fn serialization_succeeds_when_valid_site_branding() {
+        let b = site_branding_fixture();
+        let serialized = serde_json::to_string(&b).unwrap();
+
+        assert!(serialized.contains(b.name().as_str()));
+        assert!(serialized.contains(b.slogan().clone().unwrap().as_str()));
+        assert!(serialized.contains(b.logo().clone().unwrap().url().as_str()));
+    }
+
+    pub fn site_branding_fixture() -> SiteBranding {
+        SiteBrandingBuilder::default()
+            .name("Tiagocode".try_into().unwrap())
+            .slogan(Some("Software engineering notes".to_string()))
+            .logo(Some(image_fixture()))
+            .build()
+            .unwrap()
+    }
+
+    pub fn menu_tree_fixture() -> MenuTree {
+        MenuTreeBuilder::default()
+            .items(vec![menu_item_fixture()])
+            .build()
+            .unwrap()
+    }
+
     pub fn menu_item_fixture() -> MenuItem {
         MenuItemBuilder::default()
             .id("413b8ba1-2bc4-4fda-8455-0c0dea528ca0".try_into().unwrap())
@@ -79,6 +139,37 @@ pub mod tests {
             .hidden(false)
             .weight(0)
             .icon(Some(image_fixture()))
+            .external(true)
+            .expanded(false)
+            .children(vec![])
+            .build()
+            .unwrap()
+    }
+
+    pub fn menu_item_with_children_fixture() -> MenuItem {
+        let child = MenuItemBuilder::default()
+            .id("513b8ba1-2bc4-4fda-8455-0c0dea528ca1".try_into().unwrap())
+            .url("/en/blog".try_into().unwrap())
+            .title("Blog".into())
+            .hidden(false)
+            .weight(0)
+            .icon(None)
+            .external(false)
+            .expanded(false)
+            .children(vec![])
+            .build()
+            .unwrap();
+
+        MenuItemBuilder::default()
+            .id("513b8ba1-2bc4-4fda-8455-0c0dea528ca0".try_into().unwrap())
+            .url("/en".try_into().unwrap())
+            .title("Home".into())
+            .hidden(false)
+            .weight(0)
+            .icon(None)
+            .external(false)
+            .expanded(true)
+            .children(vec![child])
             .build()
             .unwrap()
     }
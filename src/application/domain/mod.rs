@@ -1,6 +1,9 @@
 pub mod article;
+pub mod breadcrumb;
 pub mod common;
+pub mod contact;
 pub mod core;
 pub mod layout;
 pub mod portfolio;
-pub mod page;
\ No newline at end of file
+pub mod page;
+pub mod project;
\ No newline at end of file
@@ -3,11 +3,12 @@ use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
 use crate::application::domain::common::MetaTags;
-use crate::application::value_objects::{Date, Identifier, ModerationStatus, RequiredText};
+use crate::application::value_objects::{Date, Identifier, ModerationStatus, RequiredText, Url};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Getters, Builder)]
 pub struct Page {
     id: Identifier,
+    slug: Url,
     title: RequiredText,
     created_at: Date,
     status: ModerationStatus,
@@ -39,6 +40,7 @@ pub mod tests {
     pub fn page_fixture() -> Page {
         PageBuilder::default()
             .id(Identifier::try_from("413b8ba1-2bc4-4fda-8455-0c0dea528ca0").unwrap())
+            .slug(Url::try_from("/en/articles").unwrap())
             .title(RequiredText::try_from("Test Page").unwrap())
             .metatags(metatags_fixture())
             .status(ModerationStatus::Published)
@@ -2,8 +2,9 @@ use derive_builder::Builder;
 use derive_getters::Getters;
 use serde::{Deserialize, Serialize};
 
-use crate::application::domain::common::{MetaTags, Document, Image, Project, Timeline};
+use crate::application::domain::common::{MetaTags, Document, Image, Timeline};
 use crate::application::domain::article::Articles;
+use crate::application::domain::project::Project;
 use crate::application::value_objects::{Date, Identifier, ModerationStatus, RequiredText};
 
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
@@ -28,6 +29,7 @@ pub enum PortfolioSection {
     Resume(Resume),
     Projects(Projects),
     Blogs(Blogs),
+    Contact(Contact),
     Unknown,
 }
 
@@ -62,6 +64,21 @@ pub struct Projects {
     projects: Vec<Project>,
 }
 
+impl Projects {
+    /// Lists the technologies used across the projects, sorted and without duplicates.
+    pub fn technologies(&self) -> Vec<String> {
+        let mut technologies = self
+            .projects
+            .iter()
+            .flat_map(|p| p.technologies().iter().map(|t| t.trim().to_string()))
+            .collect::<Vec<_>>();
+
+        technologies.sort_by_key(|t| t.to_lowercase());
+        technologies.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        technologies
+    }
+}
+
 #[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
 pub struct Blogs {
     id: Identifier,
@@ -71,13 +88,22 @@ pub struct Blogs {
     articles: Articles,
 }
 
+#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
+pub struct Contact {
+    id: Identifier,
+    title: RequiredText,
+    subtitle: RequiredText,
+    text: String,
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use crate::application::domain::article::tests::article_fixture;
     use crate::application::domain::common::tests::{
-        document_fixture, image_fixture, project_fixture, metatags_fixture, timeline_fixture,
+        document_fixture, image_fixture, metatags_fixture, timeline_fixture,
     };
+    use crate::application::domain::project::tests::{project_fixture, project_with_technologies};
 
 
     #[test]
@@ -110,6 +136,12 @@ pub mod tests {
         blogs_fixture(); 
     }
 
+    #[test]
+    fn creation_succeeds_when_valid_contact_section() {
+        // the fixture calls the builder methods, so we just ensure that it doesn't panic
+        contact_fixture(); 
+    }
+
     #[test]
     fn serialization_succeeds_when_valid_portfolio() {
         let p = portfolio_fixture();
@@ -162,6 +194,7 @@ pub mod tests {
             PortfolioSection::AboutMe(about_me_fixture()),
             PortfolioSection::Projects(projects_fixture()),
             PortfolioSection::Blogs(blogs_fixture()),
+            PortfolioSection::Contact(contact_fixture()),
         ]
     }
 
@@ -213,8 +246,8 @@ pub mod tests {
         assert!(serialized.contains(first_project.image().alt().as_str()));
         assert!(serialized.contains(first_project.image().title().as_str()));
         assert!(serialized.contains(first_project.image().width().to_string().as_str()));
-        assert!(serialized.contains(first_project.link().url().as_str()));
-        assert!(serialized.contains(first_project.link().title().as_str()));
+        assert!(serialized.contains(first_project.link().as_ref().unwrap().url().as_str()));
+        assert!(serialized.contains(first_project.link().as_ref().unwrap().title().as_str()));
     }
 
     #[test]
@@ -287,6 +320,23 @@ pub mod tests {
         assert_eq!(p.projects().len(), deserialized.projects().len());
     }
 
+    #[test]
+    fn technologies_are_unique_when_projects_share_them() {
+        let p = ProjectsBuilder::default()
+            .id("633b8ba1-2bc4-4fda-8455-0c0dea528ca2".try_into().unwrap())
+            .title("Example title".try_into().unwrap())
+            .subtitle("Example subtitle".try_into().unwrap())
+            .text("Example text".try_into().unwrap())
+            .projects(vec![
+                project_with_technologies(vec!["Rust", "Leptos"]),
+                project_with_technologies(vec!["rust", "Drupal"]),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(p.technologies(), vec!["Drupal", "Leptos", "Rust"]);
+    }
+
     #[test]
     fn deserialization_succeeds_for_blogs() {
         let b = blogs_fixture();
@@ -347,4 +397,14 @@ pub mod tests {
             .build()
             .unwrap()
     }
+
+    pub fn contact_fixture() -> Contact {
+        ContactBuilder::default()
+            .id("853b8ba1-2bc4-4fda-8455-0c0dea528ca4".try_into().unwrap())
+            .title("Example title".try_into().unwrap())
+            .subtitle("Example subtitle".try_into().unwrap())
+            .text("Example text".to_string())
+            .build()
+            .unwrap()
+    }
 }
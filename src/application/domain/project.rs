@@ -0,0 +1,163 @@
+use derive_builder::Builder;
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::application::domain::common::{Image, Link, MetaTags};
+use crate::application::value_objects::{Date, Identifier, ModerationStatus, RequiredText, Url};
+
+#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
+pub struct Project {
+    id: Identifier,
+    slug: Url,
+    title: RequiredText,
+    text: String,
+    body: String,
+    status: ModerationStatus,
+    role: Option<String>,
+    technologies: Vec<String>,
+    start_date: Date,
+    /// `None` when the project is still ongoing.
+    end_date: Option<Date>,
+    link: Option<Link>,
+    image: Image,
+    gallery: Gallery,
+    metatags: MetaTags,
+    /// The calendar months of the project, counted by `count_duration` when it's shown.
+    #[builder(default)]
+    #[serde(default)]
+    duration: u32,
+}
+
+#[derive(Debug, Clone, Getters, Serialize, Deserialize, Builder)]
+pub struct Gallery {
+    thumbnails: Vec<Image>,
+    images: Vec<Image>,
+}
+
+impl Project {
+    /// Whether the project was built with the given technology, the comparison ignores case.
+    pub fn uses(&self, technology: &str) -> bool {
+        self.technologies
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(technology.trim()))
+    }
+
+    /// Stores the duration of the project, both the start and the end month included.
+    ///
+    /// # Arguments
+    /// * `today` - The date ongoing projects are considered to end at.
+    pub fn count_duration(&mut self, today: &Date) {
+        self.duration = self.start_date.months_until(self.end_date.as_ref().unwrap_or(today));
+    }
+}
+
+impl Gallery {
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::application::domain::common::tests::{image_fixture, link_fixture, metatags_fixture};
+    use rstest::rstest;
+
+    #[test]
+    fn creation_succeeds_when_valid_project() {
+        // the fixture calls the builder methods, so we just ensure that it doesn't panic
+        project_fixture();
+    }
+
+    #[test]
+    fn serialization_succeeds_when_valid_project() {
+        let p = project_fixture();
+        let serialized = serde_json::json!(&p).to_string();
+
+        assert!(serialized.contains(p.id().to_string().as_str()));
+        assert!(serialized.contains(p.slug().as_str()));
+        assert!(serialized.contains(p.title().to_string().as_str()));
+        assert!(serialized.contains(p.text().as_str()));
+        assert!(serialized.contains(p.role().clone().unwrap().as_str()));
+        assert!(serialized.contains(p.technologies()[0].as_str()));
+        assert!(serialized.contains(p.link().clone().unwrap().url().as_str()));
+        assert!(serialized.contains(p.image().url().as_str()));
+        assert!(serialized.contains(p.gallery().images()[0].url().as_str()));
+    }
+
+    #[test]
+    fn deserialization_succeeds_when_valid_project() {
+        let p = project_fixture();
+        let serialized = serde_json::json!(&p).to_string();
+        let deserialized: Project = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(p.id(), deserialized.id());
+        assert_eq!(p.slug(), deserialized.slug());
+        assert_eq!(p.title(), deserialized.title());
+        assert_eq!(p.technologies(), deserialized.technologies());
+        assert_eq!(p.start_date(), deserialized.start_date());
+        assert_eq!(p.end_date(), deserialized.end_date());
+        assert_eq!(p.gallery().images().len(), deserialized.gallery().images().len());
+    }
+
+    #[rstest]
+    #[case("Rust", true)]
+    #[case("rust", true)]
+    #[case(" Leptos ", true)]
+    #[case("PHP", false)]
+    fn uses_succeeds_when_matching_technologies(#[case] technology: &str, #[case] expected: bool) {
+        assert_eq!(project_fixture().uses(technology), expected);
+    }
+
+    pub fn project_fixture() -> Project {
+        project_with_technologies(vec!["Rust", "Leptos"])
+    }
+
+    pub fn unpublished_project_fixture() -> Project {
+        ProjectBuilder::default()
+            .id("c3d4e5f6-7a8b-9c0d-1e2f-3a4b5c6d7e8f".try_into().unwrap())
+            .slug("/en/projects/example-draft-project".try_into().unwrap())
+            .title("Example draft project".try_into().unwrap())
+            .text("Example project description".to_string())
+            .body("<p>Example project body</p>".to_string())
+            .status(ModerationStatus::Unpublished)
+            .role(None)
+            .technologies(vec![])
+            .start_date("2024-01-01".try_into().unwrap())
+            .end_date(None)
+            .link(None)
+            .image(image_fixture())
+            .gallery(gallery_fixture())
+            .metatags(metatags_fixture())
+            .build()
+            .unwrap()
+    }
+
+    pub fn project_with_technologies(technologies: Vec<&str>) -> Project {
+        ProjectBuilder::default()
+            .id("c3d4e5f6-7a8b-9c0d-1e2f-3a4b5c6d7e8f".try_into().unwrap())
+            .slug("/en/projects/example-project".try_into().unwrap())
+            .title("Example project".try_into().unwrap())
+            .text("Example project description".to_string())
+            .body("<p>Example project body</p>".to_string())
+            .status(ModerationStatus::Published)
+            .role(Some("Lead developer".to_string()))
+            .technologies(technologies.into_iter().map(String::from).collect())
+            .start_date("2023-01-01".try_into().unwrap())
+            .end_date(Some("2023-09-01".try_into().unwrap()))
+            .link(Some(link_fixture()))
+            .image(image_fixture())
+            .gallery(gallery_fixture())
+            .metatags(metatags_fixture())
+            .build()
+            .unwrap()
+    }
+
+    pub fn gallery_fixture() -> Gallery {
+        GalleryBuilder::default()
+            .thumbnails(vec![image_fixture()])
+            .images(vec![image_fixture()])
+            .build()
+            .unwrap()
+    }
+}
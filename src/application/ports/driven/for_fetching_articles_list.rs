@@ -15,4 +15,8 @@ pub trait ForFetchingArticlesList {
     /// Returns a collection of articles sorted by recency, typically used
     /// for displaying latest content in feeds or home pages.
     async fn get_list(&self, category_id: Option<String>) -> Result<Vec<Article>>;
+
+    /// Retrieves every published article, going through all the pages of the data source,
+    /// typically used for exporting the site.
+    async fn get_all(&self) -> Result<Vec<Article>>;
 }
@@ -12,4 +12,7 @@ use crate::application::domain::core::Result;
 pub trait ForFetchingPageData {
     /// Retrieves the article from the data source.
     async fn find_by_slug(&self, slug: &str) -> Result<Page>;
+
+    /// Retrieves every published page from the data source.
+    async fn get_list(&self) -> Result<Vec<Page>>;
 }
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::application::domain::core::Result;
+use crate::application::domain::project::Project;
+
+/// Output port for retrieving projects data.
+///
+/// This trait defines the contract for fetching project entities based on different parameters.
+/// Implementations of this trait should handle data access, whether from a database, API,
+/// or other storage mechanisms.
+#[async_trait(?Send)]
+pub trait ForFetchingProjects {
+    /// Retrieves all the projects from the data source.
+    ///
+    /// Returns a collection of projects sorted by recency, typically used
+    /// for displaying them in the portfolio.
+    async fn get_list(&self) -> Result<Vec<Project>>;
+
+    /// Retrieves the project from the data source.
+    async fn find_by_slug(&self, slug: &str) -> Result<Project>;
+}
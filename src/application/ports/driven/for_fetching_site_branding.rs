@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::application::domain::core::Result;
+use crate::application::domain::layout::SiteBranding;
+
+/// Output port for retrieving the site branding (name, slogan and logo).
+///
+/// Implementations of this trait should handle data access from external sources such as
+/// databases, APIs, or other storage mechanisms.
+#[async_trait(?Send)]
+pub trait ForFetchingSiteBranding {
+    /// Retrieves the branding configured for the site.
+    ///
+    /// # Returns
+    /// - `Result<SiteBranding>`: The site branding, or an error if retrieval fails
+    async fn get_branding(&self) -> Result<SiteBranding>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::Result;
+
+/// Output port for delivering contact messages.
+///
+/// This trait defines the contract for sending the messages submitted through the
+/// contact form. Implementations of this trait should handle the delivery mechanism,
+/// whether an SMTP server, a local outbox, or any other transport.
+#[async_trait(?Send)]
+pub trait ForSendingMessages {
+    /// Delivers a contact message.
+    ///
+    /// # Arguments
+    /// * `message` - The validated `ContactMessage` to be delivered.
+    ///
+    /// # Returns
+    /// * `AppError<()>` - An empty result if the message was delivered, or an error
+    ///   if the transport could not deliver it.
+    async fn send(&self, message: &ContactMessage) -> Result<()>;
+}
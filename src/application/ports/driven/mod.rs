@@ -5,6 +5,9 @@ mod for_fetching_categories_list;
 mod for_fetching_menu_data;
 mod for_fetching_portfolio_data;
 mod for_fetching_page_data;
+mod for_fetching_projects;
+mod for_fetching_site_branding;
+mod for_sending_messages;
 
 pub use for_fetching_article_data::*;
 pub use for_fetching_articles_featured_list::*;
@@ -12,4 +15,7 @@ pub use for_fetching_articles_list::*;
 pub use for_fetching_categories_list::*;
 pub use for_fetching_menu_data::*;
 pub use for_fetching_portfolio_data::*;
-pub use for_fetching_page_data::*;
\ No newline at end of file
+pub use for_fetching_page_data::*;
+pub use for_fetching_projects::*;
+pub use for_fetching_site_branding::*;
+pub use for_sending_messages::*;
\ No newline at end of file
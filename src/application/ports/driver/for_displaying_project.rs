@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::application::domain::core::Result;
+use crate::application::domain::project::Project;
+
+/// Use case for displaying project detail.
+///
+/// This input port defines the application logic for fetching the project details.
+/// It acts as a boundary between the web UI (controller) and the application core,
+/// allowing the web UI to obtain the project data for rendering.
+#[async_trait(?Send)]
+pub trait ForDisplayingProject {
+    /// Retrieves the project full information.
+    ///
+    /// # Returns
+    /// * `Result<Project>` - A result wrapping the `Project` if found,
+    /// or an error if the project could not be retrieved.
+    async fn execute(&self, slug: &str) -> Result<Project>;
+}
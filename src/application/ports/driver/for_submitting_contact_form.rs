@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::Result;
+
+/// Input port for submitting the contact form.
+///
+/// It defines the application logic for handling a contact message, and
+/// acts as a boundary between the web UI (controller) and the application core,
+/// allowing the web UI to deliver the messages written by visitors.
+#[async_trait(?Send)]
+pub trait ForSubmittingContactForm {
+    /// Submits a contact message.
+    ///
+    /// # Arguments
+    /// * `message` - The validated `ContactMessage` written by the visitor.
+    ///
+    /// # Returns
+    /// * `AppError<()>` - An empty result if the message was submitted, or an error
+    ///   if it could not be delivered.
+    async fn execute(&self, message: ContactMessage) -> Result<()>;
+}
@@ -2,8 +2,12 @@ mod for_displaying_article;
 mod for_displaying_articles_list;
 mod for_displaying_layout;
 mod for_displaying_portfolio;
+mod for_displaying_project;
+mod for_submitting_contact_form;
 
 pub use for_displaying_article::*;
 pub use for_displaying_articles_list::*;
 pub use for_displaying_layout::*;
 pub use for_displaying_portfolio::*;
+pub use for_displaying_project::*;
+pub use for_submitting_contact_form::*;
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
+use futures::join;
+use tracing::{instrument, warn};
 
 use crate::application::domain::core::{AppError, Result};
 use crate::application::domain::layout::{Layout, LayoutBuilder};
-use crate::application::ports::driven::ForFetchingMenuData;
+use crate::application::ports::driven::{ForFetchingMenuData, ForFetchingSiteBranding};
 use crate::application::ports::driver::ForDisplayingLayout;
 
 /// Service for retrieving layout data
@@ -12,34 +14,150 @@ use crate::application::ports::driver::ForDisplayingLayout;
 ///
 pub struct GetLayoutUseCase {
     repository: Box<(dyn ForFetchingMenuData)>,
+    branding_repository: Box<dyn ForFetchingSiteBranding>,
 }
 
 impl GetLayoutUseCase {
-    /// Creates a new GetLayoutUseCase with the given repository
+    /// Creates a new GetLayoutUseCase with the given repositories
     ///
     /// # Arguments
     /// * `repository` - A boxed trait object implementing LoadMenuPort
+    /// * `branding_repository` - A boxed trait object implementing ForFetchingSiteBranding
     ///
-    pub fn new(repository: Box<(dyn ForFetchingMenuData)>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Box<(dyn ForFetchingMenuData)>,
+        branding_repository: Box<dyn ForFetchingSiteBranding>,
+    ) -> Self {
+        Self {
+            repository,
+            branding_repository,
+        }
     }
 }
 
 #[async_trait(?Send)]
 impl ForDisplayingLayout for GetLayoutUseCase {
+    #[instrument(name = "GetLayoutUseCase::execute", skip_all)]
     async fn execute(&self) -> Result<Layout> {
-        let main_menu = self.repository.find_by_id("main").await?;
-        let social_menu = self.repository.find_by_id("social-network").await?;
+        let (main_menu, social_menu, footer_menu, sidebar_menu, branding) = join!(
+            self.repository.find_by_id("main"),
+            self.repository.find_by_id("social-network"),
+            self.repository.find_by_id("footer"),
+            self.repository.find_by_id("sidebar"),
+            self.branding_repository.get_branding(),
+        );
+        let (main_menu, social_menu) = (main_menu?, social_menu?);
+
+        // the footer, the sidebar and the branding are optional, the site falls back
+        // to its defaults when editors haven't configured them or they can't be fetched
+        let footer_menu = optional("footer menu", footer_menu);
+        let sidebar_menu = optional("sidebar menu", sidebar_menu);
+        let branding = optional("site branding", branding);
 
         let layout = LayoutBuilder::default()
-            .logo(None)
+            .logo(branding.as_ref().and_then(|b| b.logo().clone()))
+            .site_name(branding.as_ref().map(|b| b.name().clone()))
+            .slogan(branding.as_ref().and_then(|b| b.slogan().clone()))
             .main_menu(main_menu)
             .social_menu(social_menu)
-            .sidebar_menu(None)
-            .footer_menu(None)
+            .sidebar_menu(sidebar_menu)
+            .footer_menu(footer_menu)
             .build()
             .map_err(|e| AppError::Unexpected(e.to_string()))?;
 
         Ok(layout)
     }
 }
+
+/// The value of an optional part of the layout, logging why it's missing when it failed.
+fn optional<T>(part: &str, result: Result<T>) -> Option<T> {
+    result
+        .inspect_err(|e| warn!("The layout goes without the {part}: {e}"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::layout::tests::{menu_tree_fixture, site_branding_fixture};
+    use crate::application::domain::layout::{MenuTree, SiteBranding};
+
+    struct MenuRepositoryMock {
+        available: Vec<&'static str>,
+    }
+
+    struct BrandingRepositoryMock {
+        fixture: Option<SiteBranding>,
+    }
+
+    #[async_trait(?Send)]
+    impl ForFetchingMenuData for MenuRepositoryMock {
+        async fn find_by_id(&self, id: &str) -> Result<MenuTree> {
+            match self.available.contains(&id) {
+                true => Ok(menu_tree_fixture()),
+                false => Err(AppError::ResourceNotFound(id.to_string())),
+            }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl ForFetchingSiteBranding for BrandingRepositoryMock {
+        async fn get_branding(&self) -> Result<SiteBranding> {
+            self.fixture
+                .clone()
+                .ok_or(AppError::ResourceNotFound("site_branding".to_string()))
+        }
+    }
+
+    #[actix_rt::test]
+    async fn executor_succeeds_when_every_menu_is_configured() {
+        let menus = MenuRepositoryMock {
+            available: vec!["main", "social-network", "footer", "sidebar"],
+        };
+        let branding = BrandingRepositoryMock {
+            fixture: Some(site_branding_fixture()),
+        };
+
+        let layout = GetLayoutUseCase::new(Box::new(menus), Box::new(branding))
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(layout.footer_menu().is_some());
+        assert!(layout.sidebar_menu().is_some());
+        assert!(layout.logo().is_some());
+        assert_eq!(layout.site_name().clone().unwrap().as_str(), "Tiagocode");
+    }
+
+    #[actix_rt::test]
+    async fn executor_succeeds_when_optional_content_is_missing() {
+        let menus = MenuRepositoryMock {
+            available: vec!["main", "social-network"],
+        };
+        let branding = BrandingRepositoryMock { fixture: None };
+
+        let layout = GetLayoutUseCase::new(Box::new(menus), Box::new(branding))
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(layout.footer_menu().is_none());
+        assert!(layout.sidebar_menu().is_none());
+        assert!(layout.logo().is_none());
+        assert!(layout.site_name().is_none());
+    }
+
+    #[actix_rt::test]
+    async fn executor_fails_when_main_menu_is_missing() {
+        let menus = MenuRepositoryMock {
+            available: vec!["social-network"],
+        };
+        let branding = BrandingRepositoryMock { fixture: None };
+
+        let result = GetLayoutUseCase::new(Box::new(menus), Box::new(branding))
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -2,8 +2,12 @@ mod get_layout_use_case;
 mod show_article_detail_use_case;
 mod show_articles_list_use_case;
 mod show_portfolio_detail_use_case;
+mod show_project_detail_use_case;
+mod send_contact_message_use_case;
 
 pub use get_layout_use_case::*;
 pub use show_article_detail_use_case::*;
 pub use show_articles_list_use_case::*;
 pub use show_portfolio_detail_use_case::*;
+pub use show_project_detail_use_case::*;
+pub use send_contact_message_use_case::*;
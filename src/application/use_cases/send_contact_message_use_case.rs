@@ -0,0 +1,85 @@
+use crate::application::domain::contact::ContactMessage;
+use crate::application::domain::core::Result;
+use crate::application::ports::driven::ForSendingMessages;
+use crate::application::ports::driver::ForSubmittingContactForm;
+use async_trait::async_trait;
+use tracing::instrument;
+
+/// Service for submitting contact messages
+///
+/// This service implements the ForSubmittingContactForm interface and uses a sender
+/// that implements ForSendingMessages to deliver the messages written by visitors.
+pub struct SendContactMessageUseCase {
+    sender: Box<dyn ForSendingMessages>,
+}
+
+impl SendContactMessageUseCase {
+    pub fn new(sender: Box<dyn ForSendingMessages>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForSubmittingContactForm for SendContactMessageUseCase {
+    #[instrument(name = "SendContactMessageUseCase::execute", skip_all)]
+    async fn execute(&self, message: ContactMessage) -> Result<()> {
+        self.sender.send(&message).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::domain::contact::tests::contact_message_fixture;
+    use crate::application::domain::core::AppError;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct MessageSenderMock {
+        outbox: Rc<RefCell<Vec<ContactMessage>>>,
+    }
+
+    struct FailingMessageSenderMock;
+
+    impl MessageSenderMock {
+        pub fn with_outbox(outbox: Rc<RefCell<Vec<ContactMessage>>>) -> Self {
+            Self { outbox }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl ForSendingMessages for MessageSenderMock {
+        async fn send(&self, message: &ContactMessage) -> Result<()> {
+            self.outbox.borrow_mut().push(message.clone());
+            Ok(())
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl ForSendingMessages for FailingMessageSenderMock {
+        async fn send(&self, _message: &ContactMessage) -> Result<()> {
+            Err(AppError::External("FailingMessageSenderMock", "Connection refused".to_string()))
+        }
+    }
+
+    #[actix_rt::test]
+    async fn executor_succeeds_when_message_is_delivered() {
+        let fixture = contact_message_fixture();
+        let outbox = Rc::new(RefCell::new(vec![]));
+        let sender_mock = Box::new(MessageSenderMock::with_outbox(outbox.clone()));
+
+        let use_case = SendContactMessageUseCase::new(sender_mock);
+        use_case.execute(fixture.clone()).await.unwrap();
+
+        assert_eq!(outbox.borrow().len(), 1);
+        assert_eq!(outbox.borrow()[0].email(), fixture.email());
+    }
+
+    #[actix_rt::test]
+    async fn executor_fails_when_message_cannot_be_delivered() {
+        let use_case = SendContactMessageUseCase::new(Box::new(FailingMessageSenderMock));
+        let result = use_case.execute(contact_message_fixture()).await;
+
+        assert!(matches!(result, Err(AppError::External(_, _))));
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use tracing::instrument;
 
 use crate::application::domain::article::Article;
 use crate::application::domain::core::{AppError, Result};
@@ -23,6 +24,7 @@ impl ShowArticleDetailUseCase {
 
 #[async_trait(?Send)]
 impl ForDisplayingArticle for ShowArticleDetailUseCase {
+    #[instrument(name = "ShowArticleDetailUseCase::execute", skip(self))]
     async fn execute(&self, slug: &str) -> Result<Article> {
         let article = self.article_repository.find_by_slug(slug).await?;
 
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use tracing::instrument;
 
 use crate::application::domain::page::Page;
 use crate::application::domain::article::{Article, Category};
@@ -34,6 +35,7 @@ impl ShowArticlesListUseCase {
 
 #[async_trait(?Send)]
 impl ForDisplayingArticlesList for ShowArticlesListUseCase {
+    #[instrument(name = "ShowArticlesListUseCase::execute", skip(self))]
     async fn execute(&self, slug: &str, category_id: Option<String>) -> Result<(Page, Vec<Category>, Vec<Article>)> {
         let page = self.page_repository.find_by_slug(slug).await?;
 
@@ -97,6 +99,10 @@ mod tests {
         async fn find_by_slug(&self, slug: &str) -> Result<Page> {
             Ok(self.fixture.clone())
         }
+
+        async fn get_list(&self) -> Result<Vec<Page>> {
+            Ok(vec![self.fixture.clone()])
+        }
     }
 
     #[async_trait(?Send)]
@@ -104,6 +110,10 @@ mod tests {
         async fn get_list(&self, _category_id: Option<String>) -> Result<Vec<Article>> {
             Ok(self.fixture.clone())
         }
+
+        async fn get_all(&self) -> Result<Vec<Article>> {
+            Ok(self.fixture.clone())
+        }
     }
 
     #[async_trait(?Send)]
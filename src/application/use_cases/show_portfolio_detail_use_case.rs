@@ -1,9 +1,12 @@
 use crate::application::domain::core::{AppError, Result};
-use crate::application::domain::portfolio::{Portfolio, BlogsBuilder, PortfolioSection};
-use crate::application::ports::driven::{ForFetchingArticlesFeatured, ForFetchingPortfolioData};
+use crate::application::domain::portfolio::{AboutMeBuilder, ResumeBuilder};
+use crate::application::domain::portfolio::{Portfolio, BlogsBuilder, PortfolioSection, ProjectsBuilder};
+use crate::application::ports::driven::{ForFetchingArticlesFeatured, ForFetchingPortfolioData, ForFetchingProjects};
 use crate::application::ports::driver::ForDisplayingPortfolio;
-use crate::application::value_objects::ModerationStatus;
+use crate::application::value_objects::{Date, ModerationStatus};
 use async_trait::async_trait;
+use tracing::{instrument, warn};
+use chrono::Utc;
 
 /// Service for retrieving portfolio data
 ///
@@ -12,22 +15,26 @@ use async_trait::async_trait;
 pub struct ShowPortfolioDetailUseCase {
     portfolio_repository: Box<dyn ForFetchingPortfolioData>,
     articles_repository: Box<dyn ForFetchingArticlesFeatured>,
+    projects_repository: Box<dyn ForFetchingProjects>,
 }
 
 impl ShowPortfolioDetailUseCase {
     pub fn new(
         portfolio_repository: Box<(dyn ForFetchingPortfolioData)>,
         articles_repository: Box<(dyn ForFetchingArticlesFeatured)>,
+        projects_repository: Box<dyn ForFetchingProjects>,
     ) -> Self {
         Self {
             portfolio_repository,
             articles_repository,
+            projects_repository,
         }
     }
 }
 
 #[async_trait(?Send)]
 impl ForDisplayingPortfolio for ShowPortfolioDetailUseCase {
+    #[instrument(name = "ShowPortfolioDetailUseCase::execute", skip_all)]
     async fn execute(&self) -> Result<Portfolio> {
         let mut portfolio = self
             .portfolio_repository
@@ -38,10 +45,69 @@ impl ForDisplayingPortfolio for ShowPortfolioDetailUseCase {
             return Err(AppError::Unauthorized("Unpublished".to_string()));
         }
 
+        // the durations of ongoing items are counted here, views rendered on the server and
+        // hydrated later on the browser would otherwise disagree
+        let today = Date::new(Utc::now());
         let articles = self.articles_repository.get_featured().await?;
+        // the projects are a section among others, the portfolio is still shown without them
+        let projects = self
+            .projects_repository
+            .get_list()
+            .await
+            .unwrap_or_else(|e| {
+                warn!("The portfolio goes without its projects: {e}");
+                vec![]
+            })
+            .into_iter()
+            .filter(|p| p.status().eq(&ModerationStatus::Published))
+            .map(|mut p| {
+                p.count_duration(&today);
+                p
+            })
+            .collect::<Vec<_>>();
+
+        // the years of experience are derived from the experience timeline,
+        // overlapping jobs are only counted once
+        let years_of_experience = portfolio.sections().iter().find_map(|s| match s {
+            PortfolioSection::Resume(r) => Some((r.experience().covered_months(&today) / 12).min(u8::MAX as u32) as u8),
+            _ => None,
+        });
 
         for section in portfolio.sections_mut() {
-            if let PortfolioSection::Blogs(b) = section {
+            if let PortfolioSection::Resume(r) = section {
+                let mut education = r.education().clone();
+                let mut experience = r.experience().clone();
+                education.sort_by_most_recent();
+                experience.sort_by_most_recent();
+                education.count_durations(&today);
+                experience.count_durations(&today);
+
+                let updated_resume = ResumeBuilder::default()
+                    .id(r.id().clone())
+                    .title(r.title().clone())
+                    .subtitle(r.subtitle().clone())
+                    .text(r.text().clone())
+                    .education(education)
+                    .experience(experience)
+                    .build()
+                    .unwrap();
+
+                *section = PortfolioSection::Resume(updated_resume);
+            } else if let (PortfolioSection::AboutMe(a), Some(years)) = (&section, years_of_experience) {
+                let updated_about_me = AboutMeBuilder::default()
+                    .id(a.id().clone())
+                    .title(a.title().clone())
+                    .subtitle(a.subtitle().clone())
+                    .text(a.text().clone())
+                    .skills(a.skills().clone())
+                    .cv_document(a.cv_document().clone())
+                    .profile_picture(a.profile_picture().clone())
+                    .years_of_experience(years)
+                    .build()
+                    .unwrap();
+
+                *section = PortfolioSection::AboutMe(updated_about_me);
+            } else if let PortfolioSection::Blogs(b) = section {
                 let updated_blog = BlogsBuilder::default()
                     .id(b.id().clone())
                     .title(b.title().clone())
@@ -52,6 +118,17 @@ impl ForDisplayingPortfolio for ShowPortfolioDetailUseCase {
                     .unwrap();
 
                 *section = PortfolioSection::Blogs(updated_blog);
+            } else if let PortfolioSection::Projects(p) = section {
+                let updated_projects = ProjectsBuilder::default()
+                    .id(p.id().clone())
+                    .title(p.title().clone())
+                    .subtitle(p.subtitle().clone())
+                    .text(p.text().clone())
+                    .projects(projects.to_vec())
+                    .build()
+                    .unwrap();
+
+                *section = PortfolioSection::Projects(updated_projects);
             }
         }
 
@@ -65,6 +142,9 @@ mod tests {
     use crate::application::domain::article::{Article, Articles};
     use crate::application::domain::portfolio::tests::portfolio_fixture;
     use crate::application::domain::portfolio::tests::unpublished_portfolio_fixture;
+    use crate::application::domain::project::tests::{project_fixture, unpublished_project_fixture};
+    use crate::application::domain::project::Project;
+    use crate::application::use_cases::show_project_detail_use_case::tests::ProjectRepositoryMock;
 
     pub struct PortfolioRepositoryMock {
         fixture: Portfolio,
@@ -74,6 +154,8 @@ mod tests {
         fixture: Vec<Article>,
     }
 
+    struct FailingProjectRepositoryMock;
+
     impl PortfolioRepositoryMock {
         pub fn with_fixture(fixture: Portfolio) -> Self {
             Self { fixture }
@@ -100,13 +182,25 @@ mod tests {
         }
     }
 
+    #[async_trait(?Send)]
+    impl ForFetchingProjects for FailingProjectRepositoryMock {
+        async fn get_list(&self) -> Result<Vec<Project>> {
+            Err(AppError::ApiFailure(503, "Service Unavailable".to_string()))
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+            Err(AppError::ResourceNotFound(slug.to_string()))
+        }
+    }
+
     #[actix_rt::test]
     async fn executor_succeeds_when_valid_portfolio_is_retrieved() {
         let fixture = portfolio_fixture();
         let portfolio_repo_mock = Box::new(PortfolioRepositoryMock::with_fixture(fixture.clone()));
         let article_repo_mock = Box::new(ArticleRepositoryMock::with_fixture(vec![]));
+        let project_repo_mock = Box::new(ProjectRepositoryMock::with_fixture(vec![]));
 
-        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock);
+        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock, project_repo_mock);
         let fetched_portfolio = use_case.execute().await.unwrap();
 
         assert_eq!(fetched_portfolio.id(), fixture.id());
@@ -115,13 +209,77 @@ mod tests {
         assert_eq!(fetched_portfolio.sections().len(), fixture.sections().len());
     }
 
+    #[actix_rt::test]
+    async fn executor_derives_resume_data_when_experience_has_dates() {
+        let fixture = portfolio_fixture();
+        let portfolio_repo_mock = Box::new(PortfolioRepositoryMock::with_fixture(fixture.clone()));
+        let article_repo_mock = Box::new(ArticleRepositoryMock::with_fixture(vec![]));
+        let project_repo_mock = Box::new(ProjectRepositoryMock::with_fixture(vec![]));
+
+        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock, project_repo_mock);
+        let fetched_portfolio = use_case.execute().await.unwrap();
+
+        let years_of_experience = fetched_portfolio.sections().iter().find_map(|s| match s {
+            PortfolioSection::AboutMe(a) => Some(*a.years_of_experience()),
+            _ => None,
+        });
+
+        let experience = fetched_portfolio.sections().iter().find_map(|s| match s {
+            PortfolioSection::Resume(r) => Some(r.experience().clone()),
+            _ => None,
+        }).unwrap();
+
+        // the fixture experience has two identical items, from Oct 2020 to Jun 2022
+        assert_eq!(years_of_experience, Some(1));
+        assert!(experience.items().iter().all(|item| *item.duration() == 21));
+    }
+
+    #[actix_rt::test]
+    async fn executor_fills_projects_when_published_projects_are_retrieved() {
+        let fixture = portfolio_fixture();
+        let portfolio_repo_mock = Box::new(PortfolioRepositoryMock::with_fixture(fixture.clone()));
+        let article_repo_mock = Box::new(ArticleRepositoryMock::with_fixture(vec![]));
+        let project_repo_mock = Box::new(ProjectRepositoryMock::with_fixture(vec![
+            project_fixture(),
+            unpublished_project_fixture(),
+        ]));
+
+        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock, project_repo_mock);
+        let fetched_portfolio = use_case.execute().await.unwrap();
+
+        let projects = fetched_portfolio.sections().iter().find_map(|s| match s {
+            PortfolioSection::Projects(p) => Some(p.projects().clone()),
+            _ => None,
+        }).unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].slug(), project_fixture().slug());
+    }
+
+    #[actix_rt::test]
+    async fn executor_succeeds_when_projects_are_unavailable() {
+        let portfolio_repo_mock = Box::new(PortfolioRepositoryMock::with_fixture(portfolio_fixture()));
+        let article_repo_mock = Box::new(ArticleRepositoryMock::with_fixture(vec![]));
+
+        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock, Box::new(FailingProjectRepositoryMock));
+        let fetched_portfolio = use_case.execute().await.unwrap();
+
+        let projects = fetched_portfolio.sections().iter().find_map(|s| match s {
+            PortfolioSection::Projects(p) => Some(p.projects().clone()),
+            _ => None,
+        }).unwrap();
+
+        assert!(projects.is_empty());
+    }
+
     #[actix_rt::test]
     async fn executor_fails_when_unpublished_portfolio_is_retrieved() {
         let fixture = unpublished_portfolio_fixture();
         let portfolio_repo_mock = Box::new(PortfolioRepositoryMock::with_fixture(fixture.clone()));
         let article_repo_mock = Box::new(ArticleRepositoryMock::with_fixture(vec![]));
+        let project_repo_mock = Box::new(ProjectRepositoryMock::with_fixture(vec![]));
 
-        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock);
+        let use_case = ShowPortfolioDetailUseCase::new(portfolio_repo_mock, article_repo_mock, project_repo_mock);
         let fetched_portfolio = use_case.execute().await;
 
         assert!(matches!(fetched_portfolio, Err(AppError::Unauthorized(_))));
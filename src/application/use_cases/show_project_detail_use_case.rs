@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::instrument;
+
+use crate::application::domain::core::{AppError, Result};
+use crate::application::domain::project::Project;
+use crate::application::ports::driven::ForFetchingProjects;
+use crate::application::ports::driver::ForDisplayingProject;
+use crate::application::value_objects::{Date, ModerationStatus};
+
+/// Service for retrieving project data
+///
+/// This use case implements the ForDisplayingProject interface and uses a repository
+/// that implements ForFetchingProjects to fetch the necessary data for displaying
+/// a project.
+pub struct ShowProjectDetailUseCase {
+    project_repository: Box<dyn ForFetchingProjects>,
+}
+
+impl ShowProjectDetailUseCase {
+    pub fn new(project_repository: Box<dyn ForFetchingProjects>) -> Self {
+        Self { project_repository }
+    }
+}
+
+#[async_trait(?Send)]
+impl ForDisplayingProject for ShowProjectDetailUseCase {
+    #[instrument(name = "ShowProjectDetailUseCase::execute", skip(self))]
+    async fn execute(&self, slug: &str) -> Result<Project> {
+        let mut project = self.project_repository.find_by_slug(slug).await?;
+
+        if project.status().eq(&ModerationStatus::Unpublished) {
+            return Err(AppError::Unauthorized("Unpublished".to_string()));
+        }
+
+        project.count_duration(&Date::new(Utc::now()));
+
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::application::domain::project::tests::project_fixture;
+    use crate::application::domain::project::tests::unpublished_project_fixture;
+
+    pub struct ProjectRepositoryMock {
+        fixture: Vec<Project>,
+    }
+
+    impl ProjectRepositoryMock {
+        pub fn with_fixture(fixture: Vec<Project>) -> Self {
+            Self { fixture }
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl ForFetchingProjects for ProjectRepositoryMock {
+        async fn get_list(&self) -> Result<Vec<Project>> {
+            Ok(self.fixture.clone())
+        }
+
+        async fn find_by_slug(&self, slug: &str) -> Result<Project> {
+            self.fixture
+                .iter()
+                .find(|p| p.slug().as_str() == slug)
+                .cloned()
+                .ok_or(AppError::ResourceNotFound(slug.to_string()))
+        }
+    }
+
+    #[actix_rt::test]
+    async fn executor_succeeds_when_valid_project_is_retrieved() {
+        let project_fixture = project_fixture();
+        let project_repository = ProjectRepositoryMock::with_fixture(vec![project_fixture.clone()]);
+
+        let use_case = ShowProjectDetailUseCase::new(Box::new(project_repository));
+        let result = use_case
+            .execute(project_fixture.slug().as_str())
+            .await
+            .unwrap();
+
+        assert_eq!(result.id(), project_fixture.id());
+        assert_eq!(result.title(), project_fixture.title());
+        assert_eq!(result.slug(), project_fixture.slug());
+        assert_eq!(result.technologies(), project_fixture.technologies());
+        // the fixture lasts from Jan 2023 to Sep 2023
+        assert_eq!(*result.duration(), 9);
+    }
+
+    #[actix_rt::test]
+    async fn executor_fails_when_unpublished_project_is_retrieved() {
+        let project_fixture = unpublished_project_fixture();
+        let project_repository = ProjectRepositoryMock::with_fixture(vec![project_fixture.clone()]);
+
+        let use_case = ShowProjectDetailUseCase::new(Box::new(project_repository));
+        let result = use_case.execute(project_fixture.slug().as_str()).await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[actix_rt::test]
+    async fn executor_fails_when_project_does_not_exist() {
+        let project_repository = ProjectRepositoryMock::with_fixture(vec![]);
+
+        let use_case = ShowProjectDetailUseCase::new(Box::new(project_repository));
+        let result = use_case.execute("/en/projects/missing").await;
+
+        assert!(matches!(result, Err(AppError::ResourceNotFound(_))));
+    }
+}
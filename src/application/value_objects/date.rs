@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::any::type_name;
 use std::fmt::{Display, Formatter};
@@ -22,15 +22,28 @@ impl Date {
     pub fn to_string_with_format(&self, format: &str) -> String {
         self.0.format(format).to_string()
     }
+
+    /// Counts the calendar months up to `end`, both the start and the end month included.
+    ///
+    /// Returns `0` when `end` falls in an earlier month.
+    pub fn months_until(&self, end: &Date) -> u32 {
+        let month_index = |date: &Date| date.year() * 12 + date.month0() as i32;
+        (month_index(end) - month_index(self) + 1).max(0) as u32
+    }
 }
 
 impl TryFrom<&str> for Date {
     type Error = AppError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%:z")
+        if let Ok(datetime) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%:z") {
+            return Ok(Self::new(datetime.to_utc()));
+        }
+
+        // plain dates, as stored by date only fields, are taken at midnight UTC
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
             .map_err(|_| AppError::InvalidValue(type_name::<Self>(), value.to_string()))
-            .map(|datetime| Self::new(datetime.to_utc()))
+            .map(|date| Self::new(date.and_time(Default::default()).and_utc()))
     }
 }
 
@@ -71,6 +84,7 @@ mod tests {
     #[case("2024-12-15T14:03:56+00:00")]
     #[case("1997-12-15T14:03:56+00:00")]
     #[case("2028-12-15T14:03:56+00:00")]
+    #[case("2021-03-01")]
     fn creation_succeeds_when_input_is_iso8601(#[case] input: &str) {
         Date::try_from(input).unwrap();
     }
@@ -83,6 +97,12 @@ mod tests {
         Date::try_from(input).unwrap();
     }
 
+    #[test]
+    fn creation_succeeds_at_midnight_when_input_is_a_plain_date() {
+        let date = Date::try_from("2021-03-01").unwrap();
+        assert_eq!(date.to_string(), "2021-03-01 00:00:00");
+    }
+
     #[test]
     fn display_succeeds_when_formatting_to_string() {
         let date = Date::try_from("2024-12-15T14:03:56+00:00").unwrap();
@@ -100,6 +120,20 @@ mod tests {
         assert_eq!(date.to_string_with_format(format), expected);
     }
 
+    #[rstest]
+    #[case("2021-03-01", "2021-03-31", 1)]
+    #[case("2021-03-01", "2022-02-01", 12)]
+    #[case("2021-03-01", "2020-03-01", 0)]
+    fn months_until_succeeds_when_counting_calendar_months(
+        #[case] start: &str,
+        #[case] end: &str,
+        #[case] expected: u32,
+    ) {
+        let start = Date::try_from(start).unwrap();
+        let end = Date::try_from(end).unwrap();
+        assert_eq!(start.months_until(&end), expected);
+    }
+
     #[test]
     fn comparison_succeeds_when_values_are_the_same() {
         let date = Date::try_from("2024-12-15T14:03:56+00:00").unwrap();
@@ -0,0 +1,95 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::any::type_name;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+use crate::application::domain::core::AppError;
+
+lazy_static! {
+    static ref EMAIL_REGEX: Regex =
+        Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9]([a-zA-Z0-9-]*[a-zA-Z0-9])?(\.[a-zA-Z0-9]([a-zA-Z0-9-]*[a-zA-Z0-9])?)*\.[a-zA-Z]{2,}$")
+            .unwrap();
+}
+
+/// A value object that represents an email address.
+///
+/// The address is trimmed and validated against a pragmatic subset of RFC 5322,
+/// which is enough to reject typos and garbage submitted through public forms.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Email(String);
+
+impl Email {
+    pub fn new(input: String) -> Result<Self, AppError> {
+        let input = input.trim().to_string();
+
+        match input.len() <= 254 && EMAIL_REGEX.is_match(&input) {
+            true => Ok(Self(input)),
+            false => Err(AppError::InvalidValue(type_name::<Self>(), input)),
+        }
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = AppError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value.to_string())
+    }
+}
+
+impl Deref for Email {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Email {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("john@example.com")]
+    #[case("john.doe+portfolio@example.co")]
+    #[case("  john@sub.example.com  ")]
+    #[case("JOHN_DOE@EXAMPLE.COM")]
+    fn creation_succeeds_when_input_is_valid_email(#[case] input: &str) {
+        assert_eq!(*Email::try_from(input).unwrap(), input.trim());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("john")]
+    #[case("john@")]
+    #[case("@example.com")]
+    #[case("john@example")]
+    #[case("john doe@example.com")]
+    #[case("john@-example.com")]
+    fn creation_fails_when_input_is_invalid(#[case] input: &str) {
+        assert!(Email::try_from(input).is_err());
+    }
+
+    #[test]
+    fn display_succeeds_when_formatting_to_string() {
+        let email = Email::try_from("john@example.com").unwrap();
+        assert_eq!(email.to_string(), "john@example.com");
+    }
+}
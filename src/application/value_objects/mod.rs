@@ -1,10 +1,12 @@
 mod date;
+mod email;
 mod identifier;
 mod moderation_status;
 mod required_text;
 mod url;
 
 pub use date::*;
+pub use email::*;
 pub use identifier::*;
 pub use moderation_status::*;
 pub use required_text::*;
@@ -1,4 +1,5 @@
 #![feature(random)]
+#![recursion_limit = "256"]
 
 pub mod adapters;
 pub mod application;
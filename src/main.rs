@@ -1,8 +1,10 @@
+#![recursion_limit = "256"]
+
 #[cfg(feature = "ssr")]
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     use actix_files::Files;
-    use actix_web::middleware::Compress;
+    use actix_web::middleware::{from_fn, Compress};
     use actix_web::*;
     use dotenvy::dotenv;
     use leptos::config::get_configuration;
@@ -10,23 +12,201 @@ async fn main() -> std::io::Result<()> {
     use leptos_actix::{generate_route_list, LeptosRoutes};
     use leptos_meta::MetaTags;
     use std::env;
+    use std::time::Duration;
 
+    use website::adapters::driven::cache::ContentCache;
+    use website::adapters::driven::content_source::ContentSource;
+    use website::adapters::driven::markdown_files::services::ContentFilesService;
+    use website::adapters::driven::mail_transport::*;
+    use website::adapters::driver::actix_endpoints::*;
     use website::adapters::driver::leptos_webui::views::app::*;
-    use website::utilities::HttpClient;
+    use website::adapters::driver::static_export::*;
+    use website::utilities::{
+        init_logging, CircuitBreaker, ClientCredentials, HttpClient, Metrics, RateLimiter, Recordings,
+        ResponseCache, SingleFlight,
+    };
 
     dotenv().ok();
 
+    init_logging(
+        env::var("LOG_FORMAT")
+            .map(|v| v.parse().expect("LOG_FORMAT is invalid"))
+            .unwrap_or_default(),
+    );
+
     let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
 
-    HttpServer::new(move || {
+    let mail_from = env::var("MAIL_FROM").expect("MAIL_FROM is undefined");
+    let mail_to = env::var("MAIL_TO").expect("MAIL_TO is undefined");
+    let mail_envelope = MailEnvelope::new(&mail_from, &mail_to).expect("MAIL_FROM or MAIL_TO is invalid");
+
+    let mail_transport = match env::var("MAIL_TRANSPORT").unwrap_or("file".to_string()).as_str() {
+        "smtp" => {
+            let smtp_url = env::var("MAIL_SMTP_URL").expect("MAIL_SMTP_URL is undefined");
+            MailTransport::Smtp(
+                SmtpMessageSender::new(&smtp_url, mail_envelope).expect("MAIL_SMTP_URL is invalid"),
+            )
+        }
+        "file" => {
+            let outbox_dir = env::var("MAIL_OUTBOX_DIR").unwrap_or(env::temp_dir().display().to_string());
+            MailTransport::File(FileMessageSender::new(&outbox_dir, mail_envelope))
+        }
+        other => panic!("MAIL_TRANSPORT `{other}` is not supported"),
+    };
+
+    // created outside the factory so that every worker shares the same counters
+    let rate_limiter = RateLimiter::default()
+        .max_requests(
+            env::var("CONTACT_RATE_LIMIT")
+                .map(|v| v.parse().expect("CONTACT_RATE_LIMIT is invalid"))
+                .unwrap_or(5),
+        )
+        .window(Duration::from_secs(
+            env::var("CONTACT_RATE_LIMIT_WINDOW")
+                .map(|v| v.parse().expect("CONTACT_RATE_LIMIT_WINDOW is invalid"))
+                .unwrap_or(3600),
+        ))
+        .trusted_proxies(
+            env::var("TRUSTED_PROXIES")
+                .map(|v| {
+                    v.split(',')
+                        .map(|ip| ip.trim().parse().expect("TRUSTED_PROXIES is invalid"))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        );
+
+    // a single registry for every worker, served at `/metrics`
+    let metrics = Metrics::default();
+
+    // shared by every worker as well, so all of them stop calling a host that is down
+    let circuit_breaker = CircuitBreaker::default()
+        .metrics(metrics.clone())
+        .failure_threshold(
+            env::var("JSONAPI_CIRCUIT_BREAKER_THRESHOLD")
+                .map(|v| v.parse().expect("JSONAPI_CIRCUIT_BREAKER_THRESHOLD is invalid"))
+                .unwrap_or(5),
+        )
+        .cooldown(Duration::from_secs(
+            env::var("JSONAPI_CIRCUIT_BREAKER_COOLDOWN")
+                .map(|v| v.parse().expect("JSONAPI_CIRCUIT_BREAKER_COOLDOWN is invalid"))
+                .unwrap_or(30),
+        ));
+    let response_cache = ResponseCache::default().max_entries(
+        env::var("JSONAPI_RESPONSE_CACHE_MAX_ENTRIES")
+            .map(|v| v.parse().expect("JSONAPI_RESPONSE_CACHE_MAX_ENTRIES is invalid"))
+            .unwrap_or(1000),
+    );
+    // the CMS traffic can be recorded once, then replayed to run the site offline
+    let api_recordings = Recordings::default()
+        .mode(
+            env::var("JSONAPI_TRAFFIC")
+                .map(|v| v.parse().expect("JSONAPI_TRAFFIC is invalid"))
+                .unwrap_or_default(),
+        )
+        .directory(
+            &env::var("JSONAPI_RECORDINGS_DIR").unwrap_or("tests/fixtures/recordings".to_string()),
+        );
+
+    // the content comes from the CMS, or from the Markdown files of a directory
+    let content_source = match env::var("CONTENT_SOURCE").unwrap_or("drupal".to_string()).as_str() {
+        "drupal" => ContentSource::Drupal,
+        "markdown" => ContentSource::Markdown(ContentFilesService::new(
+            &env::var("CONTENT_DIR").unwrap_or("content".to_string()),
+        )),
+        other => panic!("CONTENT_SOURCE `{other}` is not supported"),
+    };
+
+    // concurrent renders of every worker share the same upstream requests
+    let in_flight = SingleFlight::default();
+    let api_connect_timeout = Duration::from_millis(
+        env::var("JSONAPI_CONNECT_TIMEOUT")
+            .map(|v| v.parse().expect("JSONAPI_CONNECT_TIMEOUT is invalid"))
+            .unwrap_or(2000),
+    );
+    let api_read_timeout = Duration::from_millis(
+        env::var("JSONAPI_READ_TIMEOUT")
+            .map(|v| v.parse().expect("JSONAPI_READ_TIMEOUT is invalid"))
+            .unwrap_or(10000),
+    );
+    let api_max_retries = env::var("JSONAPI_MAX_RETRIES")
+        .map(|v| v.parse().expect("JSONAPI_MAX_RETRIES is invalid"))
+        .unwrap_or(2);
+
+    // the access token is shared by every worker, instead of each one requesting its own
+    let api_credentials = match env::var("JSONAPI_AUTH").unwrap_or("basic".to_string()).as_str() {
+        "basic" => None,
+        "oauth2" => {
+            let token_url = env::var("JSONAPI_TOKEN_URL").expect("JSONAPI_TOKEN_URL is undefined");
+            let client_id = env::var("JSONAPI_CLIENT_ID").expect("JSONAPI_CLIENT_ID is undefined");
+            let client_secret =
+                env::var("JSONAPI_CLIENT_SECRET").expect("JSONAPI_CLIENT_SECRET is undefined");
+            let credentials = ClientCredentials::new(&token_url, &client_id, &client_secret)
+                .expect("JSONAPI_TOKEN_URL is invalid")
+                .timeouts(api_connect_timeout, api_read_timeout);
+            Some(match env::var("JSONAPI_SCOPE") {
+                Ok(scope) => credentials.scope(&scope),
+                Err(_) => credentials,
+            })
+        }
+        other => panic!("JSONAPI_AUTH `{other}` is not supported"),
+    };
+
+    // the last good copy of the CMS content, served while Drupal is slow or down
+    let content_cache = ContentCache::default()
+        .metrics(metrics.clone())
+        .ttl(Duration::from_secs(
+            env::var("CONTENT_CACHE_TTL")
+                .map(|v| v.parse().expect("CONTENT_CACHE_TTL is invalid"))
+                .unwrap_or(60),
+        ))
+        .max_age(Duration::from_secs(
+            env::var("CONTENT_CACHE_MAX_AGE")
+                .map(|v| v.parse().expect("CONTENT_CACHE_MAX_AGE is invalid"))
+                .unwrap_or(86400),
+        ));
+
+    let resume_pdf_cache = ResumePdfCache::default();
+
+    let api_base_url = env::var("JSONAPI_BASE_URL").expect("JSONAPI_BASE_URL is undefined");
+    let http_client = match &api_credentials {
+        // the recordings are served without asking the CMS, no credentials are needed
+        _ if api_recordings.is_replaying() => HttpClient::default(),
+        // nor are they when the content is read from files
+        _ if matches!(content_source, ContentSource::Markdown(_)) => HttpClient::default(),
+        Some(credentials) => HttpClient::default().authentication(credentials.clone()),
+        None => {
+            let api_username =
+                env::var("JSONAPI_USERNAME").expect("JSONAPI_USERNAME is undefined");
+            let api_password =
+                env::var("JSONAPI_PASSWORD").expect("JSONAPI_PASSWORD is undefined");
+            HttpClient::default().basic_auth(api_username.as_str(), api_password.as_str())
+        }
+    }
+    .base_url(api_base_url.as_str())
+    .timeouts(api_connect_timeout, api_read_timeout)
+    .retries(api_max_retries, Duration::from_millis(100))
+    .circuit_breaker(circuit_breaker.clone())
+    .response_cache(response_cache.clone())
+    .in_flight(in_flight.clone())
+    .metrics(metrics.clone())
+    .recordings(api_recordings.clone());
+
+    // `website export [dir]` renders every page into a static site instead of serving them
+    let export_dir = match env::args().nth(1).as_deref() {
+        None => None,
+        Some("export") => Some(env::args().nth(2).unwrap_or("dist".to_string())),
+        Some(other) => panic!("Command `{other}` is not supported"),
+    };
+    let export_site_root = conf.leptos_options.site_root.to_string();
+    let export_repositories = content_source.repositories(&http_client);
+
+    let server = HttpServer::new(move || {
         let routes = generate_route_list(App);
         let leptos_options = &conf.leptos_options;
         let site_root = leptos_options.site_root.clone().to_string();
-        let api_base_url = env::var("JSONAPI_BASE_URL").expect("JSONAPI_BASE_URL is undefined");
-        let api_username = env::var("JSONAPI_USERNAME").expect("JSONAPI_USERNAME is undefined");
-        let api_password =
-            env::var("JSONAPI_PASSWORD").expect("JSONAPI_PASSWORD is undefined");
+        let api_base_url = api_base_url.clone();
         let redis_host = env::var("REDIS_HOST").expect("REDIS_HOST is undefined");
         let redis_port = env::var("REDIS_PORT").expect("REDIS_PORT is undefined");
         let redis_password =
@@ -40,18 +220,18 @@ async fn main() -> std::io::Result<()> {
         // let cache_client = CacheClient::default()
         //     .connection_url(redis_url.as_str());
 
-        let http_client = HttpClient::default()
-            .base_url(api_base_url.as_str())
-            .basic_auth(api_username.as_str(), api_password.as_str());
-
-        println!("listening on http://{}", &addr);
-
         App::new()
             .wrap(Compress::default())
+            .wrap(from_fn(track_requests))
+            .wrap(from_fn(trace_requests))
             .service(favicon)
             .service(health)
+            .service(metrics_endpoint)
             .service(Files::new("/assets", &site_root))
             .service(Files::new("/pkg", format!("{site_root}/pkg")))
+            .service(resume_pdf_endpoint)
+            .service(resume_json_endpoint)
+            .service(contact_vcard_endpoint)
             .leptos_routes(routes, {
                 let leptos_options = leptos_options.clone();
                 move || {
@@ -76,19 +256,58 @@ async fn main() -> std::io::Result<()> {
             })
             // .app_data(web::Data::new(cache_client.to_owned()))
             .app_data(web::Data::new(http_client.to_owned()))
+            .app_data(web::Data::new(content_cache.to_owned()))
+            .app_data(web::Data::new(content_source.to_owned()))
+            .app_data(web::Data::new(metrics.to_owned()))
+            .app_data(web::Data::new(mail_transport.to_owned()))
+            .app_data(web::Data::new(rate_limiter.to_owned()))
+            .app_data(web::Data::new(resume_pdf_cache.to_owned()))
             .app_data(web::Data::new(leptos_options.to_owned()))
-    })
-        .bind(&addr)?
-        .run()
-        .await
+    });
+
+    let Some(export_dir) = export_dir else {
+        tracing::info!("listening on http://{}", &addr);
+        return server.bind(&addr)?.run().await;
+    };
+
+    // the pages are rendered by the app itself, served on a free port while exporting
+    let server = server.workers(1).bind(("127.0.0.1", 0))?;
+    let server_url = format!("http://{}", server.addrs()[0]);
+    let server = server.run();
+    let server_handle = server.handle();
+    actix_web::rt::spawn(server);
+
+    let content_paths = content_paths(&export_repositories).await.map_err(std::io::Error::other)?;
+    let paths = ExportRoutes::new(generate_route_list(App).iter().map(|route| route.path().to_string()))
+        .paths(&content_paths);
+    let exporter = StaticExporter::new(&export_dir).assets(&export_site_root);
+    let exporter = match env::var("SITE_URL") {
+        Ok(site_url) => exporter.base_url(&site_url),
+        Err(_) => exporter,
+    };
+    let exported = exporter.export(&server_url, paths).await;
+
+    server_handle.stop(true).await;
+    exported.map(|_| ())
 }
 
 #[cfg(feature = "ssr")]
 #[actix_web::get("/health")]
-async fn health() -> actix_web::HttpResponse {
-    let api_url = std::env::var("JSONAPI_BASE_URL").unwrap();
+async fn health(
+    content_source: actix_web::web::Data<website::adapters::driven::content_source::ContentSource>,
+) -> actix_web::HttpResponse {
+    use website::adapters::driven::content_source::ContentSource;
+
+    // the Markdown files are read from the disk, only the CMS can be down
+    let is_up = match content_source.get_ref() {
+        ContentSource::Markdown(_) => true,
+        ContentSource::Drupal => {
+            let api_url = std::env::var("JSONAPI_BASE_URL").unwrap();
+            reqwest::get(api_url).await.unwrap().status().is_success()
+        }
+    };
 
-    if reqwest::get(api_url).await.unwrap().status().is_success() {
+    if is_up {
         return actix_web::HttpResponse::Ok()
             .content_type(actix_web::http::header::ContentType::plaintext())
             .body(
@@ -0,0 +1,76 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use async_trait::async_trait;
+use reqwest::RequestBuilder;
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::application::domain::core::Result;
+
+/// Strategy used by `HttpClient` to authenticate the requests sent to the CMS.
+#[async_trait(?Send)]
+pub trait Authentication: Debug + Send + Sync {
+    /// Adds the credentials to the request.
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder>;
+
+    /// Forgets the credentials rejected with a `401`, returns whether sending the request
+    /// again with fresh credentials is worth it.
+    fn invalidate(&self) -> bool {
+        false
+    }
+
+    /// Tells the credentials apart without exposing them, e.g. to share requests between callers.
+    fn fingerprint(&self) -> u64;
+}
+
+#[derive(Clone, Debug)]
+pub struct BasicAuth {
+    username: SecretString,
+    password: SecretString,
+}
+
+#[async_trait(?Send)]
+impl Authentication for BasicAuth {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        Ok(request.basic_auth(
+            self.username.expose_secret().to_string(),
+            Some(self.password.expose_secret().to_string()),
+        ))
+    }
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.username.expose_secret().hash(&mut hasher);
+        self.password.expose_secret().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl From<(&str, &str)> for BasicAuth {
+    fn from(value: (&str, &str)) -> Self {
+        let (username, password) = value;
+        Self {
+            username: SecretString::new(Box::from(username.to_string())),
+            password: SecretString::new(Box::from(password.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_is_equal_when_credentials_are_equal() {
+        let auth = BasicAuth::from(("test", "123456"));
+
+        assert_eq!(auth.fingerprint(), BasicAuth::from(("test", "123456")).fingerprint());
+        assert_ne!(auth.fingerprint(), BasicAuth::from(("test", "654321")).fingerprint());
+    }
+
+    #[test]
+    fn invalidate_is_not_worth_it_when_credentials_are_static() {
+        assert!(!BasicAuth::from(("test", "123456")).invalidate());
+    }
+}
@@ -0,0 +1,221 @@
+use std::any::type_name;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::application::domain::core::{AppError, Result};
+use crate::utilities::Metrics;
+
+/// Per-host circuit breaker, requests fail fast once a host keeps failing.
+///
+/// After `failure_threshold` consecutive failures the circuit opens for `cooldown`, then it's
+/// half-open: a single request is let through as a probe while the others keep failing fast.
+/// A success of the probe closes the circuit, a failure opens it once more. A probe that never
+/// reports back is replaced by a new one after another `cooldown`.
+/// Clones share the same state, so every worker sees the same hosts as down.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    hosts: Arc<Mutex<HashMap<String, HostState>>>,
+    metrics: Metrics,
+}
+
+#[derive(Clone, Debug, Default)]
+struct HostState {
+    failures: u32,
+    opened_at: Option<Instant>,
+    probed_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold;
+        self
+    }
+
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Fails while the circuit of the given host is open, once the cooldown has elapsed only
+    /// the request taken as the probe succeeds.
+    pub fn check(&self, host: &str) -> Result<()> {
+        let mut hosts = self.lock()?;
+        let Some(state) = hosts.get_mut(host).filter(|state| state.opened_at.is_some()) else {
+            return Ok(());
+        };
+
+        let is_elapsed = |instant: Option<Instant>| instant.is_none_or(|i| i.elapsed() >= self.cooldown);
+        if is_elapsed(state.opened_at) && is_elapsed(state.probed_at) {
+            state.probed_at = Some(Instant::now());
+            return Ok(());
+        }
+
+        Err(AppError::External(
+            type_name::<Self>(),
+            format!("the circuit for `{host}` is open"),
+        ))
+    }
+
+    pub fn record_success(&self, host: &str) {
+        if let Ok(mut hosts) = self.lock() {
+            if hosts.remove(host).is_some_and(|state| state.opened_at.is_some()) {
+                self.metrics.set_circuit_open(host, false);
+            }
+        }
+    }
+
+    pub fn record_failure(&self, host: &str) {
+        if let Ok(mut hosts) = self.lock() {
+            let state = hosts.entry(host.to_string()).or_default();
+            state.failures += 1;
+
+            if state.failures >= self.failure_threshold {
+                state.opened_at = Some(Instant::now());
+                state.probed_at = None;
+                self.metrics.set_circuit_open(host, true);
+            }
+        }
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, HostState>>> {
+        self.hosts
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        CircuitBreaker {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Metrics::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_succeeds_when_threshold_is_not_reached() {
+        let breaker = CircuitBreaker::default().failure_threshold(2);
+        breaker.record_failure("localhost");
+
+        assert!(breaker.check("localhost").is_ok());
+    }
+
+    #[test]
+    fn check_fails_when_threshold_is_reached() {
+        let breaker = CircuitBreaker::default().failure_threshold(2);
+        breaker.record_failure("localhost");
+        breaker.record_failure("localhost");
+
+        let result = breaker.check("localhost");
+
+        assert!(matches!(result, Err(AppError::External(_, _))));
+    }
+
+    #[test]
+    fn check_succeeds_when_hosts_are_different() {
+        let breaker = CircuitBreaker::default().failure_threshold(1);
+        breaker.record_failure("localhost");
+
+        assert!(breaker.check("example.com").is_ok());
+    }
+
+    #[test]
+    fn check_succeeds_when_success_resets_failures() {
+        let breaker = CircuitBreaker::default().failure_threshold(2);
+        breaker.record_failure("localhost");
+        breaker.record_success("localhost");
+        breaker.record_failure("localhost");
+
+        assert!(breaker.check("localhost").is_ok());
+    }
+
+    #[test]
+    fn check_succeeds_when_cooldown_has_elapsed() {
+        let breaker = CircuitBreaker::default()
+            .failure_threshold(1)
+            .cooldown(Duration::from_millis(10));
+        breaker.record_failure("localhost");
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(breaker.check("localhost").is_ok());
+    }
+
+    #[test]
+    fn check_fails_when_probe_is_in_flight() {
+        let breaker = CircuitBreaker::default()
+            .failure_threshold(1)
+            .cooldown(Duration::from_millis(10));
+        breaker.record_failure("localhost");
+        std::thread::sleep(Duration::from_millis(20));
+
+        let probe = breaker.check("localhost");
+        let concurrent = breaker.check("localhost");
+
+        assert!(probe.is_ok());
+        assert!(concurrent.is_err());
+    }
+
+    #[test]
+    fn check_succeeds_when_probe_never_reports_back() {
+        let breaker = CircuitBreaker::default()
+            .failure_threshold(1)
+            .cooldown(Duration::from_millis(10));
+        breaker.record_failure("localhost");
+        std::thread::sleep(Duration::from_millis(20));
+        breaker.check("localhost").unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(breaker.check("localhost").is_ok());
+    }
+
+    #[test]
+    fn check_fails_when_probe_after_cooldown_fails() {
+        let breaker = CircuitBreaker::default()
+            .failure_threshold(1)
+            .cooldown(Duration::from_millis(10));
+        breaker.record_failure("localhost");
+        std::thread::sleep(Duration::from_millis(20));
+        breaker.check("localhost").unwrap();
+
+        breaker.record_failure("localhost");
+
+        assert!(breaker.check("localhost").is_err());
+    }
+
+    #[test]
+    fn check_shares_state_when_breaker_is_cloned() {
+        let breaker = CircuitBreaker::default().failure_threshold(1);
+        breaker.clone().record_failure("localhost");
+
+        assert!(breaker.check("localhost").is_err());
+    }
+
+    #[test]
+    fn metrics_report_circuit_state_when_it_changes() {
+        let metrics = Metrics::default();
+        let breaker = CircuitBreaker::default().failure_threshold(1).metrics(metrics.clone());
+
+        breaker.record_failure("localhost");
+        assert!(metrics.render().contains(r#"website_circuit_breaker_open{host="localhost"} 1"#));
+
+        breaker.record_success("localhost");
+        assert!(metrics.render().contains(r#"website_circuit_breaker_open{host="localhost"} 0"#));
+    }
+}
@@ -0,0 +1,290 @@
+use std::any::type_name;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, StatusCode, Url};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::application::domain::core::{AppError, Result};
+use crate::utilities::Authentication;
+
+/// Tokens are renewed this long before they expire, so they never expire in flight.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed when the token endpoint doesn't tell it.
+const DEFAULT_EXPIRES_IN: u64 = 300;
+
+/// Limits applied until [`ClientCredentials::timeouts`] sets the configured ones.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OAuth2 client credentials grant, e.g. against the `simple_oauth` Drupal module.
+///
+/// The access token is requested from the token endpoint on first use and reused until
+/// shortly before it expires. Clones share the same token. Token requests time out like
+/// the content requests do, as every request waits for them.
+#[derive(Clone, Debug)]
+pub struct ClientCredentials {
+    client: Client,
+    token_url: Url,
+    client_id: String,
+    client_secret: SecretString,
+    scope: Option<String>,
+    token: Arc<Mutex<Option<AccessToken>>>,
+}
+
+#[derive(Debug)]
+struct AccessToken {
+    value: SecretString,
+    expires_at: Instant,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+impl ClientCredentials {
+    pub fn new(token_url: &str, client_id: &str, client_secret: &str) -> Result<Self> {
+        let token_url = Url::parse(token_url)
+            .map_err(|_| AppError::InvalidValue(type_name::<Self>(), token_url.to_string()))?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = build_client(DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT);
+        #[cfg(target_arch = "wasm32")]
+        let client = Client::new();
+
+        Ok(ClientCredentials {
+            client,
+            token_url,
+            client_id: client_id.to_string(),
+            client_secret: SecretString::new(Box::from(client_secret.to_string())),
+            scope: None,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Limits how long connecting to, and waiting for, the token endpoint may take.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeouts(mut self, connect: Duration, read: Duration) -> Self {
+        self.client = build_client(connect, read);
+        self
+    }
+
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+
+    /// Returns the cached access token, requesting a new one when it's missing or about to expire.
+    async fn access_token(&self) -> Result<SecretString> {
+        // held while requesting, so concurrent requests wait for the same token
+        let mut token = self.token.lock().await;
+
+        if let Some(token) = token.as_ref().filter(|t| t.expires_at > Instant::now() + EXPIRY_MARGIN) {
+            return Ok(token.value.clone());
+        }
+
+        let fresh = self.request_token().await?;
+        let value = fresh.value.clone();
+        *token = Some(fresh);
+
+        Ok(value)
+    }
+
+    async fn request_token(&self) -> Result<AccessToken> {
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.expose_secret()),
+        ];
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope.as_str()));
+        }
+
+        let response = self
+            .client
+            .post(self.token_url.clone())
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| AppError::External(type_name::<Self>(), e.to_string()))?;
+
+        match response.status() {
+            status if status.is_success() => {}
+            // `invalid_client` or `invalid_grant`, retrying won't help
+            StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED => {
+                return Err(AppError::Unauthorized(self.client_id.clone()));
+            }
+            // an outage of the token endpoint, as transient as one of the CMS
+            status => {
+                return Err(AppError::ApiFailure(status.as_u16(), response.text().await.unwrap_or_default()));
+            }
+        }
+
+        let token = response
+            .json::<TokenResponse>()
+            .await
+            .map_err(|e| AppError::Deserialization(e.to_string(), self.token_url.to_string()))?;
+
+        Ok(AccessToken {
+            value: SecretString::new(Box::from(token.access_token)),
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in.unwrap_or(DEFAULT_EXPIRES_IN)),
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_client(connect: Duration, read: Duration) -> Client {
+    Client::builder()
+        .connect_timeout(connect)
+        .read_timeout(read)
+        .build()
+        .unwrap()
+}
+
+#[async_trait(?Send)]
+impl Authentication for ClientCredentials {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        let token = self.access_token().await?;
+
+        Ok(request.bearer_auth(token.expose_secret()))
+    }
+
+    fn invalidate(&self) -> bool {
+        // a token being requested right now is fresh already
+        if let Ok(mut token) = self.token.try_lock() {
+            *token = None;
+        }
+        true
+    }
+
+    fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.token_url.as_str().hash(&mut hasher);
+        self.client_id.hash(&mut hasher);
+        self.scope.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::tests::{credentials_mock, token_mock};
+    use mockito::Server;
+
+    #[actix_rt::test]
+    async fn access_token_is_requested_once_when_token_is_valid() {
+        let mut server = Server::new_async().await;
+        let token_mock = token_mock(&mut server, "first", 300, 1).await;
+        let credentials = credentials_mock(&server.url());
+
+        let first = credentials.access_token().await.unwrap();
+        let second = credentials.clone().access_token().await.unwrap();
+
+        assert_eq!(first.expose_secret(), "first");
+        assert_eq!(second.expose_secret(), "first");
+        token_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn access_token_is_requested_again_when_token_is_about_to_expire() {
+        let mut server = Server::new_async().await;
+        let token_mock = server
+            .mock("POST", "/oauth/token")
+            .with_status(200)
+            .with_body(r#"{"token_type": "Bearer", "access_token": "short", "expires_in": 10}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let credentials = credentials_mock(&server.url());
+
+        credentials.access_token().await.unwrap();
+        credentials.access_token().await.unwrap();
+
+        token_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn access_token_is_requested_again_when_token_is_invalidated() {
+        let mut server = Server::new_async().await;
+        let token_mock = token_mock(&mut server, "first", 300, 2).await;
+        let credentials = credentials_mock(&server.url());
+
+        credentials.access_token().await.unwrap();
+        assert!(credentials.invalidate());
+        credentials.access_token().await.unwrap();
+
+        token_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn access_token_fails_when_client_is_rejected() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/oauth/token")
+            .with_status(401)
+            .with_body(r#"{"error": "invalid_client"}"#)
+            .create_async()
+            .await;
+        let credentials = credentials_mock(&server.url());
+
+        let result = credentials.access_token().await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[actix_rt::test]
+    async fn access_token_fails_with_api_failure_when_token_endpoint_is_unavailable() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/oauth/token")
+            .with_status(503)
+            .with_body("Service Unavailable")
+            .create_async()
+            .await;
+        let credentials = credentials_mock(&server.url());
+
+        let result = credentials.access_token().await;
+
+        assert!(matches!(result, Err(AppError::ApiFailure(503, _))));
+    }
+
+    #[test]
+    fn fingerprint_is_different_when_clients_are_different() {
+        let first = ClientCredentials::new("https://localhost.dev/oauth/token", "website", "secret").unwrap();
+        let second = ClientCredentials::new("https://localhost.dev/oauth/token", "other", "secret").unwrap();
+
+        assert_ne!(first.fingerprint(), second.fingerprint());
+    }
+
+    #[test]
+    fn new_fails_when_token_url_is_invalid() {
+        let result = ClientCredentials::new("localhost.dev/oauth/token", "website", "secret");
+
+        assert!(matches!(result, Err(AppError::InvalidValue(_, _))));
+    }
+
+    #[actix_rt::test]
+    async fn access_token_fails_when_token_endpoint_is_too_slow() {
+        // accepts the connection but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let token_url = format!("http://{}/oauth/token", listener.local_addr().unwrap());
+        let credentials = ClientCredentials::new(&token_url, "website", "s3cr3t")
+            .unwrap()
+            .timeouts(Duration::from_millis(100), Duration::from_millis(100));
+
+        let result = credentials.access_token().await;
+
+        assert!(matches!(result, Err(AppError::External(_, _))));
+    }
+}
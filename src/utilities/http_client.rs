@@ -1,20 +1,39 @@
-use reqwest::{Client, Method, RequestBuilder, Response, Url};
-use secrecy::{ExposeSecret, SecretString};
+use std::any::type_name;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderName, ETAG, LAST_MODIFIED};
+use reqwest::{Client, Method, Response, StatusCode, Url};
 use serde_json::Value;
+use tracing::{field, info_span, Instrument};
 
 use crate::application::domain::core::{AppError, Result};
+use crate::utilities::{resource_type, Authentication, BasicAuth, CachedResponse};
+use crate::utilities::{CircuitBreaker, Metrics, Recordings, ResponseCache, SingleFlight};
+
+/// Header carrying the id of the incoming request to the CMS, to correlate both logs.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
 
 #[derive(Clone, Debug)]
 pub struct HttpClient {
     client: Client,
     base_url: Option<Url>,
-    basic_auth: Option<BasicAuth>,
+    authentication: Option<Arc<dyn Authentication>>,
+    retry_policy: RetryPolicy,
+    circuit_breaker: CircuitBreaker,
+    response_cache: ResponseCache,
+    in_flight: SingleFlight<Value>,
+    metrics: Metrics,
+    request_id: Option<String>,
+    recordings: Recordings,
 }
 
+/// Exponential backoff used to retry idempotent requests on server errors and timeouts.
 #[derive(Clone, Debug)]
-pub struct BasicAuth {
-    username: SecretString,
-    password: SecretString,
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
 }
 
 impl HttpClient {
@@ -23,18 +42,125 @@ impl HttpClient {
         self
     }
 
-    pub fn basic_auth(mut self, username: &str, password: &str) -> Self {
-        self.basic_auth = Some(BasicAuth::from((username, password)));
+    pub fn basic_auth(self, username: &str, password: &str) -> Self {
+        self.authentication(BasicAuth::from((username, password)))
+    }
+
+    pub fn authentication(mut self, authentication: impl Authentication + 'static) -> Self {
+        self.authentication = Some(Arc::new(authentication));
+        self
+    }
+
+    // the browser client has no connection level timeouts
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeouts(mut self, connect: Duration, read: Duration) -> Self {
+        self.client = Client::builder()
+            .connect_timeout(connect)
+            .read_timeout(read)
+            .build()
+            .unwrap();
+        self
+    }
+
+    pub fn retries(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.retry_policy = RetryPolicy {
+            max_retries,
+            base_delay,
+            ..self.retry_policy
+        };
+        self
+    }
+
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = circuit_breaker;
+        self
+    }
+
+    pub fn response_cache(mut self, response_cache: ResponseCache) -> Self {
+        self.response_cache = response_cache;
         self
     }
 
+    pub fn in_flight(mut self, in_flight: SingleFlight<Value>) -> Self {
+        self.in_flight = in_flight;
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Records the responses of the CMS, or serves the recorded ones instead of asking it.
+    pub fn recordings(mut self, recordings: Recordings) -> Self {
+        self.recordings = recordings;
+        self
+    }
+
+    /// Sends the id of the incoming request along with every request.
+    pub fn request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+
+    /// Counts an entity fetched through this client that couldn't be mapped into the domain.
+    pub fn record_mapper_failure(&self, entity: &str) {
+        self.metrics.record_mapper_failure(entity);
+    }
+
+    /// Fetches a JSON document, sent as a conditional GET when the url was fetched before.
+    ///
+    /// A `304 Not Modified` answer reuses the stored body, so it isn't downloaded again.
+    /// Concurrent calls for the same url and credentials share a single upstream request.
     pub async fn get_json(&self, url: &str) -> Result<Value> {
-        self.request(Method::GET, url)
-            .await
-            .map_err(|e| AppError::ApiFailure(e.to_string()))?
-            .json::<Value>()
-            .await
-            .map_err(|e| AppError::ApiFailure(e.to_string()))
+        let url = self.resolve_url(url)?;
+        let key = match &self.authentication {
+            Some(auth) => format!("{url}#{:x}", auth.fingerprint()),
+            None => url.to_string(),
+        };
+
+        self.in_flight.run(&key, || self.fetch_json(url, &key)).await
+    }
+
+    /// Fetches the document, `key` identifies its stored response like it does its flight.
+    async fn fetch_json(&self, url: Url, key: &str) -> Result<Value> {
+        if self.recordings.is_replaying() {
+            return self.recordings.replay(&url);
+        }
+
+        let cached = self.response_cache.get(key);
+
+        let response = self.request(Method::GET, url.as_str(), cached.as_ref()).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            // only conditional requests can be answered without a body
+            let Some(cached) = cached else {
+                return Err(AppError::ApiFailure(
+                    StatusCode::NOT_MODIFIED.as_u16(),
+                    format!("`{url}` was not modified, but no response of it is stored"),
+                ));
+            };
+            self.metrics.record_cache_lookup("response", "hit");
+            let body = cached.body()?;
+            self.recordings.record_json(&url, &body);
+            return Ok(body);
+        }
+
+        let etag = header_value(&response, ETAG);
+        let last_modified = header_value(&response, LAST_MODIFIED);
+        // an unreadable body is as much a failure of the upstream as a 5xx
+        let bad_gateway = |e: String| AppError::ApiFailure(StatusCode::BAD_GATEWAY.as_u16(), e);
+        let text = response.text().await.map_err(|e| bad_gateway(e.to_string()))?;
+        let body = serde_json::from_str::<Value>(&text).map_err(|e| bad_gateway(e.to_string()))?;
+
+        match cached {
+            Some(_) => self.metrics.record_cache_lookup("response", "revalidated"),
+            None => self.metrics.record_cache_lookup("response", "miss"),
+        }
+        self.response_cache.store(key, etag, last_modified, &text);
+        self.recordings.record_json(&url, &body);
+
+        Ok(body)
     }
 
     fn resolve_url(&self, path: &str) -> Result<Url> {
@@ -46,30 +172,98 @@ impl HttpClient {
 
         self.base_url
             .as_ref()
-            .ok_or_else(|| AppError::ApiFailure(format!("Cannot resolve relative '{}'", path)))?
+            .ok_or_else(|| AppError::Unexpected(format!("Cannot resolve relative '{}'", path)))?
             .join(path)
-            .map_err(|e| AppError::ApiFailure(e.to_string()))
+            .map_err(|e| AppError::Unexpected(e.to_string()))
     }
 
-    async fn request(&self, method: Method, endpoint: &str) -> Result<Response> {
-        let mut request = self.client.request(method, self.resolve_url(endpoint)?);
-
-        if let Some(auth) = &self.basic_auth {
-            request = auth.apply(request);
-        }
+    async fn request(&self, method: Method, endpoint: &str, cached: Option<&CachedResponse>) -> Result<Response> {
+        let url = self.resolve_url(endpoint)?;
+        let host = url.host_str().unwrap_or_default().to_string();
+
+        // only idempotent requests are safe to send more than once
+        let max_retries = match method {
+            Method::GET | Method::HEAD => self.retry_policy.max_retries,
+            _ => 0,
+        };
+
+        // the retries belong to the same request, the circuit is checked and told about
+        // the outcome once for all of them
+        self.circuit_breaker.check(&host)?;
+
+        let mut attempt = 0;
+        let mut reauthenticated = false;
+        loop {
+            let mut request = self.client.request(method.clone(), url.clone());
+            if let Some(auth) = &self.authentication {
+                request = auth.authenticate(request).await?;
+            }
+            if let Some(cached) = cached {
+                request = cached.apply(request);
+            }
+            if let Some(request_id) = &self.request_id {
+                request = request.header(REQUEST_ID_HEADER, request_id);
+            }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| AppError::ApiFailure(e.to_string()))?;
+            let can_retry = attempt < max_retries;
+            let span = info_span!(
+                "upstream",
+                method = %method,
+                resource = %resource_type(url.path()),
+                attempt,
+                status = field::Empty,
+            );
+            let started = Instant::now();
+            let result = request.send().instrument(span.clone()).await;
+
+            let status = match &result {
+                Ok(response) => response.status().as_u16().to_string(),
+                Err(_) => "error".to_string(),
+            };
+            span.record("status", status.as_str());
+            self.metrics.observe_upstream(url.path(), &status, started.elapsed());
+
+            match result {
+                // expired or revoked credentials are renewed once before giving up
+                Ok(response)
+                    if response.status() == StatusCode::UNAUTHORIZED
+                        && !reauthenticated
+                        && self.authentication.as_ref().is_some_and(|auth| auth.invalidate()) =>
+                {
+                    reauthenticated = true;
+                    continue;
+                }
+                Ok(response) if !response.status().is_server_error() => {
+                    self.circuit_breaker.record_success(&host);
+
+                    if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED {
+                        return Ok(response);
+                    }
+
+                    let status = response.status().as_u16();
+                    let body = response.text().await.unwrap_or_default();
+                    self.recordings.record(&url, status, &body);
+
+                    return Err(AppError::ApiFailure(status, body));
+                }
+                Ok(response) => {
+                    if !can_retry {
+                        self.circuit_breaker.record_failure(&host);
+                        let status = response.status().as_u16();
+                        return Err(AppError::ApiFailure(status, response.text().await.unwrap_or_default()));
+                    }
+                }
+                Err(e) => {
+                    if !can_retry || !(e.is_timeout() || e.is_connect()) {
+                        self.circuit_breaker.record_failure(&host);
+                        return Err(AppError::External(type_name::<Self>(), e.to_string()));
+                    }
+                }
+            }
 
-        if response.status().is_success() {
-            return Ok(response);
+            wait(self.retry_policy.delay(attempt)).await;
+            attempt += 1;
         }
-
-        Err(AppError::ApiFailure(
-            response.text().await.unwrap().to_string(),
-        ))
     }
 }
 
@@ -77,52 +271,76 @@ impl Default for HttpClient {
     fn default() -> Self {
         HttpClient {
             base_url: None,
-            basic_auth: None,
+            authentication: None,
             client: Client::new(),
+            retry_policy: RetryPolicy::default(),
+            circuit_breaker: CircuitBreaker::default(),
+            response_cache: ResponseCache::default(),
+            in_flight: SingleFlight::default(),
+            metrics: Metrics::default(),
+            request_id: None,
+            recordings: Recordings::default(),
         }
     }
 }
 
-impl BasicAuth {
-    fn apply(&self, request: RequestBuilder) -> RequestBuilder {
-        request.basic_auth(
-            self.username.expose_secret().to_string(),
-            Some(self.password.expose_secret().to_string()),
-        )
+impl RetryPolicy {
+    /// Delay before the given retry, doubled on every attempt and jittered so that the
+    /// workers don't hit a recovering upstream in lockstep.
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter = f64::from(getrandom::u32().unwrap_or(u32::MAX)) / f64::from(u32::MAX);
+
+        delay.mul_f64(0.5 + jitter / 2.0)
     }
 }
 
-impl From<(&str, &str)> for BasicAuth {
-    fn from(value: (&str, &str)) -> Self {
-        let (username, password) = value;
-        Self {
-            username: SecretString::new(Box::from(username.to_string())),
-            password: SecretString::new(Box::from(password.to_string())),
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
         }
     }
 }
 
+fn header_value(response: &Response, name: HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
+
+async fn wait(duration: Duration) {
+    actix_web::rt::time::sleep(duration).await;
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use mockito::{Mock, Server};
+    use crate::utilities::{ClientCredentials, TrafficMode};
+    use mockito::{Matcher, Mock, Server};
 
     #[test]
     fn creation_succeeds_when_using_default_values() {
         let http_client = HttpClient::default();
         assert!(http_client.base_url.is_none());
-        assert!(http_client.basic_auth.is_none());
+        assert!(http_client.authentication.is_none());
     }
 
     #[test]
     fn creation_succeeds_when_custom_config_is_valid() {
         let http_client_mock = http_client_mock("https://localhost.dev/");
         let base_url = http_client_mock.base_url.unwrap();
-        let basic_auth = http_client_mock.basic_auth.unwrap();
+        let authentication = http_client_mock.authentication.unwrap();
 
         assert_eq!(base_url.as_str(), "https://localhost.dev/");
-        assert_eq!(basic_auth.username.expose_secret(), "test");
-        assert_eq!(basic_auth.password.expose_secret(), "123456");
+        assert_eq!(authentication.fingerprint(), BasicAuth::from(("test", "123456")).fingerprint());
     }
 
     #[test]
@@ -155,7 +373,7 @@ pub mod tests {
     async fn request_succeeds_when_endpoint_is_valid() {
         let mut server = Server::new_async().await;
         let http_client_mock = http_client_mock(&server.url());
-        let request_mock = request_mock(&mut server, "/api/v1/resource", 200).await;
+        let request_mock = request_mock(&mut server, "/api/v1/resource", 200, 1).await;
         let response = http_client_mock.get_json("/api/v1/resource").await;
 
         assert!(response.is_ok());
@@ -165,8 +383,8 @@ pub mod tests {
     #[actix_rt::test]
     async fn request_fails_when_external_service_returns_error() {
         let mut server = Server::new_async().await;
-        let http_client_mock = http_client_mock(&server.url());
-        let request_mock = request_mock(&mut server, "/api/v1/resource", 500).await;
+        let http_client_mock = http_client_mock(&server.url()).retries(0, Duration::from_millis(1));
+        let request_mock = request_mock(&mut server, "/api/v1/resource", 500, 1).await;
         let response = http_client_mock.get_json("/api/v1/resource").await;
 
         assert!(response.is_err());
@@ -191,19 +409,536 @@ pub mod tests {
         assert!(request_mock.matched_async().await); // ensures it works
     }
 
+    #[actix_rt::test]
+    async fn request_sends_request_id_when_it_is_given() {
+        let mut server = Server::new_async().await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header(REQUEST_ID_HEADER, "3f1c9a2e-6b1d-4c55-9a43-0d2f6f1f2a10")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body_from_file("tests/fixtures/basic_request_200.json")
+            .create_async()
+            .await;
+
+        let response = http_client_mock(&server.url())
+            .request_id("3f1c9a2e-6b1d-4c55-9a43-0d2f6f1f2a10")
+            .get_json("/api/v1/resource")
+            .await;
+
+        assert!(response.is_ok());
+        assert!(request_mock.matched_async().await);
+    }
+
+    #[actix_rt::test]
+    async fn request_succeeds_when_upstream_recovers_before_retries_run_out() {
+        let mut server = Server::new_async().await;
+        let failing_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let request_mock = request_mock(&mut server, "/api/v1/resource", 200, 1).await;
+
+        let response = http_client_mock(&server.url()).get_json("/api/v1/resource").await;
+
+        assert!(response.is_ok());
+        failing_mock.assert_async().await;
+        assert!(request_mock.matched_async().await);
+    }
+
+    #[actix_rt::test]
+    async fn request_records_every_attempt_when_metrics_are_given() {
+        let mut server = Server::new_async().await;
+        let metrics = Metrics::default();
+        let http_client = http_client_mock(&server.url()).metrics(metrics.clone());
+        server
+            .mock("GET", "/jsonapi/node/article")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        request_mock(&mut server, "/jsonapi/node/article", 200, 1).await;
+
+        http_client.get_json("/jsonapi/node/article").await.unwrap();
+        let rendered = metrics.render();
+
+        assert!(rendered.contains(
+            r#"website_upstream_request_duration_seconds_count{resource="node--article",status="503"} 1"#
+        ));
+        assert!(rendered.contains(
+            r#"website_upstream_request_duration_seconds_count{resource="node--article",status="200"} 1"#
+        ));
+        assert!(rendered.contains(r#"website_cache_lookups_total{cache="response",outcome="miss"} 1"#));
+    }
+
+    #[actix_rt::test]
+    async fn request_fails_when_upstream_keeps_failing() {
+        let mut server = Server::new_async().await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let response = http_client_mock(&server.url()).get_json("/api/v1/resource").await;
+
+        assert!(matches!(response, Err(AppError::ApiFailure(_, _))));
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_is_not_retried_when_upstream_returns_client_error() {
+        let mut server = Server::new_async().await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let response = http_client_mock(&server.url()).get_json("/api/v1/resource").await;
+
+        assert!(response.is_err());
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_fails_fast_when_circuit_is_open() {
+        let mut server = Server::new_async().await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(500)
+            .expect(2)
+            .create_async()
+            .await;
+        let http_client = http_client_mock(&server.url())
+            .retries(0, Duration::from_millis(1))
+            .circuit_breaker(CircuitBreaker::default().failure_threshold(2));
+
+        let _ = http_client.get_json("/api/v1/resource").await;
+        let _ = http_client.get_json("/api/v1/resource").await;
+        let response = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(response, Err(AppError::External(_, _))));
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_counts_one_failure_when_retries_are_exhausted() {
+        let mut server = Server::new_async().await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(503)
+            .expect(6)
+            .create_async()
+            .await;
+        let http_client = http_client_mock(&server.url())
+            .retries(2, Duration::from_millis(1))
+            .circuit_breaker(CircuitBreaker::default().failure_threshold(2));
+
+        let first = http_client.get_json("/api/v1/resource").await;
+        let second = http_client.get_json("/api/v1/resource").await;
+        let fail_fast = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(first, Err(AppError::ApiFailure(503, _))));
+        assert!(matches!(second, Err(AppError::ApiFailure(503, _))));
+        assert!(matches!(fail_fast, Err(AppError::External(_, _))));
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_closes_circuit_when_upstream_recovers() {
+        let mut server = Server::new_async().await;
+        let failing_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+        let request_mock = request_mock(&mut server, "/api/v1/resource", 200, 1).await;
+        let http_client = http_client_mock(&server.url())
+            .retries(0, Duration::from_millis(1))
+            .circuit_breaker(
+                CircuitBreaker::default()
+                    .failure_threshold(1)
+                    .cooldown(Duration::from_millis(10)),
+            );
+
+        let first = http_client.get_json("/api/v1/resource").await;
+        let fail_fast = http_client.get_json("/api/v1/resource").await;
+        actix_rt::time::sleep(Duration::from_millis(20)).await;
+        let probe = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(first, Err(AppError::ApiFailure(_, _))));
+        assert!(matches!(fail_fast, Err(AppError::External(_, _))));
+        assert!(probe.is_ok());
+        failing_mock.assert_async().await;
+        assert!(request_mock.matched_async().await);
+    }
+
+    #[actix_rt::test]
+    async fn request_fails_when_upstream_does_not_answer_in_time() {
+        // the connection is queued by the listener, but nothing ever answers it
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let http_client = http_client_mock(&url)
+            .timeouts(Duration::from_millis(50), Duration::from_millis(50))
+            .retries(1, Duration::from_millis(1));
+
+        let response = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(response, Err(AppError::External(_, _))));
+    }
+
+    #[test]
+    fn retry_delay_grows_exponentially_within_bounds() {
+        let policy = RetryPolicy::default();
+
+        for attempt in 0..6 {
+            let ceiling = (policy.base_delay * 2u32.pow(attempt)).min(policy.max_delay);
+            let delay = policy.delay(attempt);
+
+            assert!(delay >= ceiling / 2 && delay <= ceiling);
+        }
+    }
+
+    #[actix_rt::test]
+    async fn get_json_reuses_body_when_etag_is_not_modified() {
+        let mut server = Server::new_async().await;
+        let metrics = Metrics::default();
+        let http_client = http_client_mock(&server.url()).metrics(metrics.clone());
+        let first_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_header("ETag", "\"v1\"")
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let first = http_client.get_json("/api/v1/resource").await.unwrap();
+        first_mock.assert_async().await;
+
+        let conditional_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("If-None-Match", "\"v1\"")
+            .with_status(304)
+            .create_async()
+            .await;
+        let second = http_client.get_json("/api/v1/resource").await.unwrap();
+
+        assert_eq!(first, second);
+        assert!(conditional_mock.matched_async().await);
+        assert_eq!(response_lookups(&metrics), (1, 0, 1));
+    }
+
+    #[actix_rt::test]
+    async fn get_json_downloads_body_when_content_has_changed() {
+        let mut server = Server::new_async().await;
+        let metrics = Metrics::default();
+        let http_client = http_client_mock(&server.url()).metrics(metrics.clone());
+        server
+            .mock("GET", "/api/v1/resource")
+            .with_status(200)
+            .with_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+            .with_body(r#"{"version": 1}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        http_client.get_json("/api/v1/resource").await.unwrap();
+
+        let conditional_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("If-Modified-Since", "Wed, 21 Oct 2015 07:28:00 GMT")
+            .with_status(200)
+            .with_body(r#"{"version": 2}"#)
+            .create_async()
+            .await;
+        let second = http_client.get_json("/api/v1/resource").await.unwrap();
+
+        assert_eq!(second["version"], 2);
+        assert!(conditional_mock.matched_async().await);
+        assert_eq!(response_lookups(&metrics), (0, 1, 1));
+    }
+
+    #[actix_rt::test]
+    async fn get_json_sends_unconditional_request_when_response_has_no_validators() {
+        let mut server = Server::new_async().await;
+        let metrics = Metrics::default();
+        let http_client = http_client_mock(&server.url()).metrics(metrics.clone());
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("If-None-Match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        http_client.get_json("/api/v1/resource").await.unwrap();
+        http_client.get_json("/api/v1/resource").await.unwrap();
+
+        request_mock.assert_async().await;
+        assert_eq!(response_lookups(&metrics), (0, 0, 2));
+    }
+
+    #[actix_rt::test]
+    async fn get_json_sends_unconditional_request_when_credentials_are_different() {
+        let mut server = Server::new_async().await;
+        let http_client = http_client_mock(&server.url());
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("If-None-Match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("ETag", "\"v1\"")
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let other_client = http_client.clone().basic_auth("other", "secret");
+
+        http_client.get_json("/api/v1/resource").await.unwrap();
+        other_client.get_json("/api/v1/resource").await.unwrap();
+
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn get_json_fails_with_api_failure_when_not_modified_is_unsolicited() {
+        let mut server = Server::new_async().await;
+        let http_client = http_client_mock(&server.url());
+        server
+            .mock("GET", "/api/v1/resource")
+            .with_status(304)
+            .create_async()
+            .await;
+
+        let result = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(result, Err(AppError::ApiFailure(304, _))));
+    }
+
+    #[actix_rt::test]
+    async fn get_json_sends_one_request_when_calls_are_concurrent() {
+        let mut server = Server::new_async().await;
+        let http_client = http_client_mock(&server.url());
+        let request_mock = request_mock(&mut server, "/api/menu_items/main", 200, 1).await;
+        let worker_client = http_client.clone();
+
+        let (first, second, third) = tokio::join!(
+            http_client.get_json("/api/menu_items/main"),
+            worker_client.get_json("/api/menu_items/main"),
+            http_client.get_json("/api/menu_items/main")
+        );
+
+        request_mock.assert_async().await;
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert!(third.is_ok());
+    }
+
+    #[actix_rt::test]
+    async fn get_json_sends_one_request_per_url_when_calls_are_concurrent() {
+        let mut server = Server::new_async().await;
+        let http_client = http_client_mock(&server.url());
+        let main_mock = request_mock(&mut server, "/api/menu_items/main", 200, 1).await;
+        let social_mock = request_mock(&mut server, "/api/menu_items/social-network", 200, 1).await;
+
+        let _ = tokio::join!(
+            http_client.get_json("/api/menu_items/main"),
+            http_client.get_json("/api/menu_items/social-network"),
+            http_client.get_json("/api/menu_items/main"),
+            http_client.get_json("/api/menu_items/social-network")
+        );
+
+        main_mock.assert_async().await;
+        social_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn get_json_is_not_shared_when_credentials_are_different() {
+        let mut server = Server::new_async().await;
+        let http_client = http_client_mock(&server.url());
+        let request_mock = server
+            .mock("GET", "/api/menu_items/main")
+            .with_status(200)
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let other_client = http_client.clone().basic_auth("other", "secret");
+
+        let _ = tokio::join!(
+            http_client.get_json("/api/menu_items/main"),
+            other_client.get_json("/api/menu_items/main")
+        );
+
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_sends_bearer_token_when_using_client_credentials() {
+        let mut server = Server::new_async().await;
+        let token_mock = token_mock(&mut server, "first", 300, 1).await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("Authorization", "Bearer first")
+            .with_status(200)
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let http_client = HttpClient::default()
+            .base_url(&server.url())
+            .authentication(credentials_mock(&server.url()));
+
+        http_client.get_json("/api/v1/resource").await.unwrap();
+        http_client.get_json("/api/v1/resource").await.unwrap();
+
+        token_mock.assert_async().await;
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_renews_token_when_upstream_rejects_it() {
+        let mut server = Server::new_async().await;
+        let revoked_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("Authorization", "Bearer revoked")
+            .with_status(401)
+            .expect(1)
+            .create_async()
+            .await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .match_header("Authorization", "Bearer renewed")
+            .with_status(200)
+            .with_body(r#"{"message": "Hello, World!"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        // the token server hands out the revoked token first, then the renewed one
+        let revoked_token_mock = token_mock(&mut server, "revoked", 300, 1).await;
+        let renewed_token_mock = token_mock(&mut server, "renewed", 300, 1).await;
+        let http_client = HttpClient::default()
+            .base_url(&server.url())
+            .authentication(credentials_mock(&server.url()));
+
+        let response = http_client.get_json("/api/v1/resource").await;
+
+        assert!(response.is_ok());
+        revoked_token_mock.assert_async().await;
+        renewed_token_mock.assert_async().await;
+        revoked_mock.assert_async().await;
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn request_fails_when_renewed_token_is_rejected_as_well() {
+        let mut server = Server::new_async().await;
+        token_mock(&mut server, "rejected", 300, 2).await;
+        let request_mock = server
+            .mock("GET", "/api/v1/resource")
+            .with_status(401)
+            .expect(2)
+            .create_async()
+            .await;
+        let http_client = HttpClient::default()
+            .base_url(&server.url())
+            .authentication(credentials_mock(&server.url()));
+
+        let response = http_client.get_json("/api/v1/resource").await;
+
+        assert!(matches!(response, Err(AppError::ApiFailure(_, _))));
+        request_mock.assert_async().await;
+    }
+
+    #[actix_rt::test]
+    async fn get_json_replays_responses_when_they_were_recorded() {
+        let mut server = Server::new_async().await;
+        let directory = std::env::temp_dir().join(format!("recordings-{}", uuid::Uuid::new_v4()));
+        let recordings = Recordings::default().directory(directory.to_str().unwrap());
+        request_mock(&mut server, "/api/v1/resource", 200, 1).await;
+        let missing_mock = server
+            .mock("GET", "/api/v1/missing")
+            .with_status(404)
+            .with_body(r#"{"message": "Not found"}"#)
+            .create_async()
+            .await;
+
+        let recorder = http_client_mock(&server.url()).recordings(recordings.clone().mode(TrafficMode::Record));
+        let recorded = recorder.get_json("/api/v1/resource").await.unwrap();
+        let _ = recorder.get_json("/api/v1/missing").await;
+
+        // the replaying client never reaches the server, nor needs credentials
+        let replayer = HttpClient::default()
+            .base_url("https://cms.localhost.dev")
+            .recordings(recordings.mode(TrafficMode::Replay));
+
+        assert_eq!(replayer.get_json("/api/v1/resource").await.unwrap(), recorded);
+        assert!(matches!(replayer.get_json("/api/v1/missing").await, Err(AppError::ApiFailure(_, _))));
+        assert!(matches!(replayer.get_json("/api/v1/unknown").await, Err(AppError::Unexpected(_))));
+        assert!(missing_mock.matched_async().await);
+    }
+
     pub fn http_client_mock(url: &str) -> HttpClient {
         HttpClient::default()
             .base_url(url)
             .basic_auth("test", "123456")
+            .retries(2, Duration::from_millis(1))
     }
 
-    async fn request_mock(server: &mut Server, path: &str, status: usize) -> Mock {
+    async fn request_mock(server: &mut Server, path: &str, status: usize, expected_hits: usize) -> Mock {
         server
             .mock("GET", path)
             .with_status(status)
             .with_header("Content-Type", "application/json")
             .with_body_from_file(format!("tests/fixtures/basic_request_{status}.json").to_string())
             .match_header("Authorization", "Basic dGVzdDoxMjM0NTY=")
+            .expect(expected_hits)
+            .create_async()
+            .await
+    }
+
+    /// Hits, revalidations and misses of the response cache, as recorded in the metrics.
+    fn response_lookups(metrics: &Metrics) -> (u64, u64, u64) {
+        let rendered = metrics.render();
+        let count = |outcome: &str| {
+            let series = format!(r#"website_cache_lookups_total{{cache="response",outcome="{outcome}"}} "#);
+            rendered
+                .lines()
+                .find_map(|line| line.strip_prefix(&series)?.parse().ok())
+                .unwrap_or(0)
+        };
+
+        (count("hit"), count("revalidated"), count("miss"))
+    }
+
+    pub fn credentials_mock(url: &str) -> ClientCredentials {
+        ClientCredentials::new(&format!("{url}/oauth/token"), "website", "s3cr3t")
+            .unwrap()
+            .scope("content")
+    }
+
+    /// Token endpoint accepting the credentials of `credentials_mock`, expected to be hit `hits` times.
+    pub async fn token_mock(server: &mut Server, token: &str, expires_in: u64, hits: usize) -> Mock {
+        server
+            .mock("POST", "/oauth/token")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+                Matcher::UrlEncoded("client_id".into(), "website".into()),
+                Matcher::UrlEncoded("client_secret".into(), "s3cr3t".into()),
+                Matcher::UrlEncoded("scope".into(), "content".into()),
+            ]))
+            .with_status(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(format!(
+                r#"{{"token_type": "Bearer", "access_token": "{token}", "expires_in": {expires_in}}}"#
+            ))
+            .expect(hits)
             .create_async()
             .await
     }
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Output of the logs: JSON lines for the log collector, or text readable by humans.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogFormat {
+    Json,
+    Pretty,
+}
+
+/// Installs the global subscriber, the levels are read from `RUST_LOG` and default to `info`.
+///
+/// Closing a span logs it with its timing, e.g. how long an upstream call took.
+pub fn init_logging(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE);
+
+    match format {
+        LogFormat::Json => subscriber
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .init(),
+        LogFormat::Pretty => subscriber.pretty().init(),
+    }
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            LogFormat::Pretty
+        } else {
+            LogFormat::Json
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "json" => Ok(LogFormat::Json),
+            "pretty" => Ok(LogFormat::Pretty),
+            other => Err(format!("LOG_FORMAT `{other}` is not supported")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("json", LogFormat::Json)]
+    #[case("pretty", LogFormat::Pretty)]
+    fn parse_succeeds_when_format_is_supported(#[case] value: &str, #[case] expected: LogFormat) {
+        assert_eq!(value.parse::<LogFormat>().unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_fails_when_format_is_unknown() {
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+}
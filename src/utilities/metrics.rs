@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+use crate::application::domain::core::{AppError, Result};
+
+const NAMESPACE: &str = "website";
+
+/// Prometheus metrics of the website, registered in the given registry.
+///
+/// Clones share the same registry and series, so every worker reports into the same metrics.
+/// Tests use their own registry and assert on it.
+///
+/// Each registry can only hold one `Metrics`, share its clones instead of creating another.
+#[derive(Clone, Debug)]
+pub struct Metrics {
+    registry: Registry,
+    requests: HistogramVec,
+    upstream_requests: HistogramVec,
+    cache_lookups: IntCounterVec,
+    mapper_failures: IntCounterVec,
+    open_circuits: IntGaugeVec,
+}
+
+impl Metrics {
+    /// Creates the metrics, failing when the registry already holds metrics with the same names.
+    pub fn new(registry: Registry) -> Result<Self> {
+        let metrics = Metrics {
+            requests: HistogramVec::new(
+                HistogramOpts::new("http_request_duration_seconds", "Duration of the rendered requests.")
+                    .namespace(NAMESPACE),
+                &["route", "method", "status"],
+            )
+            .map_err(unexpected)?,
+            upstream_requests: HistogramVec::new(
+                HistogramOpts::new("upstream_request_duration_seconds", "Duration of the requests sent to the CMS.")
+                    .namespace(NAMESPACE),
+                &["resource", "status"],
+            )
+            .map_err(unexpected)?,
+            cache_lookups: IntCounterVec::new(
+                Opts::new("cache_lookups_total", "Outcome of the cache lookups.").namespace(NAMESPACE),
+                &["cache", "outcome"],
+            )
+            .map_err(unexpected)?,
+            mapper_failures: IntCounterVec::new(
+                Opts::new("mapper_failures_total", "CMS entities that couldn't be mapped into the domain.")
+                    .namespace(NAMESPACE),
+                &["entity"],
+            )
+            .map_err(unexpected)?,
+            open_circuits: IntGaugeVec::new(
+                Opts::new("circuit_breaker_open", "Whether requests to the host fail fast (1) or not (0).")
+                    .namespace(NAMESPACE),
+                &["host"],
+            )
+            .map_err(unexpected)?,
+            registry,
+        };
+
+        let registry = &metrics.registry;
+        registry.register(Box::new(metrics.requests.clone())).map_err(unexpected)?;
+        registry.register(Box::new(metrics.upstream_requests.clone())).map_err(unexpected)?;
+        registry.register(Box::new(metrics.cache_lookups.clone())).map_err(unexpected)?;
+        registry.register(Box::new(metrics.mapper_failures.clone())).map_err(unexpected)?;
+        registry.register(Box::new(metrics.open_circuits.clone())).map_err(unexpected)?;
+
+        Ok(metrics)
+    }
+
+    pub fn observe_request(&self, route: &str, method: &str, status: u16, duration: Duration) {
+        self.requests
+            .with_label_values(&[route, method, &status.to_string()])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Records a request sent to the CMS, `status` is `error` when no response came back.
+    pub fn observe_upstream(&self, path: &str, status: &str, duration: Duration) {
+        self.upstream_requests
+            .with_label_values(&[resource_type(path).as_str(), status])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn record_cache_lookup(&self, cache: &str, outcome: &str) {
+        self.cache_lookups.with_label_values(&[cache, outcome]).inc();
+    }
+
+    pub fn record_mapper_failure(&self, entity: &str) {
+        self.mapper_failures.with_label_values(&[entity]).inc();
+    }
+
+    pub fn set_circuit_open(&self, host: &str, open: bool) {
+        self.open_circuits.with_label_values(&[host]).set(i64::from(open));
+    }
+
+    /// Every metric of the registry, in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new(Registry::new()).expect("a new registry holds no metrics")
+    }
+}
+
+fn unexpected(e: prometheus::Error) -> AppError {
+    AppError::Unexpected(e.to_string())
+}
+
+/// JSON:API resource type requested by the path, e.g. `node--article` for `/jsonapi/node/article/{id}`.
+///
+/// Other endpoints are labelled by their first segment, so ids and slugs never become labels.
+pub fn resource_type(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+    match segments.next() {
+        Some("jsonapi") => segments.take(2).collect::<Vec<_>>().join("--"),
+        Some("api") => segments.next().unwrap_or("api").to_string(),
+        Some(segment) => segment.to_string(),
+        None => "root".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("/jsonapi/node/article?include=tags", "node--article")]
+    #[case("/jsonapi/node/portfolio/8b2f5e1c-5c4d-4f0e-9a1e-1f2d3c4b5a69", "node--portfolio")]
+    #[case("/jsonapi/block_content/site_branding", "block_content--site_branding")]
+    #[case("/api/menu_items/main", "menu_items")]
+    #[case("/router/translate-path?path=/en/articles", "router")]
+    #[case("/", "root")]
+    fn resource_type_succeeds_when_path_is_valid(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(resource_type(path), expected);
+    }
+
+    #[test]
+    fn render_succeeds_when_metrics_are_recorded() {
+        let metrics = Metrics::default();
+        metrics.observe_request("/{lang}/articles", "GET", 200, Duration::from_millis(20));
+        metrics.observe_upstream("/jsonapi/node/article", "200", Duration::from_millis(10));
+        metrics.record_cache_lookup("content", "fresh");
+        metrics.record_mapper_failure("node--article");
+        metrics.set_circuit_open("cms.localhost.dev", true);
+
+        let rendered = metrics.render();
+
+        assert!(rendered.contains(
+            r#"website_http_request_duration_seconds_count{method="GET",route="/{lang}/articles",status="200"} 1"#
+        ));
+        assert!(rendered.contains(
+            r#"website_upstream_request_duration_seconds_count{resource="node--article",status="200"} 1"#
+        ));
+        assert!(rendered.contains(r#"website_cache_lookups_total{cache="content",outcome="fresh"} 1"#));
+        assert!(rendered.contains(r#"website_mapper_failures_total{entity="node--article"} 1"#));
+        assert!(rendered.contains(r#"website_circuit_breaker_open{host="cms.localhost.dev"} 1"#));
+    }
+
+    #[test]
+    fn counters_are_shared_when_metrics_are_cloned() {
+        let metrics = Metrics::default();
+        metrics.clone().record_mapper_failure("node--project");
+        metrics.record_mapper_failure("node--project");
+
+        assert_eq!(metrics.mapper_failures.with_label_values(&["node--project"]).get(), 2);
+    }
+
+    #[test]
+    fn registry_is_injected_when_metrics_are_created() {
+        let registry = Registry::new();
+        let metrics = Metrics::new(registry.clone()).unwrap();
+        metrics.record_cache_lookup("response", "hit");
+
+        assert_eq!(registry.gather().len(), 1);
+    }
+
+    #[test]
+    fn new_fails_when_registry_already_holds_metrics() {
+        let registry = Registry::new();
+        Metrics::new(registry.clone()).unwrap();
+
+        let result = Metrics::new(registry);
+
+        assert!(matches!(result, Err(AppError::Unexpected(_))));
+    }
+}
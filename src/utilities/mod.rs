@@ -1,5 +1,31 @@
+mod authentication;
 mod cache_client;
+#[cfg(feature = "ssr")]
+mod circuit_breaker;
+mod client_credentials;
+#[cfg(feature = "ssr")]
 mod http_client;
+#[cfg(feature = "ssr")]
+mod logging;
+#[cfg(feature = "ssr")]
+mod metrics;
+mod rate_limiter;
+mod recordings;
+mod response_cache;
+mod single_flight;
 
+pub use authentication::*;
 pub use cache_client::*;
+#[cfg(feature = "ssr")]
+pub use circuit_breaker::*;
+pub use client_credentials::*;
+#[cfg(feature = "ssr")]
 pub use http_client::*;
+#[cfg(feature = "ssr")]
+pub use logging::*;
+#[cfg(feature = "ssr")]
+pub use metrics::*;
+pub use rate_limiter::*;
+pub use recordings::*;
+pub use response_cache::*;
+pub use single_flight::*;
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::application::domain::core::{AppError, Result};
+
+/// In-memory sliding window rate limiter keyed by an arbitrary string, usually the client ip.
+///
+/// Clones share the same counters, so a single instance can be registered as application
+/// data and extracted from any request handler.
+///
+/// Forwarded headers are only believed as far as they were set by the trusted proxies,
+/// otherwise any client could pick a new key on every request.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    max_requests: usize,
+    window: Duration,
+    trusted_proxies: Vec<IpAddr>,
+    hits: Arc<Mutex<HashMap<String, Vec<Instant>>>>,
+}
+
+impl RateLimiter {
+    pub fn max_requests(mut self, max_requests: usize) -> Self {
+        self.max_requests = max_requests;
+        self
+    }
+
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    pub fn trusted_proxies(mut self, trusted_proxies: Vec<IpAddr>) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
+
+    /// Returns the key of a client from its peer address and `X-Forwarded-For` header.
+    ///
+    /// Proxies append the address they received the request from, so the header is read from
+    /// the right and the first hop that isn't a trusted proxy is the client. The entries left
+    /// of it were sent by the client itself and can't be believed.
+    pub fn client_key(&self, peer: Option<IpAddr>, forwarded_for: Option<&str>) -> String {
+        let Some(peer) = peer else {
+            return "unknown".to_string();
+        };
+        if !self.trusted_proxies.contains(&peer) {
+            return peer.to_string();
+        }
+
+        let hops = forwarded_for
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|hop| !hop.is_empty())
+            .collect::<Vec<_>>();
+
+        hops.iter()
+            .rev()
+            .find(|hop| !hop.parse().is_ok_and(|ip| self.trusted_proxies.contains(&ip)))
+            .or(hops.first())
+            .map(|hop| hop.to_string())
+            .unwrap_or(peer.to_string())
+    }
+
+    /// How long the key has to wait until its oldest hit leaves the window.
+    pub fn retry_after(&self, key: &str) -> Duration {
+        let now = Instant::now();
+        self.hits
+            .lock()
+            .ok()
+            .and_then(|hits| hits.get(key)?.first().copied())
+            .map(|oldest| (oldest + self.window).saturating_duration_since(now))
+            .unwrap_or_default()
+    }
+
+    /// Registers a hit for the given key, failing when the key already reached the limit
+    /// within the current window.
+    pub fn check(&self, key: &str) -> Result<()> {
+        let now = Instant::now();
+        let mut hits = self
+            .hits
+            .lock()
+            .map_err(|e| AppError::Unexpected(e.to_string()))?;
+
+        // forget the keys that have been idle for a whole window, so the map doesn't grow forever
+        hits.retain(|_, instants| {
+            instants.retain(|instant| now.duration_since(*instant) < self.window);
+            !instants.is_empty()
+        });
+
+        let instants = hits.entry(key.to_string()).or_default();
+
+        if instants.len() >= self.max_requests {
+            return Err(AppError::TooManyRequests(key.to_string()));
+        }
+
+        instants.push(now);
+        Ok(())
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter {
+            max_requests: 5,
+            window: Duration::from_secs(3600),
+            trusted_proxies: Vec::new(),
+            hits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_succeeds_when_limit_is_not_reached() {
+        let limiter = RateLimiter::default().max_requests(2);
+
+        assert!(limiter.check("127.0.0.1").is_ok());
+        assert!(limiter.check("127.0.0.1").is_ok());
+    }
+
+    #[test]
+    fn check_fails_when_limit_is_reached() {
+        let limiter = RateLimiter::default().max_requests(2);
+        limiter.check("127.0.0.1").unwrap();
+        limiter.check("127.0.0.1").unwrap();
+
+        let result = limiter.check("127.0.0.1");
+
+        assert!(matches!(result, Err(AppError::TooManyRequests(_))));
+    }
+
+    #[test]
+    fn retry_after_succeeds_when_limit_is_reached() {
+        let limiter = RateLimiter::default().max_requests(1).window(Duration::from_secs(60));
+        limiter.check("127.0.0.1").unwrap();
+
+        let retry_after = limiter.retry_after("127.0.0.1");
+
+        assert!(retry_after > Duration::from_secs(59) && retry_after <= Duration::from_secs(60));
+        assert_eq!(limiter.retry_after("127.0.0.2"), Duration::ZERO);
+    }
+
+    #[test]
+    fn check_succeeds_when_keys_are_different() {
+        let limiter = RateLimiter::default().max_requests(1);
+
+        assert!(limiter.check("127.0.0.1").is_ok());
+        assert!(limiter.check("127.0.0.2").is_ok());
+    }
+
+    #[test]
+    fn check_succeeds_when_window_has_elapsed() {
+        let limiter = RateLimiter::default()
+            .max_requests(1)
+            .window(Duration::from_millis(10));
+        limiter.check("127.0.0.1").unwrap();
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(limiter.check("127.0.0.1").is_ok());
+    }
+
+    #[test]
+    fn check_shares_counters_when_limiter_is_cloned() {
+        let limiter = RateLimiter::default().max_requests(1);
+        limiter.clone().check("127.0.0.1").unwrap();
+
+        assert!(limiter.check("127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn client_key_is_peer_when_peer_is_not_trusted() {
+        let limiter = RateLimiter::default();
+
+        let key = limiter.client_key("10.0.0.1".parse().ok(), Some("203.0.113.7"));
+
+        assert_eq!(key, "10.0.0.1");
+    }
+
+    #[test]
+    fn client_key_is_forwarded_address_when_peer_is_trusted() {
+        let limiter = RateLimiter::default().trusted_proxies(vec!["10.0.0.1".parse().unwrap()]);
+
+        let key = limiter.client_key("10.0.0.1".parse().ok(), Some("203.0.113.7"));
+
+        assert_eq!(key, "203.0.113.7");
+    }
+
+    #[test]
+    fn client_key_is_first_untrusted_hop_when_leftmost_entry_is_spoofed() {
+        let limiter = RateLimiter::default().trusted_proxies(vec!["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()]);
+
+        let key = limiter.client_key("10.0.0.1".parse().ok(), Some("198.51.100.99, 203.0.113.7, 10.0.0.2"));
+
+        assert_eq!(key, "203.0.113.7");
+    }
+
+    #[test]
+    fn client_key_is_peer_when_trusted_proxy_forwards_nothing() {
+        let limiter = RateLimiter::default().trusted_proxies(vec!["10.0.0.1".parse().unwrap()]);
+
+        let key = limiter.client_key("10.0.0.1".parse().ok(), None);
+
+        assert_eq!(key, "10.0.0.1");
+    }
+}
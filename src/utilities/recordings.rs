@@ -0,0 +1,254 @@
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use reqwest::Url;
+use serde_json::Value;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::application::domain::core::{AppError, Result};
+
+/// Where the responses of the CMS come from.
+///
+/// - `Live`: the CMS answers every request.
+/// - `Record`: the CMS answers, and every response is written into the recordings directory.
+/// - `Replay`: nothing is sent, the responses are read from the recordings directory.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TrafficMode {
+    #[default]
+    Live,
+    Record,
+    Replay,
+}
+
+/// Responses of the CMS stored as files named like the test fixtures, e.g.
+/// `http_jsonapi_node_article_1b4e28ba_200.json`, so the site runs offline from them.
+///
+/// The file name holds the path of the request, a short hash of its path and query and the
+/// status of the response. The host isn't part of it, recordings work against any CMS.
+#[derive(Clone, Debug)]
+pub struct Recordings {
+    mode: TrafficMode,
+    directory: PathBuf,
+}
+
+impl Recordings {
+    pub fn mode(mut self, mode: TrafficMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn directory(mut self, directory: &str) -> Self {
+        self.directory = PathBuf::from(directory);
+        self
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.mode == TrafficMode::Replay
+    }
+
+    /// Writes the response into the directory, when recording.
+    ///
+    /// Failing to write doesn't fail the request, the response is still served.
+    pub fn record(&self, url: &Url, status: u16, body: &str) {
+        if self.mode != TrafficMode::Record {
+            return;
+        }
+
+        let stem = file_stem(url);
+        let path = self.directory.join(format!("{stem}_{status}.json"));
+        let result = fs::create_dir_all(&self.directory).and_then(|_| fs::write(&path, body));
+
+        if let Err(e) = result {
+            warn!("Failed to record `{url}` into `{}`: {e}", path.display());
+            return;
+        }
+
+        // a request is recorded once, the previous answers with another status are outdated
+        for (outdated, _) in self.find_all(&stem).into_iter().filter(|(_, s)| *s != status) {
+            if let Err(e) = fs::remove_file(&outdated) {
+                warn!("Failed to remove the outdated recording `{}`: {e}", outdated.display());
+            }
+        }
+    }
+
+    /// Writes a successful JSON response into the directory, when recording.
+    pub fn record_json(&self, url: &Url, body: &Value) {
+        if self.mode == TrafficMode::Record {
+            self.record(url, 200, &serde_json::to_string_pretty(body).unwrap_or_default());
+        }
+    }
+
+    /// Reads the recorded response of the request, failing when it was never recorded.
+    ///
+    /// Recorded error responses fail the same way the CMS answering them does.
+    pub fn replay(&self, url: &Url) -> Result<Value> {
+        let stem = file_stem(url);
+
+        let Some((path, status)) = self.find(&stem) else {
+            let message = format!(
+                "No recording of `{}` in `{}`, record it first with JSONAPI_TRAFFIC=record",
+                request_target(url),
+                self.directory.display()
+            );
+            error!("{message}");
+            return Err(AppError::Unexpected(message));
+        };
+
+        let body = fs::read_to_string(&path)
+            .map_err(|e| AppError::Unexpected(format!("{}: {e}", path.display())))?;
+
+        if !(200..300).contains(&status) {
+            return Err(AppError::ApiFailure(status, body));
+        }
+
+        serde_json::from_str(&body)
+            .map_err(|e| AppError::Deserialization(e.to_string(), path.display().to_string()))
+    }
+
+    fn find(&self, stem: &str) -> Option<(PathBuf, u16)> {
+        self.find_all(stem).into_iter().next()
+    }
+
+    /// Every recording of the request, whatever its status.
+    fn find_all(&self, stem: &str) -> Vec<(PathBuf, u16)> {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return Vec::new();
+        };
+
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let status = name.strip_prefix(stem)?.strip_prefix('_')?.strip_suffix(".json")?;
+
+                Some((entry.path(), status.parse().ok()?))
+            })
+            .collect()
+    }
+}
+
+impl Default for Recordings {
+    fn default() -> Self {
+        Recordings {
+            mode: TrafficMode::Live,
+            directory: PathBuf::from("tests/fixtures/recordings"),
+        }
+    }
+}
+
+impl FromStr for TrafficMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
+        match value {
+            "live" => Ok(TrafficMode::Live),
+            "record" => Ok(TrafficMode::Record),
+            "replay" => Ok(TrafficMode::Replay),
+            other => Err(format!("JSONAPI_TRAFFIC `{other}` is not supported")),
+        }
+    }
+}
+
+fn request_target(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{query}", url.path()),
+        None => url.path().to_string(),
+    }
+}
+
+/// File name of the request without its status, e.g. `http_jsonapi_node_article_1b4e28ba`.
+fn file_stem(url: &Url) -> String {
+    let path = url
+        .path()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "-"))
+        .collect::<Vec<_>>()
+        .join("_");
+    // a name based hash is stable across builds and machines, unlike the std hashers
+    let hash = Uuid::new_v5(&Uuid::NAMESPACE_URL, request_target(url).as_bytes()).simple().to_string();
+
+    format!("http_{path}_{}", &hash[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn recordings(mode: TrafficMode) -> Recordings {
+        let directory = std::env::temp_dir().join(format!("recordings-{}", Uuid::new_v4()));
+        Recordings::default().mode(mode).directory(directory.to_str().unwrap())
+    }
+
+    fn url(target: &str) -> Url {
+        Url::parse("https://cms.localhost.dev").unwrap().join(target).unwrap()
+    }
+
+    #[test]
+    fn file_stem_is_stable_when_hosts_are_different() {
+        let first = Url::parse("https://cms.localhost.dev/jsonapi/node/article?page[limit]=2").unwrap();
+        let second = Url::parse("http://127.0.0.1:8080/jsonapi/node/article?page[limit]=2").unwrap();
+
+        assert_eq!(file_stem(&first), file_stem(&second));
+        assert!(file_stem(&first).starts_with("http_jsonapi_node_article_"));
+    }
+
+    #[test]
+    fn file_stem_is_different_when_queries_are_different() {
+        assert_ne!(
+            file_stem(&url("/jsonapi/node/article?page[limit]=2")),
+            file_stem(&url("/jsonapi/node/article?page[limit]=10"))
+        );
+    }
+
+    #[test]
+    fn replay_succeeds_when_response_was_recorded() {
+        let recorder = recordings(TrafficMode::Record);
+        recorder.record_json(&url("/jsonapi/node/article"), &json!({"data": []}));
+
+        let replayed = recorder.clone().mode(TrafficMode::Replay).replay(&url("/jsonapi/node/article"));
+
+        assert_eq!(replayed.unwrap(), json!({"data": []}));
+    }
+
+    #[test]
+    fn replay_fails_with_api_failure_when_error_was_recorded() {
+        let recorder = recordings(TrafficMode::Record);
+        recorder.record(&url("/router/translate-path?path=/missing"), 404, r#"{"message": "Unable to resolve path"}"#);
+
+        let replayed = recorder.replay(&url("/router/translate-path?path=/missing"));
+
+        assert!(matches!(replayed, Err(AppError::ApiFailure(404, body)) if body.contains("Unable to resolve path")));
+    }
+
+    #[test]
+    fn replay_returns_last_response_when_request_was_recorded_twice() {
+        let recorder = recordings(TrafficMode::Record);
+        let target = url("/router/translate-path?path=/en/articles/rust");
+        recorder.record(&target, 404, r#"{"message": "Unable to resolve path"}"#);
+        recorder.record(&target, 200, r#"{"entity": {"uuid": "1b4e28ba"}}"#);
+
+        let replayed = recorder.replay(&target);
+
+        assert_eq!(replayed.unwrap(), json!({"entity": {"uuid": "1b4e28ba"}}));
+        assert_eq!(recorder.find_all(&file_stem(&target)).len(), 1);
+    }
+
+    #[test]
+    fn replay_fails_when_request_was_not_recorded() {
+        let replayed = recordings(TrafficMode::Replay).replay(&url("/jsonapi/node/project"));
+
+        assert!(matches!(replayed, Err(AppError::Unexpected(message)) if message.contains("/jsonapi/node/project")));
+    }
+
+    #[test]
+    fn record_is_skipped_when_traffic_is_live() {
+        let recorder = recordings(TrafficMode::Live);
+        recorder.record_json(&url("/jsonapi/node/article"), &json!({"data": []}));
+
+        assert!(recorder.find(&file_stem(&url("/jsonapi/node/article"))).is_none());
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use reqwest::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use reqwest::RequestBuilder;
+use serde_json::Value;
+
+use crate::application::domain::core::{AppError, Result};
+
+/// Bodies of the JSON responses stored next to their `ETag` and `Last-Modified` validators,
+/// so that the next request for the same url is sent as a conditional GET.
+///
+/// Entries are keyed like the upstream requests are shared, by url and credentials, and the
+/// least recently used one is dropped once `max_entries` are stored.
+///
+/// Clones share the same entries, and the bodies are shared between the entries and the
+/// lookups, so a hit doesn't copy the body.
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    max_entries: usize,
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: Arc<str>,
+    used_at: Instant,
+}
+
+impl ResponseCache {
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().ok()?;
+        let entry = entries.get_mut(key)?;
+        entry.used_at = Instant::now();
+
+        Some(entry.clone())
+    }
+
+    /// Stores the raw body, only when the response came with validators to send next time.
+    pub fn store(&self, key: &str, etag: Option<String>, last_modified: Option<String>, body: &str) {
+        if etag.is_none() && last_modified.is_none() {
+            return;
+        }
+
+        if let Ok(mut entries) = self.entries.lock() {
+            if !entries.contains_key(key) && entries.len() >= self.max_entries {
+                let least_recently_used = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.used_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(least_recently_used) = least_recently_used {
+                    entries.remove(&least_recently_used);
+                }
+            }
+
+            entries.insert(
+                key.to_string(),
+                CachedResponse {
+                    etag,
+                    last_modified,
+                    body: Arc::from(body),
+                    used_at: Instant::now(),
+                },
+            );
+        }
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        ResponseCache {
+            max_entries: 1000,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl CachedResponse {
+    /// Parses the stored body, which was already parsed once before being stored.
+    pub fn body(&self) -> Result<Value> {
+        serde_json::from_str(&self.body).map_err(|e| AppError::Unexpected(e.to_string()))
+    }
+
+    /// Adds the conditional headers matching the stored validators.
+    pub fn apply(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(etag) = &self.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn store_succeeds_when_response_has_validators() {
+        let cache = ResponseCache::default();
+        cache.store("/api", Some("\"v1\"".to_string()), None, r#"{"id": 1}"#);
+
+        let cached = cache.get("/api").unwrap();
+
+        assert_eq!(cached.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(cached.body().unwrap(), json!({"id": 1}));
+    }
+
+    #[test]
+    fn get_shares_body_when_entry_is_hit() {
+        let cache = ResponseCache::default();
+        cache.store("/api", Some("\"v1\"".to_string()), None, r#"{"id": 1}"#);
+
+        let first = cache.get("/api").unwrap();
+        let second = cache.get("/api").unwrap();
+
+        assert!(Arc::ptr_eq(&first.body, &second.body));
+    }
+
+    #[test]
+    fn store_is_skipped_when_response_has_no_validators() {
+        let cache = ResponseCache::default();
+        cache.store("/api", None, None, r#"{"id": 1}"#);
+
+        assert!(cache.get("/api").is_none());
+    }
+
+    #[test]
+    fn store_drops_least_recently_used_when_cache_is_full() {
+        let cache = ResponseCache::default().max_entries(2);
+        cache.store("/first", Some("\"v1\"".to_string()), None, r#"{"id": 1}"#);
+        cache.store("/second", Some("\"v1\"".to_string()), None, r#"{"id": 2}"#);
+        cache.get("/first");
+
+        cache.store("/third", Some("\"v1\"".to_string()), None, r#"{"id": 3}"#);
+
+        assert!(cache.get("/first").is_some());
+        assert!(cache.get("/second").is_none());
+        assert!(cache.get("/third").is_some());
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::broadcast::{self, Sender};
+
+use crate::application::domain::core::{AppError, Result};
+
+/// Coalesces concurrent calls sharing the same key into a single in-flight call.
+///
+/// The first caller runs the call, the callers arriving while it's in flight wait for it
+/// and get a copy of its result. Clones share the in-flight calls, so workers coalesce too.
+#[derive(Clone, Debug)]
+pub struct SingleFlight<T> {
+    calls: Arc<Mutex<HashMap<String, Sender<Result<T>>>>>,
+}
+
+/// Forgets the in-flight call when the leader finishes or is dropped halfway.
+struct Flight<'a, T> {
+    key: &'a str,
+    calls: &'a Mutex<HashMap<String, Sender<Result<T>>>>,
+    landed: bool,
+}
+
+impl<T: Clone> SingleFlight<T> {
+    pub async fn run<F, Fut>(&self, key: &str, call: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let receiver = {
+            let mut calls = self
+                .calls
+                .lock()
+                .map_err(|e| AppError::Unexpected(e.to_string()))?;
+
+            match calls.get(key) {
+                Some(sender) => Some(sender.subscribe()),
+                None => {
+                    calls.insert(key.to_string(), broadcast::channel(1).0);
+                    None
+                }
+            }
+        };
+
+        if let Some(mut receiver) = receiver {
+            // a leader dropped before answering leaves its followers to run the call themselves
+            return match receiver.recv().await {
+                Ok(result) => result,
+                Err(_) => call().await,
+            };
+        }
+
+        let flight = Flight {
+            key,
+            calls: &self.calls,
+            landed: false,
+        };
+        let result = call().await;
+
+        if let Some(sender) = flight.land() {
+            let _ = sender.send(result.clone());
+        }
+
+        result
+    }
+}
+
+impl<T> Default for SingleFlight<T> {
+    fn default() -> Self {
+        SingleFlight {
+            calls: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl<T> Flight<'_, T> {
+    fn land(mut self) -> Option<Sender<Result<T>>> {
+        self.landed = true;
+        self.calls.lock().ok()?.remove(self.key)
+    }
+}
+
+impl<T> Drop for Flight<'_, T> {
+    fn drop(&mut self) {
+        if self.landed {
+            return;
+        }
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.remove(self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[actix_rt::test]
+    async fn run_shares_result_when_calls_are_concurrent() {
+        let flight = SingleFlight::default();
+        let calls = Cell::new(0);
+        let call = || async {
+            calls.set(calls.get() + 1);
+            actix_rt::time::sleep(Duration::from_millis(10)).await;
+            Ok(calls.get())
+        };
+
+        let (first, second, third) = tokio::join!(
+            flight.run("key", call),
+            flight.run("key", call),
+            flight.run("key", call)
+        );
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!((first.unwrap(), second.unwrap(), third.unwrap()), (1, 1, 1));
+    }
+
+    #[actix_rt::test]
+    async fn run_calls_again_when_keys_are_different() {
+        let flight = SingleFlight::default();
+        let calls = Cell::new(0);
+        let call = || async {
+            calls.set(calls.get() + 1);
+            actix_rt::time::sleep(Duration::from_millis(10)).await;
+            Ok(())
+        };
+
+        let _ = tokio::join!(flight.run("first", call), flight.run("second", call));
+
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[actix_rt::test]
+    async fn run_calls_again_when_previous_call_has_finished() {
+        let flight = SingleFlight::default();
+        let calls = Cell::new(0);
+        let call = || async {
+            calls.set(calls.get() + 1);
+            Ok(())
+        };
+
+        flight.run("key", call).await.unwrap();
+        flight.run("key", call).await.unwrap();
+
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[actix_rt::test]
+    async fn run_calls_again_when_leader_is_dropped() {
+        let flight = SingleFlight::default();
+        let slow = || async {
+            actix_rt::time::sleep(Duration::from_secs(10)).await;
+            Ok("slow")
+        };
+
+        let abandoned = actix_rt::time::timeout(Duration::from_millis(5), flight.run("key", slow)).await;
+        let result = flight.run("key", || async { Ok("quick") }).await;
+
+        assert!(abandoned.is_err());
+        assert_eq!(result.unwrap(), "quick");
+    }
+
+    #[actix_rt::test]
+    async fn run_shares_error_when_call_fails() {
+        let flight: SingleFlight<()> = SingleFlight::default();
+        let call = || async {
+            actix_rt::time::sleep(Duration::from_millis(10)).await;
+            Err(AppError::ApiFailure(503, "down".to_string()))
+        };
+
+        let (first, second) = tokio::join!(flight.run("key", call), flight.run("key", call));
+
+        assert!(matches!(first, Err(AppError::ApiFailure(503, _))));
+        assert!(matches!(second, Err(AppError::ApiFailure(503, _))));
+    }
+}